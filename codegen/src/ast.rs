@@ -6,9 +6,9 @@
 //! The Abstract Syntax Tree for a register_map! invocation.
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 use std::{ops::Index, slice};
-use syn::{Attribute, Ident, LitInt, Path, Type, TypePath, Visibility};
+use syn::{Attribute, Expr, Ident, LitInt, Meta, Path, Type, TypePath, Visibility};
 
 /// Represents the full input to the register_map! procedural macro.
 ///
@@ -26,6 +26,9 @@ use syn::{Attribute, Ident, LitInt, Path, Type, TypePath, Visibility};
 ///     a: u8 { Read, Write },       // A register defined by primitive type and operation list
 ///     /// Doc comment              // Doc comment that should attach to `b`
 ///     pub b: [a; 2],               // A register array that refers to another definition
+///     #[reset(0x80)]               // Reset value for register definition `r`
+///     r: u8 { Read, Write },
+///     s: u8 { Read } [ON OFFSET(0)], // A register definition with inline bitfields
 ///     /// Doc comment              // Doc comment that should attach to `foo`
 ///     pub foo {                    // Start of a register block
 ///         0 => c: u8 { Read },     // Field register defined by primitive type and operation list
@@ -64,19 +67,35 @@ pub struct Input {
 ///         1 => _: 1,
 ///         2 => d: a,
 ///         3 => e: [b; 2],
+///     },
+///
+///     // `bar` is a Layout with a const generic parameter.
+///     pub bar<const N: usize> {
+///         0 => f: u8 { Read },
+///         1 => g: [a; N],
 ///     }
 /// }
 /// ```
 ///
 /// When a Layout is parsed, if no `#[bus]` or `#[buses]` attribute is present, `bus` will be set
-/// to `BusAttr::Buses(vec![])`. The Parse impl for `Input` will correct the `bus` entry.
+/// to `BusAttr::Buses(vec![])`. The Parse impl for `Input` will correct the `bus` entry. A
+/// `#[reset(...)]` attribute on a Layout is stored in its RegisterSpec.
 #[cfg_attr(test, derive(Debug))]
 pub struct Layout {
     /// Doc comments, converted into outer attributes.
     pub docs: Vec<Attribute>,
+    /// The predicates of the layout's `#[cfg(...)]` attributes, which are applied to the generated
+    /// module.
+    pub cfgs: Vec<Meta>,
     pub bus: BusAttr,
+    /// True if the layout has a `#[fake]` attribute (or the input has a `#![fake]` attribute), in
+    /// which case an in-memory `Fake` implementation is generated.
+    pub fake: bool,
     pub visibility: Visibility,
     pub name: Ident,
+    /// The names of the layout's const generic parameters, which all have type `usize`. Only
+    /// register blocks can have generic parameters.
+    pub generics: Vec<Ident>,
     pub value: Value,
 }
 
@@ -167,6 +186,18 @@ pub enum Value {
 ///         // The doc comment is also part of the field
 ///         /// Doc comment
 ///         2 => f: [a; 256],
+///
+///         #[stride(8)] 258 => g: [u16; 4] { Read },
+///       //^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Field (each element is 8 bytes apart)
+///
+///         #[reset(0x3)] 290 => h: u8 { Read },
+///       //^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Field (the reset value is in the RegisterSpec)
+///
+///         #[cfg(unix)] 291 => i: u8 { Read },
+///       //^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Field (the cfg predicate is stored in the FieldDef)
+///
+///         pub(crate) 292 => j: u8 { Read },
+///       //^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Field (the visibility is stored in the FieldDef)
 ///     }
 /// }
 /// ```
@@ -178,8 +209,9 @@ pub struct Field {
 
 /// Contents of a field.
 ///
-/// Note that when a FieldDef::Register is initially parsed, `aliased` is always false and `docs`
-/// is always empty. The Parse impl on Field sets those fields.
+/// Note that when a FieldDef::Register is initially parsed, `aliased` is always false, `docs` and
+/// `cfgs` are always empty, `visibility` is always inherited, and `stride` is always None. The
+/// Parse impl on Field sets those fields.
 ///
 /// ```
 /// # use tock_registers::Read;
@@ -208,15 +240,25 @@ pub enum FieldDef {
     Register {
         /// Doc comments for this register.
         docs: Vec<Attribute>,
+        /// The predicates of the register's `#[cfg(...)]` attributes. The register only exists if
+        /// all of them are satisfied.
+        cfgs: Vec<Meta>,
+        /// The register's visibility. Registers with `pub(crate)` visibility are accessed through
+        /// the block's `RestrictedInterface` trait rather than its `Interface` trait.
+        visibility: Visibility,
         aliased: bool,
+        /// The distance between consecutive elements of the (outermost) array, from the
+        /// `#[stride(...)]` attribute. None if the array is densely packed (or this is not an
+        /// array).
+        stride: Option<PerBusInt>,
         name: Ident,
         spec: RegisterSpec,
     },
 }
 
-/// Per-bus integer literal. Used for both field offsets and padding sizes. This can be a single
-/// value, which applies to all buses, or an array of values. The number of values in the array
-/// must match the number of buses.
+/// Per-bus integer constant. Used for field offsets, padding sizes, and array strides. This can be
+/// a single value, which applies to all buses, or an array of values. The number of values in the
+/// array must match the number of buses.
 ///
 /// ```
 /// # use tock_registers::{Mmio32, Mmio64, Read, Write};
@@ -228,30 +270,30 @@ pub enum FieldDef {
 ///       //^ PerBusInt::Single
 ///
 ///       //v PerBusInt::Single
-///         1 => _: 1,
-///       //        ^ PerBusInt::Single
+///         1 => _: [3, 7],
+///       //        ^^^^^^ PerBusInt::Array
 ///
-///         2 => d: usize { Read, Write },
-///       //^ PerBusInt::Single
+///         [4, 8] => d: usize { Read, Write },
+///       //^^^^^^ PerBusInt::Array
 ///
-///         [6, 10] => e: u8 { Read },
+///         [8, 16] => e: u8 { Read },
 ///       //^^^^^^^ PerBusInt::Array
 ///
 ///       //vvvvvvv PerBusInt::Array
-///         [7, 11] => _: [4, 0],
+///         [9, 17] => _: [4, 0],
 ///       //              ^^^^^^ PerBusInt::Array
 ///     }
 /// }
 /// ```
 #[cfg_attr(test, derive(Debug, PartialEq))]
 pub enum PerBusInt {
-    Array(Vec<LitInt>),
-    Single(LitInt),
+    Array(Vec<ConstInt>),
+    Single(ConstInt),
 }
 
 impl Index<usize> for PerBusInt {
-    type Output = LitInt;
-    fn index(&self, index: usize) -> &LitInt {
+    type Output = ConstInt;
+    fn index(&self, index: usize) -> &ConstInt {
         match self {
             PerBusInt::Array(vec) => &vec[index],
             PerBusInt::Single(int) => int,
@@ -259,6 +301,52 @@ impl Index<usize> for PerBusInt {
     }
 }
 
+/// A `usize` constant expression, used for offsets, sizes, strides, and array lengths. The
+/// expression can use the register block's const generic parameters and any constants that are in
+/// scope where the macro is invoked.
+///
+/// ```
+/// # use tock_registers::Read;
+/// # fn main() {}
+/// const BASE: usize = 0x40;
+/// tock_registers::mmio32_register_map! {
+///     foo<const N: usize> {
+///         0 => c: [u8; N] { Read },
+///       //^            ^ ConstInt
+///         N => _: BASE - N,
+///       //^       ^^^^^^^^ ConstInt
+///         BASE => d: [u32; 2 * 2] { Read },
+///       //^^^^             ^^^^^ ConstInt
+///     }
+/// }
+/// ```
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(Clone)]
+pub struct ConstInt(pub Expr);
+
+impl ConstInt {
+    /// Returns this constant in a form that can be used as a const generic argument (braced,
+    /// unless it is a literal or a single identifier).
+    pub fn generic_arg(&self) -> TokenStream {
+        match &self.0 {
+            Expr::Lit(_) => self.0.to_token_stream(),
+            Expr::Path(path) if path.path.get_ident().is_some() => self.0.to_token_stream(),
+            expr => quote![{ #expr }],
+        }
+    }
+}
+
+/// Constants are parenthesized (unless they are literals or paths) so that they can be used as
+/// operands in the generated code.
+impl ToTokens for ConstInt {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match &self.0 {
+            Expr::Lit(_) | Expr::Path(_) | Expr::Paren(_) => self.0.to_tokens(tokens),
+            expr => tokens.extend(quote![(#expr)]),
+        }
+    }
+}
+
 /// A single register specification. A register specification can appear in two places: as its own
 /// top-level layout or as a field within a register block. The specification can either be an
 /// inline register definition (specifies the register's DataType and operations) or a register
@@ -285,6 +373,7 @@ impl Index<usize> for PerBusInt {
 /// }
 /// ```
 #[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(Clone)]
 pub struct RegisterSpec {
     /// element_type can be a primitive type (for register definitions with operation lists) or a
     /// path to another register definition (for register references). If the register type
@@ -294,9 +383,60 @@ pub struct RegisterSpec {
     /// The array sizes. If this register specification is a nested array, the sizes are listed
     /// from the innermost array to the outermost. For example, `[[[u8; 2]; 3]; 4]` would have
     /// sizes list `[2, 3, 4]`.
-    pub array_sizes: Vec<LitInt>,
+    pub array_sizes: Vec<ConstInt>,
 
     /// Operations, if this is a register definition. If this is a register reference, this will be
     /// None.
     pub operations: Option<Vec<Path>>,
+
+    /// The register's reset value, from the `#[reset(...)]` attribute. Only register definitions
+    /// can have a reset value (every element of an array shares it). This is always None when the
+    /// RegisterSpec is initially parsed; the Parse impls for Layout and Field set it.
+    pub reset: Option<Expr>,
+
+    /// Inline bitfields, listed in brackets after the operations. If this is non-empty, the
+    /// register's DataType is a generated bitfield register type, and `element_type` is that
+    /// type's value type (which must be an unsigned integer primitive).
+    pub bitfields: Vec<Bitfield>,
+}
+
+/// An inline bitfield of a register definition. Bitfields use the same syntax as
+/// `register_bitfields!`, except that `NUMBITS` is optional (and defaults to 1).
+///
+/// ```
+/// # use tock_registers::{Read, Write};
+/// # fn main() {}
+/// tock_registers::mmio32_register_map! {
+///     ctrl: u32 { Read, Write } [
+///         ENABLE OFFSET(0),
+///       //^^^^^^^^^^^^^^^^ Bitfield (1 bit wide)
+///
+///         /// Doc comment
+///         MODE OFFSET(1) NUMBITS(2) [Off = 0, Slow = 1, Fast = 2],
+///       //^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ Bitfield
+///       //                          ^^^^^^^ BitfieldValue
+///     ],
+/// }
+/// ```
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(Clone)]
+pub struct Bitfield {
+    /// Doc comments for this bitfield.
+    pub docs: Vec<Attribute>,
+    pub name: Ident,
+    pub offset: LitInt,
+    /// The bitfield's width. This is 1 if `NUMBITS` was not specified.
+    pub numbits: LitInt,
+    /// The bitfield's enumerated values.
+    pub values: Vec<BitfieldValue>,
+}
+
+/// An enumerated value of a bitfield.
+#[cfg_attr(test, derive(Debug, PartialEq))]
+#[derive(Clone)]
+pub struct BitfieldValue {
+    /// Doc comments for this value.
+    pub docs: Vec<Attribute>,
+    pub name: Ident,
+    pub value: Expr,
 }
@@ -0,0 +1,52 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::ast::{Bitfield, RegisterSpec};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Ident, Path, Type};
+
+/// Generates a `register_bitfields!` invocation for a register definition with inline bitfields,
+/// which defines a module named `name` containing the bitfield register type (`Register`) and a
+/// `Field` constant and module for each bitfield. `name` is the register's name, which is returned
+/// by RegisterDebugInfo::name. `attrs` are attributes applied to the module.
+pub fn generate(
+    tock_registers: &Path,
+    attrs: &TokenStream,
+    name: &Ident,
+    register: &RegisterSpec,
+) -> TokenStream {
+    let value_type = &register.element_type;
+    // register_bitfields! requires every bitfield of a register to have the same form, so each
+    // bitfield specifies its width and enumerated values (which may be empty).
+    let fields = register.bitfields.iter().map(|bitfield| {
+        let Bitfield {
+            docs,
+            name,
+            offset,
+            numbits,
+            values,
+        } = bitfield;
+        let value_docs = values.iter().map(|v| &v.docs);
+        let value_names = values.iter().map(|v| &v.name);
+        let value_exprs = values.iter().map(|v| &v.value);
+        quote! {
+            #(#docs)* #name OFFSET(#offset) NUMBITS(#numbits) [
+                #(#(#value_docs)* #value_names = #value_exprs),*
+            ]
+        }
+    });
+    quote! {
+        #tock_registers::register_bitfields![#value_type, #attrs pub #name [#(#fields),*]];
+    }
+}
+
+/// Returns a copy of `register` whose element type is the bitfield register type generated for its
+/// inline bitfields, which is at `data_type`.
+pub fn with_data_type(register: &RegisterSpec, data_type: Type) -> RegisterSpec {
+    RegisterSpec {
+        element_type: data_type,
+        ..register.clone()
+    }
+}
@@ -4,10 +4,14 @@
 // Copyright Better Bytes 2026.
 
 use crate::ast::{Field, FieldDef, Layout, PerBusInt};
-use crate::{new_doc_comment, register_definition, Env};
+use crate::{
+    bitfields, cfg_attributes, doc_summary, fake_register_definition, new_doc_comment,
+    new_fake_doc_comment, register_definition, reset_doc_comment, value_type, Dump, Env, Reference,
+};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
-use syn::{spanned::Spanned, Ident, Path, TypePath};
+use syn::ext::IdentExt;
+use syn::{parse_quote, spanned::Spanned, Ident, Meta, Path, TypePath, Visibility};
 
 /// Generates the module for a register block.
 pub fn generate(env: Env, tock_registers: &Path, layout: &Layout, fields: &[Field]) -> TokenStream {
@@ -34,149 +38,434 @@ pub fn generate(env: Env, tock_registers: &Path, layout: &Layout, fields: &[Fiel
         Env::ProcMacro => quote![],
     };
     let docs = &layout.docs;
+    let layout_cfg = cfg_attributes(&layout.cfgs);
     let visibility = &layout.visibility;
     let name = &layout.name;
+    let generics = BlockGenerics::new(&layout.generics);
+    let no_generics = BlockGenerics::new(&[]);
+    let (params, args) = (&generics.params, &generics.args);
+    let (leading_params, leading_args) = (&generics.leading_params, &generics.leading_args);
+    let (trailing_params, trailing_args) = (&generics.trailing_params, &generics.trailing_args);
+    // The trait containing the block's size, alignment, and field offsets and strides. For generic
+    // blocks, these depend on the generic parameters, so they are in BusLayout rather than Bus.
+    let layout_trait = match layout.generics.is_empty() {
+        true => quote![Bus],
+        false => quote![BusLayout #args],
+    };
     let interface_comment = interface_doc_comment();
     let mut interface_fields = TokenStream::new();
     let mut len_definitions = TokenStream::new();
     let bus_comment = bus_doc_comment();
     let mut bus_bounds = TokenStream::new();
-    let mut bus_offset_decls = TokenStream::new();
+    // Bounds of BusLayout, which are bounds that depend on the block's generic parameters.
+    let mut layout_bounds = TokenStream::new();
+    let mut bus_const_decls = TokenStream::new();
     let buses = layout.bus.as_slice();
     // cumulative_sizes is empty if the current cumulative size is unknown (due to a padding field
     // with no specified size).
     let mut cumulative_sizes: Vec<_> = (0..buses.len()).map(|_| quote![0]).collect();
-    let mut bus_offset_defs: Vec<_> = (0..buses.len()).map(|_| TokenStream::new()).collect();
+    // If the field that ends at cumulative_sizes has #[cfg] attributes, its predicates and offsets
+    // (where the block ends if the field is not compiled in).
+    let mut cumulative_cfgs: Option<(&[Meta], &PerBusInt)> = None;
+    // If the field that ends at cumulative_sizes has #[cfg] attributes, the cumulative sizes before
+    // it and the #[cfg] attributes under which they bound the next field's offset (the field is
+    // not compiled in, and the field before it is).
+    let mut cfg_fallback: Option<(TokenStream, Vec<TokenStream>)> = None;
+    let mut bus_const_defs: Vec<_> = (0..buses.len()).map(|_| TokenStream::new()).collect();
     let mut borrowed_bus_defs = TokenStream::new();
     let mut offset_tests = TokenStream::new();
+    // The alignments of the block's fields on each bus, as comma-separated lists.
+    let mut alignments: Vec<_> = (0..buses.len()).map(|_| TokenStream::new()).collect();
+    // The [start, end) range of each padding field on each bus. Padding without a specified size
+    // extends to the next non-aliased field, so its range is added when that field is reached.
+    let mut padding_ranges: Vec<Vec<(TokenStream, TokenStream)>> = Vec::new();
+    let mut unsized_padding: Option<&PerBusInt> = None;
+    // The name, #[cfg] attributes, interface trait, offsets, and whether the field is strided for
+    // each aliased field, which are checked against the block's size and padding after all the
+    // fields have been processed.
+    let mut aliased_fields = Vec::new();
+    // Bounds of fields with #[cfg] attributes, which cannot be put directly into the Bus trait's
+    // supertraits or the Interface impl's where clause (as those do not support attributes).
+    let mut cfg_bounds = TokenStream::new();
     let bus_default = layout.bus.generic_default();
     let real_comment = real_doc_comment();
     let new_comment = new_doc_comment();
+    let mut stride_definitions = TokenStream::new();
+    let mut reset_definitions = TokenStream::new();
+    let mut bitfield_modules = TokenStream::new();
     let mut interface_bounds = TokenStream::new();
     let mut interface_impl_items = TokenStream::new();
     let mut real_structs = TokenStream::new();
+    // The FieldInfo of each field, for Real's LayoutInfo impl.
+    let mut layout_fields = TokenStream::new();
+    // Statements that add each readable field to fmt_dump's debug struct.
+    let mut dump_fields = TokenStream::new();
+    let mut fake_fields = TokenStream::new();
+    let mut fake_inits = TokenStream::new();
+    // Bounds of Fake::new, which requires each field's fake to implement Default.
+    let mut fake_bounds = TokenStream::new();
+    let mut fake_structs = TokenStream::new();
+    let mut fake_impl_items = TokenStream::new();
+    // The RestrictedInterface counterparts of interface_fields, interface_bounds,
+    // interface_impl_items, and fake_impl_items, which pub(crate) fields are added to.
+    let mut restricted_fields = TokenStream::new();
+    let mut restricted_bounds = TokenStream::new();
+    let mut restricted_impl_items = TokenStream::new();
+    let mut restricted_fake_impl_items = TokenStream::new();
 
     // Step 2: Loop through each field, update the variables.
-    for field in fields.iter() {
+    for (index, field) in fields.iter().enumerate() {
         // This `match` statement handles padding and `continue`s to the next iteration on padding
         // fields, so the rest of the body of this loop does not need to special-case for padding.
-        let (docs, aliased, name, register) = match &field.field_def {
+        let (docs, cfgs, field_visibility, aliased, stride, name, register) = match &field.field_def
+        {
             FieldDef::Padding(sizes) => {
                 add_offset_tests(
                     tock_registers,
                     &mut offset_tests,
                     buses,
                     &cumulative_sizes,
+                    &previous_cfg(cumulative_cfgs),
                     &field.offsets,
                 );
+                add_fallback_tests(
+                    tock_registers,
+                    &mut offset_tests,
+                    buses,
+                    cfg_fallback.take(),
+                    &quote![],
+                    &field.offsets,
+                );
+                add_unsized_padding(&mut padding_ranges, &mut unsized_padding, buses, field);
                 cumulative_sizes.clear();
-                if let Some(sizes) = sizes {
-                    for bus_idx in 0..buses.len() {
-                        let offset = &field.offsets[bus_idx];
-                        let size = &sizes[bus_idx];
-                        cumulative_sizes.push(quote![#offset + #size]);
+                cumulative_cfgs = None;
+                match sizes {
+                    None => unsized_padding = Some(&field.offsets),
+                    Some(sizes) => {
+                        let mut ranges = Vec::with_capacity(buses.len());
+                        for bus_idx in 0..buses.len() {
+                            let offset = &field.offsets[bus_idx];
+                            let size = &sizes[bus_idx];
+                            cumulative_sizes.push(quote![#offset + #size]);
+                            ranges.push((quote![#offset], quote![#offset + #size]));
+                        }
+                        padding_ranges.push(ranges);
                     }
                 }
                 continue;
             }
             FieldDef::Register {
                 docs,
+                cfgs,
+                visibility,
                 aliased,
+                stride,
                 name,
                 spec,
-            } => (docs, *aliased, name, spec),
+            } => (docs, cfgs, visibility, *aliased, stride, name, spec),
         };
+        let cfg = cfg_attributes(cfgs);
+        // match that handles restricted (pub(crate)) fields, which are accessed through
+        // RestrictedInterface rather than Interface, and whose generated types have the field's
+        // visibility.
+        let (interface, item_visibility, trait_items, impl_bounds, impl_items, fake_items) =
+            match field_visibility {
+                Visibility::Restricted(_) => (
+                    quote![RestrictedInterface #args],
+                    quote![#field_visibility],
+                    &mut restricted_fields,
+                    &mut restricted_bounds,
+                    &mut restricted_impl_items,
+                    &mut restricted_fake_impl_items,
+                ),
+                _ => (
+                    quote![Interface #args],
+                    quote![pub],
+                    &mut interface_fields,
+                    &mut interface_bounds,
+                    &mut interface_impl_items,
+                    &mut fake_impl_items,
+                ),
+            };
         // The rest of this loop body is for register fields. It consists of a series of
         // conditionals and loops that all switch/iterate on a different aspect of the field.
+        //
+        // If statement that handles inline bitfields. The bitfield register type is generated in a
+        // module named after the field, and is used as the field's DataType.
+        let bitfield_register;
+        let register = match register.bitfields.is_empty() {
+            true => register,
+            false => {
+                bitfield_modules.extend(bitfields::generate(tock_registers, &cfg, name, register));
+                bitfield_register =
+                    bitfields::with_data_type(register, parse_quote![#name::Register]);
+                &bitfield_register
+            }
+        };
         let element_type = &register.element_type;
         let mut interface_bound;
         let mut real;
+        let mut fake;
+        let bus_bound;
+        // True if bus_bound depends on the block's generic parameters.
+        let layout_bound;
         // If statement that handles differences between register definitions (which have
         // operations) and register references (which do not).
         if let Some(operations) = &register.operations {
             interface_bound =
                 quote![#tock_registers::Register<DataType = #element_type> #(+ #operations)*];
             let real_name = format_ident!("real_{name}");
+            let fake_name = format_ident!("fake_{name}");
             real = quote![#real_name<B>];
-            let bus_trait = quote![#tock_registers::DataTypeBus<#element_type>];
-            bus_bounds.extend(quote![+ #bus_trait]);
+            fake = quote![#fake_name<'h>];
+            bus_bound = quote![#tock_registers::DataTypeBus<#element_type>];
+            layout_bound = false;
             real_structs.extend(register_definition(
                 tock_registers,
+                &cfg,
                 field_struct_doc_comment(name),
+                &item_visibility,
                 &bus_default,
                 &real_name,
                 register,
                 operations,
+                &quote![resets::#name],
             ));
+            if layout.fake {
+                fake_structs.extend(fake_register_definition(
+                    tock_registers,
+                    &cfg,
+                    field_fake_doc_comment(name),
+                    &item_visibility,
+                    &fake_name,
+                    register,
+                    operations,
+                    &quote![resets::#name],
+                ));
+            }
         } else {
-            interface_bound = quote![#element_type::Interface];
-            real = quote![#element_type::Real<B>];
-            bus_bounds.extend(quote_spanned![element_type.span()=>+ #element_type::Bus]);
+            let reference = Reference::new(element_type);
+            interface_bound = reference.interface();
+            real = reference.real();
+            fake = reference.fake();
+            bus_bound = reference.bus();
+            layout_bound = !layout.generics.is_empty() && !reference.args.is_empty();
         };
-        interface_bounds.extend(quote![#real: #interface_bound,]);
+        // If statement that handles fields with #[cfg] attributes, whose bounds are provided by
+        // helper traits that are only bounded when the field is compiled in.
+        if cfgs.is_empty() {
+            match layout_bound {
+                true => layout_bounds.extend(quote![+ #bus_bound]),
+                false => bus_bounds.extend(quote![+ #bus_bound]),
+            }
+            impl_bounds.extend(quote![#real: #interface_bound,]);
+        } else {
+            let not_cfg = quote![#[cfg(not(all(#(#cfgs),*)))]];
+            // The helpers' names include the field's index, as fields with mutually exclusive
+            // #[cfg] attributes can have the same name.
+            let name_bus = format_ident!("{name}_{index}_bus");
+            let name_interface = format_ident!("{name}_{index}_interface");
+            let name_real = format_ident!("{name}_{index}_real");
+            // The bus helper only has the block's generic parameters if its bound depends on them
+            // (in which case it is a bound of BusLayout rather than Bus).
+            let bus_generics = match layout_bound {
+                true => &generics,
+                false => &no_generics,
+            };
+            let (bus_params, bus_args) = (&bus_generics.params, &bus_generics.args);
+            let bus_trailing_params = &bus_generics.trailing_params;
+            let phantom = &generics.phantom;
+            cfg_bounds.extend(quote! {
+                #cfg pub trait #name_bus #bus_params: #bus_bound {}
+                #cfg impl<B #bus_trailing_params> #name_bus #bus_args for B where B: #bus_bound {}
+                #not_cfg pub trait #name_bus #bus_params {}
+                #not_cfg impl<B #bus_trailing_params> #name_bus #bus_args for B {}
+                #cfg pub trait #name_interface #params: #interface_bound {}
+                #cfg impl<T #trailing_params> #name_interface #args for T
+                    where T: #interface_bound {}
+                #not_cfg pub trait #name_interface #params {}
+                #not_cfg impl<T #trailing_params> #name_interface #args for T {}
+                #cfg #item_visibility type #name_real<#leading_params B> = #real;
+                #not_cfg #item_visibility type #name_real<#leading_params B> =
+                    #tock_registers::internal::core::marker::PhantomData<#phantom>;
+            });
+            match layout_bound {
+                true => layout_bounds.extend(quote![+ cfg_bounds::#name_bus #bus_args]),
+                false => bus_bounds.extend(quote![+ cfg_bounds::#name_bus]),
+            }
+            impl_bounds.extend(quote! {
+                cfg_bounds::#name_real<#leading_args B>: cfg_bounds::#name_interface #args,
+            });
+        }
         // match that handles the difference between scalar registers, non-nested array registers,
         // and nested array registers.
         let len_types_sizes = match register.array_sizes.as_slice() {
             [] => vec![],
             [len] => {
-                len_definitions.extend(quote![pub enum #name {}]);
-                vec![(quote![#name], len)]
+                len_definitions.extend(quote![#cfg pub enum #name #params {}]);
+                vec![(quote![#name #args], len)]
             }
             nested => {
-                len_definitions.extend(quote![pub enum #name<const N: usize> {}]);
+                len_definitions
+                    .extend(quote![#cfg pub enum #name<const LEVEL: usize #trailing_params> {}]);
                 nested
                     .iter()
                     .enumerate()
-                    .map(|(n, s)| (quote![#name<#n>], s))
+                    .map(|(n, s)| (quote![#name<#n #trailing_args>], s))
                     .collect()
             }
         };
+        let levels = len_types_sizes.len();
         // Loop that runs once for each level of array nesting.
-        for (len_type, size) in len_types_sizes {
+        for (level, (len_type, size)) in len_types_sizes.into_iter().enumerate() {
             interface_bound =
                 quote![#tock_registers::RegisterArray<lens::#len_type, Element: #interface_bound>];
             len_definitions.extend(quote! {
-                impl #tock_registers::array::Len for #len_type { const LEN: usize = #size; }
+                #cfg impl #params #tock_registers::array::Len for #len_type {
+                    const LEN: usize = #size;
+                }
             });
-            real = quote![#tock_registers::RealRegisterArray<#real, lens::#len_type>];
+            // The stride applies to the outermost array.
+            real = match stride {
+                Some(_) if level == levels - 1 => quote! {
+                    #tock_registers::RealRegisterArray<
+                        #real, lens::#len_type, strides::#name<#leading_args B>>
+                },
+                _ => quote![#tock_registers::RealRegisterArray<#real, lens::#len_type>],
+            };
+            let size_arg = size.generic_arg();
+            fake = quote![#tock_registers::FakeArray<#fake, lens::#len_type, #size_arg>];
         }
-        interface_fields.extend(quote! {
-            type #name: #interface_bound;
-            #(#docs)* fn #name(self) -> Self::#name;
+        // if that handles register definitions with a reset value, which is stored in the resets
+        // module and exposed through Interface.
+        if let Some(reset) = &register.reset {
+            let reset_comment = reset_doc_comment();
+            let value = value_type(tock_registers, register);
+            let name_reset = format_ident!("{name}_reset");
+            reset_definitions.extend(quote![#cfg #reset_comment pub const #name: #value = #reset;]);
+            trait_items
+                .extend(quote![#cfg #reset_comment const #name_reset: #value = resets::#name;]);
+        }
+        trait_items.extend(quote! {
+            #cfg type #name: #interface_bound;
+            #cfg #(#docs)* fn #name(self) -> Self::#name;
+        });
+        // If statement that bounds Fake::new on the field's fake, through a helper alias if the
+        // field has #[cfg] attributes (as where clauses do not support attributes).
+        if cfgs.is_empty() {
+            fake_bounds.extend(quote![#fake: #tock_registers::internal::core::default::Default,]);
+        } else if layout.fake {
+            let not_cfg = quote![#[cfg(not(all(#(#cfgs),*)))]];
+            let name_fake = format_ident!("{name}_{index}_fake");
+            let fake_phantom = &generics.fake_phantom;
+            cfg_bounds.extend(quote! {
+                #cfg #item_visibility type #name_fake<'h #trailing_params> = #fake;
+                #not_cfg #item_visibility type #name_fake<'h #trailing_params> =
+                    #tock_registers::internal::core::marker::PhantomData<#fake_phantom>;
+            });
+            fake_bounds.extend(quote! {
+                cfg_bounds::#name_fake<'h #trailing_args>:
+                    #tock_registers::internal::core::default::Default,
+            });
+        }
+        fake_fields.extend(quote![#cfg #(#docs)* #item_visibility #name: #fake,]);
+        fake_inits.extend(
+            quote![#cfg #name: #tock_registers::internal::core::default::Default::default(),],
+        );
+        fake_items.extend(quote! {
+            #cfg type #name = &'a #fake;
+            #cfg fn #name(self) -> Self::#name { &self.#name }
         });
         let name_offset = format_ident!("{name}_offset");
-        // match that handles the difference between scalar offset definitions and array offset
-        // definitions (moves the value of the name_offset fields between the Bus trait definition
-        // and impls).
-        bus_offset_decls.extend(match &field.offsets {
-            PerBusInt::Array(offsets) => {
-                for (bus_idx, offset) in offsets.iter().enumerate() {
-                    bus_offset_defs[bus_idx].extend(quote![const #name_offset: usize = #offset;]);
+        add_bus_const(
+            &cfg,
+            &layout_trait,
+            &name_offset,
+            &field.offsets,
+            &mut bus_const_decls,
+            &mut bus_const_defs,
+            &mut borrowed_bus_defs,
+        );
+        // if that handles strided arrays. The stride type forwards to a per-bus constant in Bus.
+        if let Some(stride) = stride {
+            let name_stride = format_ident!("{name}_stride");
+            add_bus_const(
+                &cfg,
+                &layout_trait,
+                &name_stride,
+                stride,
+                &mut bus_const_decls,
+                &mut bus_const_defs,
+                &mut borrowed_bus_defs,
+            );
+            stride_definitions.extend(quote! {
+                #cfg pub struct #name<#leading_params B>(
+                    #tock_registers::internal::core::marker::PhantomData<B>);
+                #cfg impl<#leading_params B: super::#layout_trait> #tock_registers::array::Stride
+                    for #name<#leading_args B>
+                {
+                    const STRIDE: #tock_registers::internal::core::option::Option<usize> =
+                        #tock_registers::internal::core::option::Option::Some(
+                            <B as super::#layout_trait>::#name_stride);
                 }
-                borrowed_bus_defs
-                    .extend(quote![const #name_offset: usize = <B as Bus>::#name_offset;]);
-                quote![const #name_offset: usize;]
-            }
-            PerBusInt::Single(offset) => quote![const #name_offset: usize = #offset;],
-        });
+            });
+        }
+        // Loop that checks the field's alignment on each bus, and adds it to the block's alignment.
+        for (bus_idx, bus) in buses.iter().enumerate() {
+            let offset = &field.offsets[bus_idx];
+            let alignment = quote! {
+                <<Real<#leading_args #bus> as #interface>::#name as #tock_registers::Span>::ALIGNMENT
+            };
+            let msg = format!("field `{name}` is misaligned for bus {}", bus_name(bus));
+            offset_tests.extend(quote_spanned![offset.span()=>#cfg assert!(#offset %
+                #tock_registers::internal::core::convert::identity(#alignment) == 0, #msg);]);
+            alignments[bus_idx].extend(quote![#cfg #alignment,]);
+        }
         // if that handles aliased vs. non-aliased fields.
-        if !aliased {
+        if aliased {
+            aliased_fields.push((
+                name,
+                cfg.clone(),
+                interface,
+                &field.offsets,
+                stride.is_some(),
+            ));
+        } else {
+            let previous_cfg = previous_cfg(cumulative_cfgs);
             add_offset_tests(
                 tock_registers,
                 &mut offset_tests,
                 buses,
                 &cumulative_sizes,
+                &quote![#previous_cfg #cfg],
+                &field.offsets,
+            );
+            add_fallback_tests(
+                tock_registers,
+                &mut offset_tests,
+                buses,
+                cfg_fallback.take(),
+                &cfg,
                 &field.offsets,
             );
+            add_unsized_padding(&mut padding_ranges, &mut unsized_padding, buses, field);
+            if !cfgs.is_empty() {
+                let not_cfg = quote![#[cfg(not(all(#(#cfgs),*)))] #previous_cfg];
+                cfg_fallback = Some((not_cfg, cumulative_sizes.clone()));
+            }
             cumulative_sizes.clear();
+            cumulative_cfgs = match cfgs.is_empty() {
+                true => None,
+                false => Some((cfgs, &field.offsets)),
+            };
             for (bus_idx, bus) in buses.iter().enumerate() {
                 let offset = &field.offsets[bus_idx];
-                cumulative_sizes.push(quote![#offset + <<Real<#bus> as Interface>::#name as #tock_registers::Span>::SIZE]);
+                cumulative_sizes.push(quote![#offset + <<Real<#leading_args #bus> as #interface>::#name as #tock_registers::Span>::SIZE]);
             }
         }
-        interface_impl_items.extend(quote! {
-            type #name = #real;
-            fn #name(self) -> Self::#name {
+        impl_items.extend(quote! {
+            #cfg type #name = #real;
+            #cfg fn #name(self) -> Self::#name {
                 // Safety (see crate::new_doc_comment() for requirements):
                 // 1. When Self::new was called to construct `self`, the caller guaranteed that the
                 //    passed address points to registers on the bus of type B.
@@ -188,64 +477,349 @@ pub fn generate(env: Env, tock_registers: &Path, layout: &Layout, fields: &[Fiel
                 //    a way that avoids data races, which includes the responsibility to avoid data
                 //    races on individual fields of the register block.
                 unsafe {
-                    Self::#name::new(self.address.byte_add(<B as Bus>::#name_offset))
+                    Self::#name::new(self.address.byte_add(<B as #layout_trait>::#name_offset))
                 }
             }
         });
+        let name_str = name.unraw().to_string();
+        let summary = doc_summary(docs);
+        // Restricted fields are only accessible within the crate that defines the block, so they
+        // are left out of its public layout.
+        if !matches!(field_visibility, Visibility::Restricted(_)) {
+            layout_fields.extend(quote! {
+                #cfg #tock_registers::layout::FieldInfo::new::<#real>(
+                    #name_str, #summary, <B as #layout_trait>::#name_offset),
+            });
+        }
+        // if statement that adds the field to debug dumps if its register is readable. Restricted
+        // fields are not part of Interface, so they are not included in debug dumps.
+        if !matches!(field_visibility, Visibility::Restricted(_)) {
+            let debug_fn = quote![#tock_registers::internal::debug_fn];
+            let value = quote![<I as Interface #args>::#name(registers)];
+            let probe = quote![#tock_registers::internal::dump::Probe::of(&#value)];
+            let Dump { dumped, expr } = Dump::new(tock_registers, register, value, probe);
+            dump_fields.extend(quote! {
+                #cfg if #dumped { fields.field(#name_str, &#debug_fn(|f| #expr)); }
+            });
+        }
     }
 
+    // If the last non-aliased field has #[cfg] attributes and is not compiled in, the block ends at
+    // its offset, which must not be before the end of the field before it.
+    if let Some((_, offsets)) = cumulative_cfgs {
+        add_fallback_tests(
+            tock_registers,
+            &mut offset_tests,
+            buses,
+            cfg_fallback.take(),
+            &quote![],
+            offsets,
+        );
+    }
+
+    // Loop that checks that each aliased field is within the block and does not overlap padding.
+    for (name, cfg, interface, offsets, strided) in aliased_fields {
+        for (bus_idx, bus) in buses.iter().enumerate() {
+            let offset = &offsets[bus_idx];
+            // A strided array ends at the end of its last element, which is before the end of its
+            // last stride.
+            let end = match strided {
+                false => {
+                    quote![#offset + <<Real<#leading_args #bus> as #interface>::#name as #tock_registers::Span>::SIZE]
+                }
+                true => quote![#offset + <<Real<#leading_args #bus> as #interface>::#name>::END],
+            };
+            let bus_name = bus_name(bus);
+            let msg = format!("aliased field `{name}` extends beyond the block for bus {bus_name}");
+            offset_tests.extend(
+                quote_spanned![name.span()=>#cfg assert!(#end <= <#bus as #layout_trait>::SIZE, #msg);],
+            );
+            let msg = format!("aliased field `{name}` overlaps padding for bus {bus_name}");
+            for ranges in &padding_ranges {
+                let (start, padding_end) = &ranges[bus_idx];
+                offset_tests.extend(quote_spanned![name.span()=>#cfg assert!(
+                    !#tock_registers::internal::overlaps_padding(#start, #padding_end, #offset, #end),
+                    #msg);]);
+            }
+        }
+    }
+
+    // If statement that switches on whether the last non-aliased field has #[cfg] attributes. If
+    // it is not compiled in, the block ends at its offset.
+    let size_definitions: Vec<_> = match cumulative_cfgs {
+        None => cumulative_sizes
+            .iter()
+            .map(|size| quote![const SIZE: usize = #size;])
+            .collect(),
+        Some((cfgs, offsets)) => cumulative_sizes
+            .iter()
+            .enumerate()
+            .map(|(bus_idx, size)| {
+                let offset = &offsets[bus_idx];
+                quote! {
+                    #(#[cfg(#cfgs)])* const SIZE: usize = #size;
+                    #[cfg(not(all(#(#cfgs),*)))] const SIZE: usize = #offset;
+                }
+            })
+            .collect(),
+    };
+    // If statement that switches on whether the block has fields with #[cfg] attributes, which need
+    // a cfg_bounds module.
+    let cfg_bounds_module = match cfg_bounds.is_empty() {
+        true => quote![],
+        false => quote![#[doc(hidden)] pub mod cfg_bounds { use super::*; #cfg_bounds }],
+    };
+    // If statement that switches on whether the block has strided arrays, which need a strides
+    // module.
+    let strides_module = match stride_definitions.is_empty() {
+        true => quote![],
+        false => quote![pub mod strides { #stride_definitions }],
+    };
+    // If statement that switches on whether the block has reset values, which need a resets
+    // module.
+    let resets_module = match reset_definitions.is_empty() {
+        true => quote![],
+        false => quote! {
+            pub mod resets { #![allow(non_upper_case_globals)] use super::*; #reset_definitions }
+        },
+    };
+
+    // If statement that switches on whether the block has restricted fields, which need a
+    // RestrictedInterface trait (implemented by Real and, if requested, Fake). Interface is a
+    // supertrait of RestrictedInterface, so Real's impl needs Interface's bounds as well.
+    let (restricted_interface, restricted_real_impl, restricted_fake_impl) =
+        match restricted_fields.is_empty() {
+            true => (quote![], quote![], quote![]),
+            false => {
+                let restricted_comment = restricted_interface_doc_comment();
+                (
+                    quote! {
+                        #restricted_comment pub(crate) trait RestrictedInterface #params:
+                            Interface #args
+                        {
+                            #restricted_fields
+                        }
+                    },
+                    quote! {
+                        impl<#leading_params B: #layout_trait> RestrictedInterface #args
+                            for Real<#leading_args B>
+                        where
+                            #interface_bounds #restricted_bounds
+                        {
+                            #restricted_impl_items
+                        }
+                    },
+                    quote! {
+                        impl<'a, 'h #trailing_params> RestrictedInterface #args
+                            for &'a Fake<'h #trailing_args>
+                        {
+                            #restricted_fake_impl_items
+                        }
+                    },
+                )
+            }
+        };
+    // If statement that switches on whether a Fake implementation was requested. Fake has a
+    // PhantomData field so that 'h is used even if the block has no registers.
+    let fake_definition = match layout.fake {
+        false => quote![],
+        true => {
+            let fake_comment = fake_doc_comment();
+            let new_fake_comment = new_fake_doc_comment();
+            quote! {
+                #fake_comment pub struct Fake<'h #trailing_params> {
+                    #fake_fields
+                    _phantom: #tock_registers::internal::core::marker::PhantomData<&'h ()>,
+                }
+                impl<'a, 'h #trailing_params> Interface #args for &'a Fake<'h #trailing_args> {
+                    #fake_impl_items
+                }
+                #restricted_fake_impl
+                impl<'h #trailing_params> Fake<'h #trailing_args> {
+                    #new_fake_comment pub fn new() -> Self where #fake_bounds {
+                        Fake {
+                            #fake_inits
+                            _phantom: #tock_registers::internal::core::marker::PhantomData,
+                        }
+                    }
+                }
+                impl<'h #trailing_params> #tock_registers::internal::core::default::Default
+                    for Fake<'h #trailing_args>
+                where
+                    #fake_bounds
+                {
+                    fn default() -> Self { Self::new() }
+                }
+                #fake_structs
+            }
+        }
+    };
+
+    // The contents of the trait containing the block's layout (Bus or BusLayout), its impl for each
+    // bus, and its impl for BorrowedBus.
+    let layout_decls = quote! {
+        const SIZE: usize;
+        const ALIGNMENT: usize;
+        #bus_const_decls
+    };
+    let layout_defs = size_definitions
+        .iter()
+        .zip(&alignments)
+        .zip(&bus_const_defs)
+        .map(|((size_definition, alignments), bus_const_defs)| {
+            quote! {
+                #size_definition
+                const ALIGNMENT: usize = #tock_registers::internal::max_alignment(&[#alignments]);
+                #bus_const_defs
+            }
+        });
+    let borrowed_layout_defs = quote! {
+        const SIZE: usize = <B as #layout_trait>::SIZE;
+        const ALIGNMENT: usize = <B as #layout_trait>::ALIGNMENT;
+        #borrowed_bus_defs
+    };
+    // If statement that switches on whether the block has generic parameters. The layout of a
+    // generic block depends on its generic parameters, but Bus cannot have generic parameters (the
+    // operation macros use it to bound their register structs' bus), so the layout is in a
+    // separate BusLayout trait. The offset tests also depend on the generic parameters, so they are
+    // in an associated constant of Real, which is evaluated when Real is constructed.
+    let (bus_definitions, offset_tests_item, offset_tests_const, offset_tests_check) =
+        match layout.generics.is_empty() {
+            true => (
+                quote! {
+                    #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
+                    pub trait Bus: #tock_registers::Address #bus_bounds + sealed::Bus {
+                        #layout_decls
+                    }
+                    #(
+                        impl Bus for #buses { #layout_defs }
+                        impl sealed::Bus for #buses {}
+                    )*
+                    impl<B: Bus> Bus for #tock_registers::BorrowedBus<'_, B> {
+                        #borrowed_layout_defs
+                    }
+                    impl<B: Bus> sealed::Bus for #tock_registers::BorrowedBus<'_, B> {}
+                },
+                quote![const _: () = { #offset_tests };],
+                quote![],
+                quote![],
+            ),
+            false => {
+                let bus_layout_comment = bus_layout_doc_comment();
+                (
+                    quote! {
+                        #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
+                        pub trait Bus: #tock_registers::Address #bus_bounds + sealed::Bus {}
+                        #(
+                            impl Bus for #buses {}
+                            impl sealed::Bus for #buses {}
+                        )*
+                        impl<B: Bus> Bus for #tock_registers::BorrowedBus<'_, B> {}
+                        impl<B: Bus> sealed::Bus for #tock_registers::BorrowedBus<'_, B> {}
+                        #bus_layout_comment #[allow(clippy::trait_duplication_in_bounds)]
+                        pub trait BusLayout #params: Bus #layout_bounds { #layout_decls }
+                        #(impl #params BusLayout #args for #buses { #layout_defs })*
+                        impl<#leading_params B: BusLayout #args> BusLayout #args
+                            for #tock_registers::BorrowedBus<'_, B>
+                        {
+                            #borrowed_layout_defs
+                        }
+                    },
+                    quote![],
+                    quote![const OFFSET_TESTS: () = { #offset_tests };],
+                    quote![let () = Self::OFFSET_TESTS;],
+                )
+            }
+        };
+
+    let core = quote![#tock_registers::internal::core];
+    let block_name = name.unraw().to_string();
+    let debug_dump_comment = debug_dump_doc_comment();
+
     // Step 3: the final quote! call that puts everything together.
     quote! {
-        #(#docs)*
+        #(#docs)* #layout_cfg
         #visibility mod #name {
             #![allow(non_camel_case_types #env_allows)] use super::*;
-            #interface_comment pub trait Interface: #tock_registers::internal::core::marker::Copy {
+            #interface_comment
+            pub trait Interface #params: #tock_registers::internal::core::marker::Copy {
                 #interface_fields
             }
-            pub mod lens { #len_definitions }
-            #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
-            pub trait Bus: #tock_registers::Address #bus_bounds + sealed::Bus {
-                const SIZE: usize;
-                #bus_offset_decls
-            }
-            #(
-                impl Bus for #buses {
-                    const SIZE: usize = #cumulative_sizes;
-                    #bus_offset_defs
-                }
-                impl sealed::Bus for #buses {}
-            )*
-            impl<B: Bus> Bus for #tock_registers::BorrowedBus<'_, B> {
-                const SIZE: usize = <B as Bus>::SIZE;
-                #borrowed_bus_defs
-            }
-            impl<B: Bus> sealed::Bus for #tock_registers::BorrowedBus<'_, B> {}
-            const _: () = { #offset_tests };
+            #restricted_interface
+            // Array lengths can refer to constants in scope at the macro invocation.
+            pub mod lens { #[allow(unused_imports)] use super::*; #len_definitions }
+            #cfg_bounds_module
+            #strides_module
+            #resets_module
+            #bitfield_modules
+            #bus_definitions
+            #offset_tests_item
             mod sealed { pub trait Bus {} }
-            #real_comment #[derive(Clone)] pub struct Real<B: Bus #bus_default> {
+            #real_comment #[derive(Clone)]
+            pub struct Real<#leading_params B: #layout_trait #bus_default> {
                 address: B,
                 _phantom: #tock_registers::internal::RealPhantom,
             }
-            impl<B: Bus> Real<B> {
+            impl<#leading_params B: #layout_trait> Real<#leading_args B> {
+                #offset_tests_const
                 #new_comment pub const unsafe fn new(address: B) -> Self {
+                    #offset_tests_check
                     Self { address, _phantom: #tock_registers::internal::RealPhantom::new() }
                 }
             }
-            impl<B: Bus> #tock_registers::internal::core::marker::Copy for Real<B> {}
-            impl<B: Bus> Interface for Real<B> where #interface_bounds {
+            impl<#leading_params B: #layout_trait> #tock_registers::internal::core::marker::Copy
+                for Real<#leading_args B> {}
+            impl<#leading_params B: #layout_trait> Interface #args for Real<#leading_args B>
+            where
+                #interface_bounds
+            {
                 #interface_impl_items
             }
-            // Safety: Our size calculation for Bus::SIZE is correct. Bus is sealed, so there can
-            // be no other implementations of Bus.
-            unsafe impl<B: Bus> #tock_registers::Span for Real<B> {
+            #restricted_real_impl
+            // Safety: Our size calculation for the layout's SIZE is correct. Bus is sealed, so
+            // there can be no other implementations of Bus (or BusLayout).
+            unsafe impl<#leading_params B: #layout_trait> #tock_registers::Span
+                for Real<#leading_args B>
+            {
                 type Address = B;
-                const SIZE: usize = <B as Bus>::SIZE;
+                const SIZE: usize = <B as #layout_trait>::SIZE;
+                const ALIGNMENT: usize = <B as #layout_trait>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
+                    #offset_tests_check
                     Self { address, _phantom: #tock_registers::internal::RealPhantom::new() }
                 }
-                type Borrowed<'b> = Real<#tock_registers::BorrowedBus<'b, B>>;
+                type Borrowed<'b> = Real<#leading_args #tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<#leading_params B: #layout_trait> #tock_registers::layout::LayoutInfo
+                for Real<#leading_args B>
+            {
+                const LAYOUT: #tock_registers::layout::Layout =
+                    #tock_registers::layout::Layout::Block { fields: &[#layout_fields] };
+            }
+            #[doc(hidden)]
+            pub fn is_dumped<#leading_params I: Interface #args>(
+                _probe: #tock_registers::internal::dump::Probe<I>
+            ) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<#leading_params I: Interface #args>(
+                registers: I,
+                f: &mut #core::fmt::Formatter<'_>,
+            ) -> #core::fmt::Result {
+                #[allow(unused_imports)] use #tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct(#block_name);
+                #dump_fields
+                fields.finish()
+            }
+            #debug_dump_comment
+            pub fn debug_dump<#leading_params I: Interface #args>(registers: I)
+                -> #tock_registers::debug::DebugDump<I>
+            {
+                #tock_registers::debug::DebugDump::new(registers, fmt_dump::<#leading_args I>)
             }
             #real_structs
+            #fake_definition
         }
     }
 }
@@ -257,37 +831,211 @@ pub fn interface_doc_comment() -> TokenStream {
     }
 }
 
+pub fn restricted_interface_doc_comment() -> TokenStream {
+    quote! {
+        /// Extension of [Interface] containing this register block's `pub(crate)` registers, which
+        /// are only accessible within the crate that defines the register block.
+    }
+}
+
 pub fn bus_doc_comment() -> TokenStream {
     quote! {
         /// Buses supported by this register block.
     }
 }
 
+pub fn bus_layout_doc_comment() -> TokenStream {
+    quote! {
+        /// Layout of this register block on each of its buses, which depends on the block's generic
+        /// parameters.
+    }
+}
+
 pub fn real_doc_comment() -> TokenStream {
     quote! {
         /// Struct implementing [Interface] for use with the real hardware.
     }
 }
 
+pub fn debug_dump_doc_comment() -> TokenStream {
+    quote! {
+        /// Returns a value whose `Debug` implementation reads and prints every readable register
+        /// in this register block, including the registers of nested arrays and register blocks.
+        /// Registers without the `Read` operation, and registers with the `ReadDestructive`
+        /// operation (whose reads have side effects), are not read.
+    }
+}
+
+pub fn fake_doc_comment() -> TokenStream {
+    quote! {
+        /// In-memory implementation of this register block for unit testing. `&Fake` implements
+        /// [Interface]; hooks can be attached to each register's `FakeCell` to add side effects.
+        /// `Fake::new()` constructs a fake in which every register contains its reset value.
+    }
+}
+
+pub fn field_fake_doc_comment(name: &Ident) -> TokenStream {
+    let msg = format!("In-memory implementation of the `{name}` register for unit testing.");
+    quote![#[doc = #msg]]
+}
+
 pub fn field_struct_doc_comment(name: &Ident) -> TokenStream {
     let msg = format!("Struct that provides access to the `{name}` register on real hardware.");
     quote![#[doc = #msg]]
 }
 
+/// Adds a `usize` constant with the given per-bus values to the Bus trait. A single value is
+/// defined in the Bus trait itself, while per-bus values are defined in each bus' impl (and
+/// forwarded by the BorrowedBus impl). `cfg` contains the `#[cfg]` attributes of the field the
+/// constant belongs to, and `layout_trait` is the trait the constant is added to (Bus, or BusLayout
+/// for generic blocks).
+fn add_bus_const(
+    cfg: &TokenStream,
+    layout_trait: &TokenStream,
+    name: &Ident,
+    values: &PerBusInt,
+    bus_const_decls: &mut TokenStream,
+    bus_const_defs: &mut [TokenStream],
+    borrowed_bus_defs: &mut TokenStream,
+) {
+    bus_const_decls.extend(match values {
+        PerBusInt::Array(values) => {
+            for (bus_idx, value) in values.iter().enumerate() {
+                bus_const_defs[bus_idx].extend(quote![#cfg const #name: usize = #value;]);
+            }
+            borrowed_bus_defs
+                .extend(quote![#cfg const #name: usize = <B as #layout_trait>::#name;]);
+            quote![#cfg const #name: usize;]
+        }
+        PerBusInt::Single(value) => quote![#cfg const #name: usize = #value;],
+    });
+}
+
 /// Adds offset tests for a field with the given offsets. If the current cumulative size is unknown
-/// (because of a padding field with no specified size), this does nothing.
+/// (because of a padding field with no specified size), this does nothing. The tests are only
+/// compiled in if the `#[cfg]` attributes in `cfg` are satisfied.
 fn add_offset_tests(
     tock_registers: &Path,
     offset_tests: &mut TokenStream,
     buses: &[TypePath],
     cumulative_sizes: &[TokenStream],
+    cfg: &TokenStream,
     offsets: &PerBusInt,
 ) {
     for (bus_idx, cumulative_size) in cumulative_sizes.iter().enumerate() {
         let offset = &offsets[bus_idx];
-        let bus = &buses[bus_idx].path.segments.last().expect("empty bus path");
-        let msg = format!("offset mismatch for bus {}", bus.ident);
-        offset_tests.extend(quote_spanned![offset.span()=>assert!(#offset ==
+        let msg = format!("offset mismatch for bus {}", bus_name(&buses[bus_idx]));
+        offset_tests.extend(quote_spanned![offset.span()=>#cfg assert!(#offset ==
             #tock_registers::internal::core::convert::identity(#cumulative_size), #msg);]);
     }
 }
+
+/// Adds offset tests for a field that follows a field with `#[cfg]` attributes, which check that
+/// the field does not start before the end of the field before that one (if the `#[cfg]`'d field
+/// is not compiled in, its offset is not checked against it). `fallback` is the `cfg_fallback` of
+/// the `#[cfg]`'d field, and the tests are also only compiled in if the `#[cfg]` attributes in
+/// `cfg` are satisfied.
+fn add_fallback_tests(
+    tock_registers: &Path,
+    offset_tests: &mut TokenStream,
+    buses: &[TypePath],
+    fallback: Option<(TokenStream, Vec<TokenStream>)>,
+    cfg: &TokenStream,
+    offsets: &PerBusInt,
+) {
+    let Some((fallback_cfg, sizes)) = fallback else {
+        return;
+    };
+    for (bus_idx, size) in sizes.iter().enumerate() {
+        let offset = &offsets[bus_idx];
+        let msg = format!(
+            "offset overlaps the previous field for bus {}",
+            bus_name(&buses[bus_idx])
+        );
+        offset_tests.extend(
+            quote_spanned![offset.span()=>#fallback_cfg #cfg assert!(#offset >=
+            #tock_registers::internal::core::convert::identity(#size), #msg);],
+        );
+    }
+}
+
+/// Returns the `#[cfg]` attributes of the field that ends at the current cumulative size. The size
+/// is only known if that field is compiled in.
+fn previous_cfg(cumulative_cfgs: Option<(&[Meta], &PerBusInt)>) -> TokenStream {
+    match cumulative_cfgs {
+        None => quote![],
+        Some((cfgs, _)) => cfg_attributes(cfgs),
+    }
+}
+
+/// If the preceding padding field has no specified size, adds its range (which ends at `field`) to
+/// `padding_ranges`.
+fn add_unsized_padding(
+    padding_ranges: &mut Vec<Vec<(TokenStream, TokenStream)>>,
+    unsized_padding: &mut Option<&PerBusInt>,
+    buses: &[TypePath],
+    field: &Field,
+) {
+    if let Some(starts) = unsized_padding.take() {
+        let ranges = (0..buses.len())
+            .map(|bus_idx| {
+                let (start, end) = (&starts[bus_idx], &field.offsets[bus_idx]);
+                (quote![#start], quote![#end])
+            })
+            .collect();
+        padding_ranges.push(ranges);
+    }
+}
+
+/// Returns the name of a bus, for use in error messages.
+fn bus_name(bus: &TypePath) -> &Ident {
+    &bus.path.segments.last().expect("empty bus path").ident
+}
+
+/// A register block's const generic parameters, in the forms the generated code uses. These are all
+/// empty if the block has no generic parameters.
+struct BlockGenerics {
+    /// `<const N: usize, ...>`
+    params: TokenStream,
+    /// `<N, ...>`
+    args: TokenStream,
+    /// `const N: usize, ...,` (for parameter lists that continue with a bus parameter).
+    leading_params: TokenStream,
+    /// `N, ...,`
+    leading_args: TokenStream,
+    /// `, const N: usize, ...` (for parameter lists that start with a lifetime or type parameter).
+    trailing_params: TokenStream,
+    /// `, N, ...`
+    trailing_args: TokenStream,
+    /// The type parameter of the PhantomData that a type alias with parameters `<leading_params B>`
+    /// uses when its field is not compiled in, which must use every parameter: `B` or
+    /// `(B, [(); N], ...)`.
+    phantom: TokenStream,
+    /// The equivalent of `phantom` for type aliases with parameters `<'h trailing_params>`:
+    /// `&'h ()` or `(&'h (), [(); N], ...)`.
+    fake_phantom: TokenStream,
+}
+
+impl BlockGenerics {
+    fn new(names: &[Ident]) -> BlockGenerics {
+        let (params, args, phantom, fake_phantom) = match names.is_empty() {
+            true => (quote![], quote![], quote![B], quote![&'h ()]),
+            false => (
+                quote![<#(const #names: usize),*>],
+                quote![<#(#names),*>],
+                quote![(B, #([(); #names]),*)],
+                quote![(&'h (), #([(); #names]),*)],
+            ),
+        };
+        BlockGenerics {
+            params,
+            args,
+            leading_params: quote![#(const #names: usize,)*],
+            leading_args: quote![#(#names,)*],
+            trailing_params: quote![#(, const #names: usize)*],
+            trailing_args: quote![#(, #names)*],
+            phantom,
+            fake_phantom,
+        }
+    }
+}
@@ -4,7 +4,8 @@
 // Copyright Better Bytes 2026.
 
 use crate::block::{
-    bus_doc_comment, field_struct_doc_comment, interface_doc_comment, real_doc_comment,
+    bus_doc_comment, debug_dump_doc_comment, field_struct_doc_comment, interface_doc_comment,
+    real_doc_comment,
 };
 use crate::{new_doc_comment, register_map, test_util::assert_tokens_eq, Env::ProcMacro};
 use quote::quote;
@@ -35,6 +36,7 @@ fn all_field_types_example() {
     let array_definition_comment = field_struct_doc_comment(&parse_quote![array_definition]);
     let flat_array_definition_comment =
         field_struct_doc_comment(&parse_quote![flat_array_definition]);
+    let debug_dump_comment = debug_dump_doc_comment();
     let expected = quote! {
         pub mod foo {
             #![allow(non_camel_case_types)]
@@ -63,10 +65,11 @@ fn all_field_types_example() {
                 fn flat_array_reference(self) -> Self::flat_array_reference;
             }
             pub mod lens {
-                pub enum array_definition<const N: usize> {}
+                #[allow(unused_imports)] use super::*;
+                pub enum array_definition<const LEVEL: usize> {}
                 impl ::tock_registers::array::Len for array_definition<0usize> { const LEN: usize = 2; }
                 impl ::tock_registers::array::Len for array_definition<1usize> { const LEN: usize = 3; }
-                pub enum array_reference<const N: usize> {}
+                pub enum array_reference<const LEVEL: usize> {}
                 impl ::tock_registers::array::Len for array_reference<0usize> { const LEN: usize = 2; }
                 impl ::tock_registers::array::Len for array_reference<1usize> { const LEN: usize = 3; }
                 pub enum flat_array_definition {}
@@ -84,6 +87,7 @@ fn all_field_types_example() {
                 ::tock_registers::DataTypeBus<u8> + c::Bus + sealed::Bus
             {
                 const SIZE: usize;
+                const ALIGNMENT: usize;
                 const scalar_definition_offset: usize = 0;
                 const array_definition_offset: usize = 1;
                 const scalar_reference_offset: usize = 8;
@@ -94,24 +98,43 @@ fn all_field_types_example() {
             impl Bus for Mmio32 {
                 const SIZE: usize = 17 + <<Real<Mmio32> as Interface>::flat_array_reference
                     as ::tock_registers::Span>::SIZE;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio32> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::array_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::scalar_reference as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::flat_array_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::flat_array_reference as ::tock_registers::Span>::ALIGNMENT,
+                ]);
             }
             impl sealed::Bus for Mmio32 {}
             impl Bus for Mmio64 {
                 const SIZE: usize = 17 + <<Real<Mmio64> as Interface>::flat_array_reference
                     as ::tock_registers::Span>::SIZE;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio64> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::array_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::scalar_reference as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::flat_array_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::flat_array_reference as ::tock_registers::Span>::ALIGNMENT,
+                ]);
             }
             impl sealed::Bus for Mmio64 {}
             impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {
                 const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
             }
             impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
             const _: () = {
                 // The call to identity() in each assert! prevents the clippy::eq_op lint from
                 // triggering.
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `scalar_definition` is misaligned for bus Mmio32");
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `scalar_definition` is misaligned for bus Mmio64");
                 assert!(0 == ::tock_registers::internal::core::convert::identity(0),
                     "offset mismatch for bus Mmio32");
                 assert!(0 == ::tock_registers::internal::core::convert::identity(0),
                     "offset mismatch for bus Mmio64");
+                assert!(1 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::array_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `array_definition` is misaligned for bus Mmio32");
+                assert!(1 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::array_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `array_definition` is misaligned for bus Mmio64");
                 assert!(1 == ::tock_registers::internal::core::convert::identity(0 + <<Real<Mmio32>
                     as Interface>::scalar_definition as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio32");
@@ -124,22 +147,46 @@ fn all_field_types_example() {
                 assert!(7 == ::tock_registers::internal::core::convert::identity(1 + <<Real<Mmio64>
                     as Interface>::array_definition as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio64");
+                assert!(8 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::scalar_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `scalar_reference` is misaligned for bus Mmio32");
+                assert!(8 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::scalar_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `scalar_reference` is misaligned for bus Mmio64");
                 assert!(8 == ::tock_registers::internal::core::convert::identity(7 + 1),
                     "offset mismatch for bus Mmio32");
                 assert!(8 == ::tock_registers::internal::core::convert::identity(7 + 1),
                     "offset mismatch for bus Mmio64");
+                assert!(9 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `array_reference` is misaligned for bus Mmio32");
+                assert!(9 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `array_reference` is misaligned for bus Mmio64");
                 assert!(9 == ::tock_registers::internal::core::convert::identity(8 + <<Real<Mmio32>
                     as Interface>::scalar_reference as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio32");
                 assert!(9 == ::tock_registers::internal::core::convert::identity(8 + <<Real<Mmio64>
                     as Interface>::scalar_reference as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio64");
+                assert!(15 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::flat_array_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `flat_array_definition` is misaligned for bus Mmio32");
+                assert!(15 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::flat_array_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `flat_array_definition` is misaligned for bus Mmio64");
                 assert!(15 == ::tock_registers::internal::core::convert::identity(9 +
                     <<Real<Mmio32> as Interface>::array_reference as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio32");
                 assert!(15 == ::tock_registers::internal::core::convert::identity(9 +
                     <<Real<Mmio64> as Interface>::array_reference as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio64");
+                assert!(17 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::flat_array_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `flat_array_reference` is misaligned for bus Mmio32");
+                assert!(17 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::flat_array_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `flat_array_reference` is misaligned for bus Mmio64");
                 assert!(17 == ::tock_registers::internal::core::convert::identity(15 +
                     <<Real<Mmio32> as Interface>::flat_array_definition
                     as ::tock_registers::Span>::SIZE), "offset mismatch for bus Mmio32");
@@ -220,11 +267,124 @@ fn all_field_types_example() {
             unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
                 type Address = B;
                 const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
                 type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                ::tock_registers::layout::Layout::Block {
+                    fields: &[
+                        ::tock_registers::layout::FieldInfo::new::<real_scalar_definition<B> >("scalar_definition", "", <B as Bus>::scalar_definition_offset),
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<
+                                ::tock_registers::RealRegisterArray<
+                                    real_array_definition<B>,
+                                    lens::array_definition<0usize>
+                                >,
+                                lens::array_definition<1usize>
+                            >
+                        >("array_definition", "", <B as Bus>::array_definition_offset),
+                        ::tock_registers::layout::FieldInfo::new::<a::Real<B> >("scalar_reference", "", <B as Bus>::scalar_reference_offset),
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<
+                                ::tock_registers::RealRegisterArray<
+                                    b::Real<B>,
+                                    lens::array_reference<0usize>
+                                >,
+                                lens::array_reference<1usize>
+                            >
+                        >("array_reference", "", <B as Bus>::array_reference_offset),
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<
+                                real_flat_array_definition<B>,
+                                lens::flat_array_definition
+                            >
+                        >("flat_array_definition", "", <B as Bus>::flat_array_definition_offset),
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<
+                                c::Real<B>,
+                                lens::flat_array_reference
+                            >
+                        >("flat_array_reference", "", <B as Bus>::flat_array_reference_offset),
+                    ]
+                };
+            }
+            // Register blocks that contain this block only format it if is_dumped returns true, which
+            // it always does for register blocks (it can return false for register definitions).
+            #[doc(hidden)]
+            pub fn is_dumped<I: Interface>(_probe: ::tock_registers::internal::dump::Probe<I>) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<I: Interface>(
+                registers: I,
+                f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>,
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct("foo");
+                // Register definitions are formatted if their register type implements Read (and not
+                // ReadDestructive), which the traits in internal::dump check. The macro does not know
+                // whether referenced layouts are readable, so references call the referenced
+                // module's is_dumped.
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::scalar_definition(registers))).readable() {
+                    fields.field("scalar_definition", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::scalar_definition(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                if (&&&::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        ::tock_registers::internal::dump::Probe::of(&<I as Interface>::array_definition(registers))
+                    )
+                )).readable() {
+                    fields.field("array_definition", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface>::array_definition(registers), f,
+                            |register, f| ::tock_registers::internal::fmt_array(register, f,
+                                |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                    .fmt_register(register, f,
+                                        (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt())))));
+                }
+                if a::is_dumped::<_>(::tock_registers::internal::dump::Probe::of(&<I as Interface>::scalar_reference(registers))) {
+                    fields.field("scalar_reference", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::scalar_reference(registers), f,
+                            a::fmt_dump::<_>)));
+                }
+                if b::is_dumped::<_>(::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        ::tock_registers::internal::dump::Probe::of(&<I as Interface>::array_reference(registers))
+                    )
+                )) {
+                    fields.field("array_reference", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface>::array_reference(registers), f,
+                            |register, f| ::tock_registers::internal::fmt_array(register, f,
+                                b::fmt_dump::<_>))));
+                }
+                if (&&&::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::Probe::of(&<I as Interface>::flat_array_definition(registers))
+                )).readable() {
+                    fields.field("flat_array_definition", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface>::flat_array_definition(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                if c::is_dumped::<_>(::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::Probe::of(&<I as Interface>::flat_array_reference(registers))
+                )) {
+                    fields.field("flat_array_reference", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface>::flat_array_reference(registers), f,
+                            c::fmt_dump::<_>)));
+                }
+                fields.finish()
+            }
+            #debug_dump_comment
+            pub fn debug_dump<I: Interface>(registers: I) -> ::tock_registers::debug::DebugDump<I> {
+                ::tock_registers::debug::DebugDump::new(registers, fmt_dump::<I>)
+            }
             #scalar_definition_comment #[derive(Clone)] pub struct real_scalar_definition<B: Bus> {
                 address: B,
                 _phantom: ::tock_registers::internal::RealPhantom,
@@ -239,6 +399,7 @@ fn all_field_types_example() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_scalar_definition<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
@@ -247,6 +408,13 @@ fn all_field_types_example() {
             impl<B: Bus> ::tock_registers::Register for real_scalar_definition<B> {
                 type DataType = u8;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_scalar_definition<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Dance"],
+                    };
+            }
             Read!(real_impl, real_scalar_definition, u8,,);
             // Since macros cannot accept generic arguments, the generics are instead detached from
             // the operation path and moved into an argument of the macro invocation.
@@ -265,6 +433,7 @@ fn all_field_types_example() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_array_definition<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
@@ -273,6 +442,13 @@ fn all_field_types_example() {
             impl<B: Bus> ::tock_registers::Register for real_array_definition<B> {
                 type DataType = u8;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_array_definition<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Write"],
+                    };
+            }
             Read!(real_impl, real_array_definition, u8,,);
             Write!(real_impl, real_array_definition, u8,,);
             #flat_array_definition_comment #[derive(Clone)]
@@ -290,6 +466,7 @@ fn all_field_types_example() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_flat_array_definition<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
@@ -299,6 +476,13 @@ fn all_field_types_example() {
             impl<B: Bus> ::tock_registers::Register for real_flat_array_definition<B> {
                 type DataType = u8;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_flat_array_definition<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
             Read!(real_impl, real_flat_array_definition, u8,,);
         }
     };
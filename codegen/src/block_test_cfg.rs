@@ -0,0 +1,334 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::block::{
+    bus_doc_comment, debug_dump_doc_comment, field_struct_doc_comment, interface_doc_comment,
+    real_doc_comment,
+};
+use crate::{new_doc_comment, register_map, test_util::assert_tokens_eq, Env::ProcMacro};
+use quote::quote;
+use syn::parse_quote;
+
+/// Tests a register block with #[cfg] attributes on the block and on consecutive fields, the last
+/// of which is the block's last field.
+#[test]
+fn cfg() {
+    let input = quote! {
+        ::tock_registers
+        #[bus(Mmio32)]
+        #[cfg(unix)]
+        pub foo {
+            0 => a: u8 { Read },
+            #[cfg(feature = "b")] 1 => b: u8 { Read },
+            #[cfg(feature = "c")] 2 => c: u8 { Read },
+        }
+    };
+    let interface_comment = interface_doc_comment();
+    let bus_comment = bus_doc_comment();
+    let real_comment = real_doc_comment();
+    let new_comment = new_doc_comment();
+    let a_comment = field_struct_doc_comment(&parse_quote![a]);
+    let b_comment = field_struct_doc_comment(&parse_quote![b]);
+    let c_comment = field_struct_doc_comment(&parse_quote![c]);
+    let debug_dump_comment = debug_dump_doc_comment();
+    let expected = quote! {
+        // The layout's #[cfg] attributes are applied to the generated module.
+        #[cfg(unix)]
+        pub mod foo {
+            #![allow(non_camel_case_types)]
+            use super::*;
+            // Items generated for a field with #[cfg] attributes have the same attributes.
+            #interface_comment pub trait Interface: ::tock_registers::internal::core::marker::Copy {
+                type a: ::tock_registers::Register<DataType = u8> + Read;
+                fn a(self) -> Self::a;
+                #[cfg(feature = "b")] type b: ::tock_registers::Register<DataType = u8> + Read;
+                #[cfg(feature = "b")] fn b(self) -> Self::b;
+                #[cfg(feature = "c")] type c: ::tock_registers::Register<DataType = u8> + Read;
+                #[cfg(feature = "c")] fn c(self) -> Self::c;
+            }
+            pub mod lens {
+                #[allow(unused_imports)] use super::*;
+            }
+            // Supertraits and where clauses cannot have attributes, so the bounds a field with
+            // #[cfg] attributes adds to Bus and the Interface impl are provided by helper traits
+            // that are only bounded when the field is compiled in. The real type is provided by a
+            // type alias, as it does not exist when the field is not compiled in.
+            #[doc(hidden)] pub mod cfg_bounds {
+                use super::*;
+                #[cfg(feature = "b")] pub trait b_1_bus: ::tock_registers::DataTypeBus<u8> {}
+                #[cfg(feature = "b")]
+                impl<B> b_1_bus for B where B: ::tock_registers::DataTypeBus<u8> {}
+                #[cfg(not(all(feature = "b")))] pub trait b_1_bus {}
+                #[cfg(not(all(feature = "b")))] impl<B> b_1_bus for B {}
+                #[cfg(feature = "b")]
+                pub trait b_1_interface: ::tock_registers::Register<DataType = u8> + Read {}
+                #[cfg(feature = "b")]
+                impl<T> b_1_interface for T where T: ::tock_registers::Register<DataType = u8> + Read {}
+                #[cfg(not(all(feature = "b")))] pub trait b_1_interface {}
+                #[cfg(not(all(feature = "b")))] impl<T> b_1_interface for T {}
+                #[cfg(feature = "b")] pub type b_1_real<B> = real_b<B>;
+                #[cfg(not(all(feature = "b")))]
+                pub type b_1_real<B> = ::tock_registers::internal::core::marker::PhantomData<B>;
+                #[cfg(feature = "c")] pub trait c_2_bus: ::tock_registers::DataTypeBus<u8> {}
+                #[cfg(feature = "c")]
+                impl<B> c_2_bus for B where B: ::tock_registers::DataTypeBus<u8> {}
+                #[cfg(not(all(feature = "c")))] pub trait c_2_bus {}
+                #[cfg(not(all(feature = "c")))] impl<B> c_2_bus for B {}
+                #[cfg(feature = "c")]
+                pub trait c_2_interface: ::tock_registers::Register<DataType = u8> + Read {}
+                #[cfg(feature = "c")]
+                impl<T> c_2_interface for T where T: ::tock_registers::Register<DataType = u8> + Read {}
+                #[cfg(not(all(feature = "c")))] pub trait c_2_interface {}
+                #[cfg(not(all(feature = "c")))] impl<T> c_2_interface for T {}
+                #[cfg(feature = "c")] pub type c_2_real<B> = real_c<B>;
+                #[cfg(not(all(feature = "c")))]
+                pub type c_2_real<B> = ::tock_registers::internal::core::marker::PhantomData<B>;
+            }
+            #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
+            pub trait Bus: ::tock_registers::Address + ::tock_registers::DataTypeBus<u8> +
+                cfg_bounds::b_1_bus + cfg_bounds::c_2_bus + sealed::Bus
+            {
+                const SIZE: usize;
+                const ALIGNMENT: usize;
+                const a_offset: usize = 0;
+                #[cfg(feature = "b")] const b_offset: usize = 1;
+                #[cfg(feature = "c")] const c_offset: usize = 2;
+            }
+            impl Bus for Mmio32 {
+                // If the last field is not compiled in, the block ends at its offset.
+                #[cfg(feature = "c")] const SIZE: usize = 2 + <<Real<Mmio32> as Interface>::c
+                    as ::tock_registers::Span>::SIZE;
+                #[cfg(not(all(feature = "c")))] const SIZE: usize = 2;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::ALIGNMENT,
+                    #[cfg(feature = "b")]
+                    <<Real<Mmio32> as Interface>::b as ::tock_registers::Span>::ALIGNMENT,
+                    #[cfg(feature = "c")]
+                    <<Real<Mmio32> as Interface>::c as ::tock_registers::Span>::ALIGNMENT,
+                ]);
+            }
+            impl sealed::Bus for Mmio32 {}
+            impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {
+                const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
+            }
+            impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            // Offset tests that involve a field with #[cfg] attributes are only compiled in if
+            // the field is. If it is not, the next field (or the block's end) must not be before
+            // the end of the field before it.
+            const _: () = {
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `a` is misaligned for bus Mmio32");
+                assert!(0 == ::tock_registers::internal::core::convert::identity(0),
+                    "offset mismatch for bus Mmio32");
+                #[cfg(feature = "b")]
+                assert!(1 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::b as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `b` is misaligned for bus Mmio32");
+                #[cfg(feature = "b")]
+                assert!(1 == ::tock_registers::internal::core::convert::identity(0 +
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::SIZE),
+                    "offset mismatch for bus Mmio32");
+                #[cfg(feature = "c")]
+                assert!(2 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::c as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `c` is misaligned for bus Mmio32");
+                #[cfg(feature = "b")] #[cfg(feature = "c")]
+                assert!(2 == ::tock_registers::internal::core::convert::identity(1 +
+                    <<Real<Mmio32> as Interface>::b as ::tock_registers::Span>::SIZE),
+                    "offset mismatch for bus Mmio32");
+                #[cfg(not(all(feature = "b")))] #[cfg(feature = "c")]
+                assert!(2 >= ::tock_registers::internal::core::convert::identity(0 +
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::SIZE),
+                    "offset overlaps the previous field for bus Mmio32");
+                #[cfg(not(all(feature = "c")))] #[cfg(feature = "b")]
+                assert!(2 >= ::tock_registers::internal::core::convert::identity(1 +
+                    <<Real<Mmio32> as Interface>::b as ::tock_registers::Span>::SIZE),
+                    "offset overlaps the previous field for bus Mmio32");
+            };
+            mod sealed { pub trait Bus {} }
+            #real_comment #[derive(Clone)] pub struct Real<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> Real<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for Real<B> {}
+            impl<B: Bus> Interface for Real<B> where
+                real_a<B>: ::tock_registers::Register<DataType = u8> + Read,
+                cfg_bounds::b_1_real<B>: cfg_bounds::b_1_interface,
+                cfg_bounds::c_2_real<B>: cfg_bounds::c_2_interface,
+            {
+                type a = real_a<B>;
+                fn a(self) -> Self::a {
+                    unsafe { Self::a::new(self.address.byte_add(<B as Bus>::a_offset)) }
+                }
+                #[cfg(feature = "b")] type b = real_b<B>;
+                #[cfg(feature = "b")] fn b(self) -> Self::b {
+                    unsafe { Self::b::new(self.address.byte_add(<B as Bus>::b_offset)) }
+                }
+                #[cfg(feature = "c")] type c = real_c<B>;
+                #[cfg(feature = "c")] fn c(self) -> Self::c {
+                    unsafe { Self::c::new(self.address.byte_add(<B as Bus>::c_offset)) }
+                }
+            }
+            unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
+                type Address = B;
+                const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                ::tock_registers::layout::Layout::Block {
+                    fields: &[
+                        ::tock_registers::layout::FieldInfo::new::<real_a<B> >("a", "", <B as Bus>::a_offset),
+                        #[cfg(feature = "b")]
+                        ::tock_registers::layout::FieldInfo::new::<real_b<B> >("b", "", <B as Bus>::b_offset),
+                        #[cfg(feature = "c")]
+                        ::tock_registers::layout::FieldInfo::new::<real_c<B> >("c", "", <B as Bus>::c_offset),
+                    ]
+                };
+            }
+            #[doc(hidden)]
+            pub fn is_dumped<I: Interface>(_probe: ::tock_registers::internal::dump::Probe<I>) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<I: Interface>(
+                registers: I,
+                f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>,
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct("foo");
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::a(registers))).readable() {
+                    fields.field("a", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::a(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                #[cfg(feature = "b")] if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::b(registers))).readable() {
+                    fields.field("b", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::b(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                #[cfg(feature = "c")] if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::c(registers))).readable() {
+                    fields.field("c", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::c(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                fields.finish()
+            }
+            #debug_dump_comment
+            pub fn debug_dump<I: Interface>(registers: I) -> ::tock_registers::debug::DebugDump<I> {
+                ::tock_registers::debug::DebugDump::new(registers, fmt_dump::<I>)
+            }
+            #a_comment #[derive(Clone)] pub struct real_a<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> real_a<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for real_a<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for real_a<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = real_a<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for real_a<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_a<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
+            Read!(real_impl, real_a, u8,,);
+            #[cfg(feature = "b")] #b_comment #[derive(Clone)]
+            pub struct real_b<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            #[cfg(feature = "b")] impl<B: Bus> real_b<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            #[cfg(feature = "b")]
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for real_b<B> {}
+            #[cfg(feature = "b")] unsafe impl<B: Bus> ::tock_registers::Span for real_b<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = real_b<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            #[cfg(feature = "b")]
+            impl<B: Bus> ::tock_registers::Register for real_b<B> { type DataType = u8; }
+            #[cfg(feature = "b")]
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_b<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
+            #[cfg(feature = "b")] Read!(real_impl, real_b, u8,,);
+            #[cfg(feature = "c")] #c_comment #[derive(Clone)]
+            pub struct real_c<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            #[cfg(feature = "c")] impl<B: Bus> real_c<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            #[cfg(feature = "c")]
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for real_c<B> {}
+            #[cfg(feature = "c")] unsafe impl<B: Bus> ::tock_registers::Span for real_c<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = real_c<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            #[cfg(feature = "c")]
+            impl<B: Bus> ::tock_registers::Register for real_c<B> { type DataType = u8; }
+            #[cfg(feature = "c")]
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_c<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
+            #[cfg(feature = "c")] Read!(real_impl, real_c, u8,,);
+        }
+    };
+    assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
+}
@@ -73,12 +73,13 @@ fn doc_comments() {
                 fn array_reference(self) -> Self::array_reference;
             }
             pub mod lens {
-                pub enum array_definition<const N: usize> {}
+                #[allow(unused_imports)] use super::*;
+                pub enum array_definition<const LEVEL: usize> {}
                 impl ::tock_registers::array::Len for
                     array_definition<0usize> { const LEN: usize = 2; }
                 impl ::tock_registers::array::Len for
                     array_definition<1usize> { const LEN: usize = 3; }
-                pub enum array_reference<const N: usize> {}
+                pub enum array_reference<const LEVEL: usize> {}
                 impl ::tock_registers::array::Len for
                     array_reference<0usize> { const LEN: usize = 2; }
                 impl ::tock_registers::array::Len for
@@ -90,6 +91,7 @@ fn doc_comments() {
                 ::tock_registers::DataTypeBus<u8> + a::Bus + b::Bus + sealed::Bus
             {
                 const SIZE: usize;
+                const ALIGNMENT: usize;
                 const scalar_definition_offset: usize = 0;
                 const array_definition_offset: usize = 1;
                 const scalar_reference_offset: usize = 7;
@@ -98,34 +100,65 @@ fn doc_comments() {
             impl Bus for Mmio32 {
                 const SIZE: usize = 8 +
                     <<Real<Mmio32> as Interface>::array_reference as ::tock_registers::Span>::SIZE;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio32> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::array_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::scalar_reference as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT,
+                ]);
             }
             impl sealed::Bus for Mmio32 {}
             impl Bus for Mmio64 {
                 const SIZE: usize = 8 +
                     <<Real<Mmio64> as Interface>::array_reference as ::tock_registers::Span>::SIZE;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio64> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::array_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::scalar_reference as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT,
+                ]);
             }
             impl sealed::Bus for Mmio64 {}
             impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {
                 const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
             }
             impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
             const _: () = {
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `scalar_definition` is misaligned for bus Mmio32");
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `scalar_definition` is misaligned for bus Mmio64");
                 assert!(0 == ::tock_registers::internal::core::convert::identity(0),
                     "offset mismatch for bus Mmio32");
                 assert!(0 == ::tock_registers::internal::core::convert::identity(0),
                     "offset mismatch for bus Mmio64");
+                assert!(1 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::array_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `array_definition` is misaligned for bus Mmio32");
+                assert!(1 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::array_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `array_definition` is misaligned for bus Mmio64");
                 assert!(1 == ::tock_registers::internal::core::convert::identity(0 + <<Real<Mmio32>
                     as Interface>::scalar_definition as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio32");
                 assert!(1 == ::tock_registers::internal::core::convert::identity(0 + <<Real<Mmio64>
                     as Interface>::scalar_definition as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio64");
+                assert!(7 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::scalar_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `scalar_reference` is misaligned for bus Mmio32");
+                assert!(7 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::scalar_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `scalar_reference` is misaligned for bus Mmio64");
                 assert!(7 == ::tock_registers::internal::core::convert::identity(1 + <<Real<Mmio32>
                     as Interface>::array_definition as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio32");
                 assert!(7 == ::tock_registers::internal::core::convert::identity(1 + <<Real<Mmio64>
                     as Interface>::array_definition as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio64");
+                assert!(8 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `array_reference` is misaligned for bus Mmio32");
+                assert!(8 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `array_reference` is misaligned for bus Mmio64");
                 assert!(8 == ::tock_registers::internal::core::convert::identity(7 + <<Real<Mmio32>
                     as Interface>::scalar_reference as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio32");
@@ -199,11 +232,109 @@ fn doc_comments() {
             unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
                 type Address = B;
                 const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
                 type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                ::tock_registers::layout::Layout::Block {
+                    fields: &[
+                        ::tock_registers::layout::FieldInfo::new::<real_scalar_definition<B> >(
+                            "scalar_definition",
+                            "Doc comment G Doc comment H",
+                            <B as Bus>::scalar_definition_offset
+                        ),
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<
+                                ::tock_registers::RealRegisterArray<
+                                    real_array_definition<B>,
+                                    lens::array_definition<0usize>
+                                >,
+                                lens::array_definition<1usize>
+                            >
+                        >(
+                            "array_definition",
+                            "Doc comment I Doc comment J",
+                            <B as Bus>::array_definition_offset
+                        ),
+                        ::tock_registers::layout::FieldInfo::new::<a::Real<B> >(
+                            "scalar_reference",
+                            "Doc comment K Doc comment L",
+                            <B as Bus>::scalar_reference_offset
+                        ),
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<
+                                ::tock_registers::RealRegisterArray<
+                                    b::Real<B>,
+                                    lens::array_reference<0usize>
+                                >,
+                                lens::array_reference<1usize>
+                            >
+                        >(
+                            "array_reference",
+                            "Doc comment M Doc comment N",
+                            <B as Bus>::array_reference_offset
+                        ),
+                    ]
+                };
+            }
+            #[doc(hidden)]
+            pub fn is_dumped<I: Interface>(_probe: ::tock_registers::internal::dump::Probe<I>) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<I: Interface>(
+                registers: I,
+                f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>,
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct("foo");
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::scalar_definition(registers))).readable() {
+                    fields.field("scalar_definition", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::scalar_definition(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                if (&&&::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        ::tock_registers::internal::dump::Probe::of(&<I as Interface>::array_definition(registers))
+                    )
+                )).readable() {
+                    fields.field("array_definition", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface>::array_definition(registers), f,
+                            |register, f| ::tock_registers::internal::fmt_array(register, f,
+                                |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                    .fmt_register(register, f,
+                                        (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt())))));
+                }
+                if a::is_dumped::<_>(::tock_registers::internal::dump::Probe::of(&<I as Interface>::scalar_reference(registers))) {
+                    fields.field("scalar_reference", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::scalar_reference(registers), f,
+                            a::fmt_dump::<_>)));
+                }
+                if b::is_dumped::<_>(::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        ::tock_registers::internal::dump::Probe::of(&<I as Interface>::array_reference(registers))
+                    )
+                )) {
+                    fields.field("array_reference", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface>::array_reference(registers), f,
+                            |register, f| ::tock_registers::internal::fmt_array(register, f,
+                                b::fmt_dump::<_>))));
+                }
+                fields.finish()
+            }
+            /// Returns a value whose `Debug` implementation reads and prints every readable register
+            /// in this register block, including the registers of nested arrays and register blocks.
+            /// Registers without the `Read` operation, and registers with the `ReadDestructive`
+            /// operation (whose reads have side effects), are not read.
+            pub fn debug_dump<I: Interface>(registers: I) -> ::tock_registers::debug::DebugDump<I> {
+                ::tock_registers::debug::DebugDump::new(registers, fmt_dump::<I>)
+            }
             #[doc =
                 "Struct that provides access to the `scalar_definition` register on real hardware."]
             #[derive(Clone)] pub struct real_scalar_definition<B: Bus> {
@@ -231,6 +362,7 @@ fn doc_comments() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_scalar_definition<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
@@ -239,6 +371,13 @@ fn doc_comments() {
             impl<B: Bus> ::tock_registers::Register for real_scalar_definition<B> {
                 type DataType = u8;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_scalar_definition<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Write"],
+                    };
+            }
             Read!(real_impl, real_scalar_definition, u8,,);
             Write!(real_impl, real_scalar_definition, u8,,);
             #[doc =
@@ -268,6 +407,7 @@ fn doc_comments() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_array_definition<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
@@ -276,6 +416,13 @@ fn doc_comments() {
             impl<B: Bus> ::tock_registers::Register for real_array_definition<B> {
                 type DataType = u8;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_array_definition<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Write"],
+                    };
+            }
             Read!(real_impl, real_array_definition, u8,,);
             Write!(real_impl, real_array_definition, u8,,);
         }
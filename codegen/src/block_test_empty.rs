@@ -3,7 +3,9 @@
 // Copyright Tock Contributors 2026.
 // Copyright Better Bytes 2026.
 
-use crate::block::{bus_doc_comment, interface_doc_comment, real_doc_comment};
+use crate::block::{
+    bus_doc_comment, debug_dump_doc_comment, interface_doc_comment, real_doc_comment,
+};
 use crate::{new_doc_comment, register_map, test_util::assert_tokens_eq, Env::External};
 use quote::quote;
 
@@ -19,20 +21,28 @@ fn empty() {
     let bus_comment = bus_doc_comment();
     let real_comment = real_doc_comment();
     let new_comment = new_doc_comment();
+    let debug_dump_comment = debug_dump_doc_comment();
     let expected = quote! {
         pub mod foo {
             #![allow(non_camel_case_types,dead_code,non_upper_case_globals)] use super::*;
             #interface_comment
             pub trait Interface: ::tock_registers::internal::core::marker::Copy {}
-            pub mod lens {}
+            pub mod lens {
+                #[allow(unused_imports)] use super::*;
+            }
             #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
             pub trait Bus: ::tock_registers::Address + sealed::Bus {
                 const SIZE: usize;
+                const ALIGNMENT: usize;
+            }
+            impl Bus for Mmio32 {
+                const SIZE: usize = 0;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[]);
             }
-            impl Bus for Mmio32 { const SIZE: usize = 0; }
             impl sealed::Bus for Mmio32 {}
             impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {
                 const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
             }
             impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
             const _: () = {};
@@ -51,11 +61,35 @@ fn empty() {
             unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
                 type Address = B;
                 const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
                 type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                ::tock_registers::layout::Layout::Block {
+                    fields: &[]
+                };
+            }
+            #[doc(hidden)]
+            pub fn is_dumped<I: Interface>(_probe: ::tock_registers::internal::dump::Probe<I>) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<I: Interface>(
+                registers: I,
+                f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>,
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct("foo");
+                fields.finish()
+            }
+            #debug_dump_comment
+            pub fn debug_dump<I: Interface>(registers: I) -> ::tock_registers::debug::DebugDump<I> {
+                ::tock_registers::debug::DebugDump::new(registers, fmt_dump::<I>)
+            }
         }
     };
     assert_tokens_eq(register_map(input, External).unwrap(), expected);
@@ -0,0 +1,291 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::block::{
+    bus_doc_comment, debug_dump_doc_comment, fake_doc_comment, field_fake_doc_comment,
+    field_struct_doc_comment, interface_doc_comment, real_doc_comment,
+};
+use crate::{
+    new_doc_comment, new_fake_doc_comment, register_map, test_util::assert_tokens_eq,
+    Env::ProcMacro,
+};
+use quote::quote;
+use syn::parse_quote;
+
+/// Tests the Fake struct generated for a register block.
+#[test]
+fn fake() {
+    let input = quote! {
+        ::tock_registers
+        #[bus(Mmio32)]
+        #[fake]
+        pub foo {
+            /// Doc comment
+            0 => scalar_definition: u8 { Read },
+            1 => array_reference: [[b; 2]; 3],
+        }
+    };
+    let interface_comment = interface_doc_comment();
+    let bus_comment = bus_doc_comment();
+    let real_comment = real_doc_comment();
+    let new_comment = new_doc_comment();
+    let scalar_definition_comment = field_struct_doc_comment(&parse_quote![scalar_definition]);
+    let scalar_definition_fake_comment = field_fake_doc_comment(&parse_quote![scalar_definition]);
+    let fake_comment = fake_doc_comment();
+    let new_fake_comment = new_fake_doc_comment();
+    let debug_dump_comment = debug_dump_doc_comment();
+    let expected = quote! {
+        pub mod foo {
+            #![allow(non_camel_case_types)]
+            use super::*;
+            #interface_comment pub trait Interface: ::tock_registers::internal::core::marker::Copy {
+                type scalar_definition: ::tock_registers::Register<DataType = u8> + Read;
+                /// Doc comment
+                fn scalar_definition(self) -> Self::scalar_definition;
+                type array_reference: ::tock_registers::RegisterArray<
+                    lens::array_reference<1usize>, Element: ::tock_registers::RegisterArray<
+                        lens::array_reference<0usize>, Element: b::Interface> >;
+                fn array_reference(self) -> Self::array_reference;
+            }
+            pub mod lens {
+                #[allow(unused_imports)] use super::*;
+                pub enum array_reference<const LEVEL: usize> {}
+                impl ::tock_registers::array::Len for array_reference<0usize> { const LEN: usize = 2; }
+                impl ::tock_registers::array::Len for array_reference<1usize> { const LEN: usize = 3; }
+            }
+            #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
+            pub trait Bus: ::tock_registers::Address + ::tock_registers::DataTypeBus<u8> + b::Bus +
+                sealed::Bus
+            {
+                const SIZE: usize;
+                const ALIGNMENT: usize;
+                const scalar_definition_offset: usize = 0;
+                const array_reference_offset: usize = 1;
+            }
+            impl Bus for Mmio32 {
+                const SIZE: usize = 1 + <<Real<Mmio32> as Interface>::array_reference
+                    as ::tock_registers::Span>::SIZE;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio32> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT,
+                ]);
+            }
+            impl sealed::Bus for Mmio32 {}
+            impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {
+                const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
+            }
+            impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            const _: () = {
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::scalar_definition as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `scalar_definition` is misaligned for bus Mmio32");
+                assert!(0 == ::tock_registers::internal::core::convert::identity(0),
+                    "offset mismatch for bus Mmio32");
+                assert!(1 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::array_reference as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `array_reference` is misaligned for bus Mmio32");
+                assert!(1 == ::tock_registers::internal::core::convert::identity(0 + <<Real<Mmio32>
+                    as Interface>::scalar_definition as ::tock_registers::Span>::SIZE),
+                    "offset mismatch for bus Mmio32");
+            };
+            mod sealed { pub trait Bus {} }
+            #real_comment #[derive(Clone)] pub struct Real<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> Real<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for Real<B> {}
+            impl<B: Bus> Interface for Real<B> where
+                real_scalar_definition<B>: ::tock_registers::Register<DataType = u8> + Read,
+                b::Real<B>: b::Interface,
+            {
+                type scalar_definition = real_scalar_definition<B>;
+                fn scalar_definition(self) -> Self::scalar_definition {
+                    unsafe {
+                        Self::scalar_definition::new(
+                            self.address.byte_add(<B as Bus>::scalar_definition_offset))
+                    }
+                }
+                type array_reference = ::tock_registers::RealRegisterArray<
+                    ::tock_registers::RealRegisterArray<b::Real<B>, lens::array_reference<0usize> >,
+                    lens::array_reference<1usize> >;
+                fn array_reference(self) -> Self::array_reference {
+                    unsafe {
+                        Self::array_reference::new(
+                            self.address.byte_add(<B as Bus>::array_reference_offset))
+                    }
+                }
+            }
+            unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
+                type Address = B;
+                const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                ::tock_registers::layout::Layout::Block {
+                    fields: &[
+                        ::tock_registers::layout::FieldInfo::new::<real_scalar_definition<B> >(
+                            "scalar_definition",
+                            "Doc comment",
+                            <B as Bus>::scalar_definition_offset
+                        ),
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<
+                                ::tock_registers::RealRegisterArray<
+                                    b::Real<B>,
+                                    lens::array_reference<0usize>
+                                >,
+                                lens::array_reference<1usize>
+                            >
+                        >("array_reference", "", <B as Bus>::array_reference_offset),
+                    ]
+                };
+            }
+            #[doc(hidden)]
+            pub fn is_dumped<I: Interface>(_probe: ::tock_registers::internal::dump::Probe<I>) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<I: Interface>(
+                registers: I,
+                f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>,
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct("foo");
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::scalar_definition(registers))).readable() {
+                    fields.field("scalar_definition", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::scalar_definition(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                if b::is_dumped::<_>(::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        ::tock_registers::internal::dump::Probe::of(&<I as Interface>::array_reference(registers))
+                    )
+                )) {
+                    fields.field("array_reference", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface>::array_reference(registers), f,
+                            |register, f| ::tock_registers::internal::fmt_array(register, f,
+                                b::fmt_dump::<_>))));
+                }
+                fields.finish()
+            }
+            #debug_dump_comment
+            pub fn debug_dump<I: Interface>(registers: I) -> ::tock_registers::debug::DebugDump<I> {
+                ::tock_registers::debug::DebugDump::new(registers, fmt_dump::<I>)
+            }
+            #scalar_definition_comment #[derive(Clone)]
+            pub struct real_scalar_definition<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> real_scalar_definition<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy
+                for real_scalar_definition<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for real_scalar_definition<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = real_scalar_definition<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for real_scalar_definition<B> {
+                type DataType = u8;
+            }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_scalar_definition<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
+            Read!(real_impl, real_scalar_definition, u8,,);
+            // The Fake struct has a public field for each register, carrying over the register's
+            // doc comments. Register definitions are stored in per-register fake structs, and
+            // referenced layouts use their own Fake type (so they must also be #[fake]).
+            #fake_comment pub struct Fake<'h> {
+                /// Doc comment
+                pub scalar_definition: fake_scalar_definition<'h>,
+                pub array_reference: ::tock_registers::FakeArray<::tock_registers::FakeArray<
+                    b::Fake<'h>, lens::array_reference<0usize>, 2>,
+                    lens::array_reference<1usize>, 3>,
+                _phantom: ::tock_registers::internal::core::marker::PhantomData<&'h ()>,
+            }
+            // The interface is implemented on a reference to the Fake struct, as Interface
+            // requires Copy.
+            impl<'a, 'h> Interface for &'a Fake<'h> {
+                type scalar_definition = &'a fake_scalar_definition<'h>;
+                fn scalar_definition(self) -> Self::scalar_definition {
+                    &self.scalar_definition
+                }
+                type array_reference = &'a ::tock_registers::FakeArray<
+                    ::tock_registers::FakeArray<b::Fake<'h>, lens::array_reference<0usize>, 2>,
+                    lens::array_reference<1usize>, 3>;
+                fn array_reference(self) -> Self::array_reference { &self.array_reference }
+            }
+            // new initializes each field with its Default impl, which is its reset value (or its
+            // default value if it has none). Default forwards to new.
+            impl<'h> Fake<'h> {
+                #new_fake_comment pub fn new() -> Self where
+                    fake_scalar_definition<'h>: ::tock_registers::internal::core::default::Default,
+                    ::tock_registers::FakeArray<::tock_registers::FakeArray<
+                        b::Fake<'h>, lens::array_reference<0usize>, 2>,
+                        lens::array_reference<1usize>, 3>: ::tock_registers::internal::core::default::Default,
+                {
+                    Fake {
+                        scalar_definition: ::tock_registers::internal::core::default::Default::default(),
+                        array_reference: ::tock_registers::internal::core::default::Default::default(),
+                        _phantom: ::tock_registers::internal::core::marker::PhantomData,
+                    }
+                }
+            }
+            impl<'h> ::tock_registers::internal::core::default::Default for Fake<'h> where
+                fake_scalar_definition<'h>: ::tock_registers::internal::core::default::Default,
+                ::tock_registers::FakeArray<::tock_registers::FakeArray<
+                    b::Fake<'h>, lens::array_reference<0usize>, 2>,
+                    lens::array_reference<1usize>, 3>: ::tock_registers::internal::core::default::Default,
+            {
+                fn default() -> Self { Self::new() }
+            }
+            // Register definitions' fake structs implement only the register's operations.
+            #scalar_definition_fake_comment
+            pub struct fake_scalar_definition<'h>(::tock_registers::FakeCell<'h, u8>);
+            impl<'h> fake_scalar_definition<'h> {
+                #new_fake_comment pub fn new() -> Self
+                    where ::tock_registers::FakeCell<'h, u8>: ::tock_registers::internal::core::default::Default
+                {
+                    Self(::tock_registers::internal::core::default::Default::default())
+                }
+            }
+            impl<'h> ::tock_registers::internal::core::default::Default for fake_scalar_definition<'h>
+                where ::tock_registers::FakeCell<'h, u8>: ::tock_registers::internal::core::default::Default
+            {
+                fn default() -> Self { Self::new() }
+            }
+            impl<'h> ::tock_registers::internal::core::ops::Deref for fake_scalar_definition<'h> {
+                type Target = ::tock_registers::FakeCell<'h, u8>;
+                fn deref(&self) -> &::tock_registers::FakeCell<'h, u8> { &self.0 }
+            }
+            impl ::tock_registers::Register for &fake_scalar_definition<'_> { type DataType = u8; }
+            Read!(fake_impl, fake_scalar_definition, u8,,);
+        }
+    };
+    assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
+}
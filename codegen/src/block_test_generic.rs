@@ -0,0 +1,313 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::block::{
+    bus_doc_comment, bus_layout_doc_comment, debug_dump_doc_comment, fake_doc_comment,
+    field_fake_doc_comment, field_struct_doc_comment, interface_doc_comment, real_doc_comment,
+};
+use crate::{
+    new_doc_comment, new_fake_doc_comment, register_map, test_util::assert_tokens_eq,
+    Env::ProcMacro,
+};
+use quote::quote;
+use syn::parse_quote;
+
+/// Tests a register block with a const generic parameter.
+#[test]
+fn generic() {
+    let input = quote! {
+        ::tock_registers
+        #[bus(Mmio32)]
+        #[fake]
+        pub foo<const N: usize> {
+            0 => a: u8 { Read },
+            1 => b: [u8; N] { Write },
+        }
+    };
+    let interface_comment = interface_doc_comment();
+    let bus_comment = bus_doc_comment();
+    let bus_layout_comment = bus_layout_doc_comment();
+    let real_comment = real_doc_comment();
+    let new_comment = new_doc_comment();
+    let a_comment = field_struct_doc_comment(&parse_quote![a]);
+    let b_comment = field_struct_doc_comment(&parse_quote![b]);
+    let fake_comment = fake_doc_comment();
+    let a_fake_comment = field_fake_doc_comment(&parse_quote![a]);
+    let b_fake_comment = field_fake_doc_comment(&parse_quote![b]);
+    let new_fake_comment = new_fake_doc_comment();
+    // Note: some of the `> >`s below are necessary because the generated code's `>` tokens are not
+    // joined with the `>` tokens that follow them.
+    let debug_dump_comment = debug_dump_doc_comment();
+    let expected = quote! {
+        pub mod foo {
+            #![allow(non_camel_case_types)]
+            use super::*;
+            // The generic parameters are passed to the Interface trait, because array lengths (and
+            // therefore the interface types) can depend on them.
+            #interface_comment
+            pub trait Interface<const N: usize>: ::tock_registers::internal::core::marker::Copy {
+                type a: ::tock_registers::Register<DataType = u8> + Read;
+                fn a(self) -> Self::a;
+                type b: ::tock_registers::RegisterArray<lens::b<N>,
+                    Element: ::tock_registers::Register<DataType = u8> + Write>;
+                fn b(self) -> Self::b;
+            }
+            // Every lens type has the block's generic parameters.
+            pub mod lens {
+                #[allow(unused_imports)] use super::*;
+                pub enum b<const N: usize> {}
+                impl<const N: usize> ::tock_registers::array::Len for b<N> {
+                    const LEN: usize = N;
+                }
+            }
+            // Bus does not have generic parameters, as the operation macros use it as the bound of
+            // the real_ structs' bus parameter. The layout of the block is instead in BusLayout.
+            #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
+            pub trait Bus: ::tock_registers::Address + ::tock_registers::DataTypeBus<u8> +
+                ::tock_registers::DataTypeBus<u8> + sealed::Bus {}
+            impl Bus for Mmio32 {}
+            impl sealed::Bus for Mmio32 {}
+            impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            #bus_layout_comment #[allow(clippy::trait_duplication_in_bounds)]
+            pub trait BusLayout<const N: usize>: Bus {
+                const SIZE: usize;
+                const ALIGNMENT: usize;
+                const a_offset: usize = 0;
+                const b_offset: usize = 1;
+            }
+            impl<const N: usize> BusLayout<N> for Mmio32 {
+                const SIZE: usize = 1 + <<Real<N, Mmio32> as Interface<N> >::b
+                    as ::tock_registers::Span>::SIZE;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<N, Mmio32> as Interface<N> >::a as ::tock_registers::Span>::ALIGNMENT,
+                    <<Real<N, Mmio32> as Interface<N> >::b as ::tock_registers::Span>::ALIGNMENT,
+                ]);
+            }
+            impl<const N: usize, B: BusLayout<N> > BusLayout<N>
+                for ::tock_registers::BorrowedBus<'_, B>
+            {
+                const SIZE: usize = <B as BusLayout<N> >::SIZE;
+                const ALIGNMENT: usize = <B as BusLayout<N> >::ALIGNMENT;
+            }
+            mod sealed { pub trait Bus {} }
+            // The generic parameters come before the bus parameter, so that the bus parameter can
+            // keep its default.
+            #real_comment #[derive(Clone)]
+            pub struct Real<const N: usize, B: BusLayout<N> = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            // The offset tests cannot be checked in a `const _` item, as they depend on the generic
+            // parameters. Instead, they are evaluated when a Real is constructed.
+            impl<const N: usize, B: BusLayout<N> > Real<N, B> {
+                const OFFSET_TESTS: () = {
+                    assert!(0 % ::tock_registers::internal::core::convert::identity(
+                        <<Real<N, Mmio32> as Interface<N> >::a
+                            as ::tock_registers::Span>::ALIGNMENT) == 0,
+                        "field `a` is misaligned for bus Mmio32");
+                    assert!(0 == ::tock_registers::internal::core::convert::identity(0),
+                        "offset mismatch for bus Mmio32");
+                    assert!(1 % ::tock_registers::internal::core::convert::identity(
+                        <<Real<N, Mmio32> as Interface<N> >::b
+                            as ::tock_registers::Span>::ALIGNMENT) == 0,
+                        "field `b` is misaligned for bus Mmio32");
+                    assert!(1 == ::tock_registers::internal::core::convert::identity(0 +
+                        <<Real<N, Mmio32> as Interface<N> >::a as ::tock_registers::Span>::SIZE),
+                        "offset mismatch for bus Mmio32");
+                };
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    let () = Self::OFFSET_TESTS;
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<const N: usize, B: BusLayout<N> > ::tock_registers::internal::core::marker::Copy
+                for Real<N, B> {}
+            impl<const N: usize, B: BusLayout<N> > Interface<N> for Real<N, B> where
+                real_a<B>: ::tock_registers::Register<DataType = u8> + Read,
+                real_b<B>: ::tock_registers::Register<DataType = u8> + Write,
+            {
+                type a = real_a<B>;
+                fn a(self) -> Self::a {
+                    unsafe { Self::a::new(self.address.byte_add(<B as BusLayout<N> >::a_offset)) }
+                }
+                type b = ::tock_registers::RealRegisterArray<real_b<B>, lens::b<N> >;
+                fn b(self) -> Self::b {
+                    unsafe { Self::b::new(self.address.byte_add(<B as BusLayout<N> >::b_offset)) }
+                }
+            }
+            unsafe impl<const N: usize, B: BusLayout<N> > ::tock_registers::Span for Real<N, B> {
+                type Address = B;
+                const SIZE: usize = <B as BusLayout<N> >::SIZE;
+                const ALIGNMENT: usize = <B as BusLayout<N> >::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    let () = Self::OFFSET_TESTS;
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = Real<N, ::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<const N: usize, B: BusLayout<N> > ::tock_registers::layout::LayoutInfo
+            for Real<N, B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                ::tock_registers::layout::Layout::Block {
+                    fields: &[
+                        ::tock_registers::layout::FieldInfo::new::<real_a<B> >(
+                            "a", "", <B as BusLayout<N> >::a_offset
+                        ),
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<real_b<B>, lens::b<N> >
+                        >("b", "", <B as BusLayout<N> >::b_offset),
+                    ]
+                };
+            }
+            #[doc(hidden)]
+            pub fn is_dumped<const N: usize, I: Interface<N> >(_probe: ::tock_registers::internal::dump::Probe<I>) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<const N: usize, I: Interface<N> >(
+                registers: I,
+                f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>,
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct("foo");
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface<N> >::a(registers))).readable() {
+                    fields.field("a", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface<N> >::a(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                if (&&&::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::Probe::of(&<I as Interface<N> >::b(registers))
+                )).readable() {
+                    fields.field("b", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface<N> >::b(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                fields.finish()
+            }
+            #debug_dump_comment
+            pub fn debug_dump<const N: usize, I: Interface<N> >(registers: I) -> ::tock_registers::debug::DebugDump<I> {
+                ::tock_registers::debug::DebugDump::new(registers, fmt_dump::<N, I>)
+            }
+            // The real_ structs do not depend on the generic parameters.
+            #a_comment #[derive(Clone)] pub struct real_a<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> real_a<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for real_a<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for real_a<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = real_a<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for real_a<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_a<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
+            Read!(real_impl, real_a, u8,,);
+            #b_comment #[derive(Clone)] pub struct real_b<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> real_b<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for real_b<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for real_b<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = real_b<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for real_b<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_b<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Write"],
+                    };
+            }
+            Write!(real_impl, real_b, u8,,);
+            // The generic parameters follow Fake's lifetime parameter.
+            #fake_comment pub struct Fake<'h, const N: usize> {
+                pub a: fake_a<'h>,
+                pub b: ::tock_registers::FakeArray<fake_b<'h>, lens::b<N>, N>,
+                _phantom: ::tock_registers::internal::core::marker::PhantomData<&'h ()>,
+            }
+            impl<'a, 'h, const N: usize> Interface<N> for &'a Fake<'h, N> {
+                type a = &'a fake_a<'h>;
+                fn a(self) -> Self::a { &self.a }
+                type b = &'a ::tock_registers::FakeArray<fake_b<'h>, lens::b<N>, N>;
+                fn b(self) -> Self::b { &self.b }
+            }
+            impl<'h, const N: usize> Fake<'h, N> {
+                #new_fake_comment pub fn new() -> Self where
+                    fake_a<'h>: ::tock_registers::internal::core::default::Default,
+                    ::tock_registers::FakeArray<fake_b<'h>, lens::b<N>, N>: ::tock_registers::internal::core::default::Default,
+                {
+                    Fake {
+                        a: ::tock_registers::internal::core::default::Default::default(),
+                        b: ::tock_registers::internal::core::default::Default::default(),
+                        _phantom: ::tock_registers::internal::core::marker::PhantomData,
+                    }
+                }
+            }
+            impl<'h, const N: usize> ::tock_registers::internal::core::default::Default for Fake<'h, N> where
+                    fake_a<'h>: ::tock_registers::internal::core::default::Default,
+                    ::tock_registers::FakeArray<fake_b<'h>, lens::b<N>, N>: ::tock_registers::internal::core::default::Default,
+            {
+                fn default() -> Self { Self::new() }
+            }
+            #a_fake_comment pub struct fake_a<'h>(::tock_registers::FakeCell<'h, u8>);
+            impl<'h> fake_a<'h> {
+                #new_fake_comment pub fn new() -> Self where ::tock_registers::FakeCell<'h, u8>: ::tock_registers::internal::core::default::Default { Self(::tock_registers::internal::core::default::Default::default()) }
+            }
+            impl<'h> ::tock_registers::internal::core::default::Default for fake_a<'h> where ::tock_registers::FakeCell<'h, u8>: ::tock_registers::internal::core::default::Default {
+                fn default() -> Self { Self::new() }
+            }
+            impl<'h> ::tock_registers::internal::core::ops::Deref for fake_a<'h> {
+                type Target = ::tock_registers::FakeCell<'h, u8>;
+                fn deref(&self) -> &::tock_registers::FakeCell<'h, u8> { &self.0 }
+            }
+            impl ::tock_registers::Register for &fake_a<'_> { type DataType = u8; }
+            Read!(fake_impl, fake_a, u8,,);
+            #b_fake_comment pub struct fake_b<'h>(::tock_registers::FakeCell<'h, u8>);
+            impl<'h> fake_b<'h> {
+                #new_fake_comment pub fn new() -> Self where ::tock_registers::FakeCell<'h, u8>: ::tock_registers::internal::core::default::Default { Self(::tock_registers::internal::core::default::Default::default()) }
+            }
+            impl<'h> ::tock_registers::internal::core::default::Default for fake_b<'h> where ::tock_registers::FakeCell<'h, u8>: ::tock_registers::internal::core::default::Default {
+                fn default() -> Self { Self::new() }
+            }
+            impl<'h> ::tock_registers::internal::core::ops::Deref for fake_b<'h> {
+                type Target = ::tock_registers::FakeCell<'h, u8>;
+                fn deref(&self) -> &::tock_registers::FakeCell<'h, u8> { &self.0 }
+            }
+            impl ::tock_registers::Register for &fake_b<'_> { type DataType = u8; }
+            Write!(fake_impl, fake_b, u8,,);
+        }
+    };
+    assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
+}
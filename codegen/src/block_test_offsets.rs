@@ -4,7 +4,8 @@
 // Copyright Better Bytes 2026.
 
 use crate::block::{
-    bus_doc_comment, field_struct_doc_comment, interface_doc_comment, real_doc_comment,
+    bus_doc_comment, debug_dump_doc_comment, field_struct_doc_comment, interface_doc_comment,
+    real_doc_comment,
 };
 use crate::{new_doc_comment, register_map, test_util::assert_tokens_eq, Env::ProcMacro};
 use quote::quote;
@@ -40,6 +41,7 @@ fn offsets() {
     let aliased_comment = field_struct_doc_comment(&parse_quote![aliased]);
     let fixed_pos_comment = field_struct_doc_comment(&parse_quote![fixed_pos]);
     let padded_pos_comment = field_struct_doc_comment(&parse_quote![padded_pos]);
+    let debug_dump_comment = debug_dump_doc_comment();
     let expected = quote! {
         pub mod foo {
             #![allow(non_camel_case_types)]
@@ -56,13 +58,16 @@ fn offsets() {
                 type padded_pos: ::tock_registers::Register<DataType = u8> + Read;
                 fn padded_pos(self) -> Self::padded_pos;
             }
-            pub mod lens {}
+            pub mod lens {
+                #[allow(unused_imports)] use super::*;
+            }
             #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
             pub trait Bus: ::tock_registers::Address + ::tock_registers::DataTypeBus<usize> +
                 ::tock_registers::DataTypeBus<u32> + ::tock_registers::DataTypeBus<u16> +
                 ::tock_registers::DataTypeBus<u32> + ::tock_registers::DataTypeBus<u8> + sealed::Bus
             {
                 const SIZE: usize;
+                const ALIGNMENT: usize;
                 const variable_size_offset: usize = 0;
                 const size_variable_pos_offset: usize;
                 const aliased_offset: usize = 6;
@@ -71,39 +76,70 @@ fn offsets() {
             }
             impl Bus for Mmio32 {
                 const SIZE: usize = 24 + 0;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio32> as Interface>::variable_size as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::size_variable_pos as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::aliased as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::fixed_pos as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::padded_pos as ::tock_registers::Span>::ALIGNMENT,
+                ]);
                 const size_variable_pos_offset: usize = 4;
                 const padded_pos_offset: usize = 20;
             }
             impl sealed::Bus for Mmio32 {}
             impl Bus for Mmio64 {
                 const SIZE: usize = 32 + 0;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio64> as Interface>::variable_size as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::size_variable_pos as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::aliased as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::fixed_pos as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::padded_pos as ::tock_registers::Span>::ALIGNMENT,
+                ]);
                 const size_variable_pos_offset: usize = 8;
                 const padded_pos_offset: usize = 24;
             }
             impl sealed::Bus for Mmio64 {}
             impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {
                 const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
                 const size_variable_pos_offset: usize = <B as Bus>::size_variable_pos_offset;
                 const padded_pos_offset: usize = <B as Bus>::padded_pos_offset;
             }
             impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
             const _: () = {
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::variable_size as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `variable_size` is misaligned for bus Mmio32");
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::variable_size as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `variable_size` is misaligned for bus Mmio64");
                 assert!(0 == ::tock_registers::internal::core::convert::identity(0),
                     "offset mismatch for bus Mmio32");
                 assert!(0 == ::tock_registers::internal::core::convert::identity(0),
                     "offset mismatch for bus Mmio64");
+                assert!(4 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::size_variable_pos as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `size_variable_pos` is misaligned for bus Mmio32");
+                assert!(8 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::size_variable_pos as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `size_variable_pos` is misaligned for bus Mmio64");
                 assert!(4 == ::tock_registers::internal::core::convert::identity(0 +
                     <<Real<Mmio32> as Interface>::variable_size as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio32");
                 assert!(8 == ::tock_registers::internal::core::convert::identity(0 +
                     <<Real<Mmio64> as Interface>::variable_size as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio64");
+                assert!(6 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::aliased as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `aliased` is misaligned for bus Mmio32");
+                assert!(6 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::aliased as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `aliased` is misaligned for bus Mmio64");
                 assert!(8 == ::tock_registers::internal::core::convert::identity(4 + <<Real<Mmio32>
                     as Interface>::size_variable_pos as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio32");
                 assert!(12 == ::tock_registers::internal::core::convert::identity(8 + <<Real<Mmio64>
                     as Interface>::size_variable_pos as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio64");
+                assert!(12 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::fixed_pos as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `fixed_pos` is misaligned for bus Mmio32");
+                assert!(12 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::fixed_pos as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `fixed_pos` is misaligned for bus Mmio64");
                 assert!(12 == ::tock_registers::internal::core::convert::identity(8 + 4),
                     "offset mismatch for bus Mmio32");
                 assert!(12 == ::tock_registers::internal::core::convert::identity(12 + 0),
@@ -114,12 +150,38 @@ fn offsets() {
                 assert!(16 == ::tock_registers::internal::core::convert::identity(12 +
                     <<Real<Mmio64> as Interface>::fixed_pos as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio64");
+                assert!(20 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::padded_pos as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `padded_pos` is misaligned for bus Mmio32");
+                assert!(24 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::padded_pos as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `padded_pos` is misaligned for bus Mmio64");
                 assert!(21 == ::tock_registers::internal::core::convert::identity(20 +
                     <<Real<Mmio32> as Interface>::padded_pos as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio32");
                 assert!(25 == ::tock_registers::internal::core::convert::identity(24 +
                     <<Real<Mmio64> as Interface>::padded_pos as ::tock_registers::Span>::SIZE),
                     "offset mismatch for bus Mmio64");
+                assert!(6 + <<Real<Mmio32> as Interface>::aliased as ::tock_registers::Span>::SIZE <= <Mmio32 as Bus>::SIZE,
+                    "aliased field `aliased` extends beyond the block for bus Mmio32");
+                assert!(!::tock_registers::internal::overlaps_padding(8, 8 + 4, 6, 6 + <<Real<Mmio32> as Interface>::aliased as ::tock_registers::Span>::SIZE),
+                    "aliased field `aliased` overlaps padding for bus Mmio32");
+                assert!(!::tock_registers::internal::overlaps_padding(16, 20, 6, 6 + <<Real<Mmio32> as Interface>::aliased as ::tock_registers::Span>::SIZE),
+                    "aliased field `aliased` overlaps padding for bus Mmio32");
+                assert!(!::tock_registers::internal::overlaps_padding(21, 24, 6, 6 + <<Real<Mmio32> as Interface>::aliased as ::tock_registers::Span>::SIZE),
+                    "aliased field `aliased` overlaps padding for bus Mmio32");
+                assert!(!::tock_registers::internal::overlaps_padding(24, 24 + 0, 6, 6 + <<Real<Mmio32> as Interface>::aliased as ::tock_registers::Span>::SIZE),
+                    "aliased field `aliased` overlaps padding for bus Mmio32");
+                assert!(6 + <<Real<Mmio64> as Interface>::aliased as ::tock_registers::Span>::SIZE <= <Mmio64 as Bus>::SIZE,
+                    "aliased field `aliased` extends beyond the block for bus Mmio64");
+                assert!(!::tock_registers::internal::overlaps_padding(12, 12 + 0, 6, 6 + <<Real<Mmio64> as Interface>::aliased as ::tock_registers::Span>::SIZE),
+                    "aliased field `aliased` overlaps padding for bus Mmio64");
+                assert!(!::tock_registers::internal::overlaps_padding(16, 24, 6, 6 + <<Real<Mmio64> as Interface>::aliased as ::tock_registers::Span>::SIZE),
+                    "aliased field `aliased` overlaps padding for bus Mmio64");
+                assert!(!::tock_registers::internal::overlaps_padding(25, 32, 6, 6 + <<Real<Mmio64> as Interface>::aliased as ::tock_registers::Span>::SIZE),
+                    "aliased field `aliased` overlaps padding for bus Mmio64");
+                assert!(!::tock_registers::internal::overlaps_padding(32, 32 + 0, 6, 6 + <<Real<Mmio64> as Interface>::aliased as ::tock_registers::Span>::SIZE),
+                    "aliased field `aliased` overlaps padding for bus Mmio64");
             };
             mod sealed { pub trait Bus {} }
             #real_comment #[derive(Clone)] pub struct Real<B: Bus> {
@@ -174,11 +236,76 @@ fn offsets() {
             unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
                 type Address = B;
                 const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
                 type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                ::tock_registers::layout::Layout::Block {
+                    fields: &[
+                        ::tock_registers::layout::FieldInfo::new::<real_variable_size<B> >("variable_size", "", <B as Bus>::variable_size_offset),
+                        ::tock_registers::layout::FieldInfo::new::<real_size_variable_pos<B> >("size_variable_pos", "", <B as Bus>::size_variable_pos_offset),
+                        ::tock_registers::layout::FieldInfo::new::<real_aliased<B> >("aliased", "", <B as Bus>::aliased_offset),
+                        ::tock_registers::layout::FieldInfo::new::<real_fixed_pos<B> >("fixed_pos", "", <B as Bus>::fixed_pos_offset),
+                        ::tock_registers::layout::FieldInfo::new::<real_padded_pos<B> >("padded_pos", "", <B as Bus>::padded_pos_offset),
+                    ]
+                };
+            }
+            #[doc(hidden)]
+            pub fn is_dumped<I: Interface>(_probe: ::tock_registers::internal::dump::Probe<I>) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<I: Interface>(
+                registers: I,
+                f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>,
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct("foo");
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::variable_size(registers))).readable() {
+                    fields.field("variable_size", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::variable_size(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<usize>::new()).value_fmt()))));
+                }
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::size_variable_pos(registers))).readable() {
+                    fields.field("size_variable_pos", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::size_variable_pos(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u32>::new()).value_fmt()))));
+                }
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::aliased(registers))).readable() {
+                    fields.field("aliased", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::aliased(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u16>::new()).value_fmt()))));
+                }
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::fixed_pos(registers))).readable() {
+                    fields.field("fixed_pos", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::fixed_pos(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u32>::new()).value_fmt()))));
+                }
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::padded_pos(registers))).readable() {
+                    fields.field("padded_pos", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::padded_pos(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                fields.finish()
+            }
+            #debug_dump_comment
+            pub fn debug_dump<I: Interface>(registers: I) -> ::tock_registers::debug::DebugDump<I> {
+                ::tock_registers::debug::DebugDump::new(registers, fmt_dump::<I>)
+            }
             #variable_size_comment #[derive(Clone)] pub struct real_variable_size<B: Bus> {
                 address: B,
                 _phantom: ::tock_registers::internal::RealPhantom,
@@ -192,6 +319,7 @@ fn offsets() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_variable_size<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<usize>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<usize>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
@@ -200,6 +328,13 @@ fn offsets() {
             impl<B: Bus> ::tock_registers::Register for real_variable_size<B> {
                 type DataType = usize;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_variable_size<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
             Read!(real_impl, real_variable_size, usize,,);
             #size_variable_pos_comment #[derive(Clone)] pub struct real_size_variable_pos<B: Bus> {
                 address: B,
@@ -214,6 +349,7 @@ fn offsets() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_size_variable_pos<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u32>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u32>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
@@ -222,6 +358,13 @@ fn offsets() {
             impl<B: Bus> ::tock_registers::Register for real_size_variable_pos<B> {
                 type DataType = u32;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_size_variable_pos<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
             Read!(real_impl, real_size_variable_pos, u32,,);
             #aliased_comment #[derive(Clone)] pub struct real_aliased<B: Bus> {
                 address: B,
@@ -236,12 +379,20 @@ fn offsets() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_aliased<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u16>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u16>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
                 type Borrowed<'b> = real_aliased<::tock_registers::BorrowedBus<'b, B>>;
             }
             impl<B: Bus> ::tock_registers::Register for real_aliased<B> { type DataType = u16; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_aliased<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
             Read!(real_impl, real_aliased, u16,,);
             #fixed_pos_comment #[derive(Clone)] pub struct real_fixed_pos<B: Bus> {
                 address: B,
@@ -257,6 +408,7 @@ fn offsets() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_fixed_pos<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u32>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u32>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
@@ -265,6 +417,13 @@ fn offsets() {
             impl<B: Bus> ::tock_registers::Register for real_fixed_pos<B> {
                 type DataType = u32;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_fixed_pos<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
             Read!(real_impl, real_fixed_pos, u32,,);
             #padded_pos_comment #[derive(Clone)] pub struct real_padded_pos<B: Bus> {
                 address: B,
@@ -280,6 +439,7 @@ fn offsets() {
             unsafe impl<B: Bus> ::tock_registers::Span for real_padded_pos<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
@@ -288,6 +448,13 @@ fn offsets() {
             impl<B: Bus> ::tock_registers::Register for real_padded_pos<B> {
                 type DataType = u8;
             }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_padded_pos<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
             Read!(real_impl, real_padded_pos, u8,,);
         }
     };
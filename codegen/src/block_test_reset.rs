@@ -0,0 +1,289 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::block::{
+    bus_doc_comment, debug_dump_doc_comment, fake_doc_comment, field_fake_doc_comment,
+    field_struct_doc_comment, interface_doc_comment, real_doc_comment,
+};
+use crate::{
+    new_doc_comment, new_fake_doc_comment, register_map, reset_doc_comment,
+    test_util::assert_tokens_eq, Env::ProcMacro,
+};
+use quote::quote;
+use syn::parse_quote;
+
+/// Tests a register block with a reset value on a field.
+#[test]
+fn reset() {
+    let input = quote! {
+        ::tock_registers
+        #[bus(Mmio32)]
+        #[fake]
+        pub foo {
+            #[reset(1 << 7)] 0 => a: u8 { Read, Write },
+            1 => b: u8 { Read },
+        }
+    };
+    let interface_comment = interface_doc_comment();
+    let reset_comment = reset_doc_comment();
+    let bus_comment = bus_doc_comment();
+    let real_comment = real_doc_comment();
+    let new_comment = new_doc_comment();
+    let a_comment = field_struct_doc_comment(&parse_quote![a]);
+    let b_comment = field_struct_doc_comment(&parse_quote![b]);
+    let fake_comment = fake_doc_comment();
+    let a_fake_comment = field_fake_doc_comment(&parse_quote![a]);
+    let b_fake_comment = field_fake_doc_comment(&parse_quote![b]);
+    let new_fake_comment = new_fake_doc_comment();
+    let debug_dump_comment = debug_dump_doc_comment();
+    let expected = quote! {
+        pub mod foo {
+            #![allow(non_camel_case_types)]
+            use super::*;
+            // Each reset value is exposed as an associated constant named after its field.
+            #interface_comment pub trait Interface: ::tock_registers::internal::core::marker::Copy {
+                #reset_comment const a_reset: <u8 as ::tock_registers::DataType>::Value = resets::a;
+                type a: ::tock_registers::Register<DataType = u8> + Read + Write;
+                fn a(self) -> Self::a;
+                type b: ::tock_registers::Register<DataType = u8> + Read;
+                fn b(self) -> Self::b;
+            }
+            pub mod lens {
+                #[allow(unused_imports)] use super::*;
+            }
+            // The reset values are stored in a resets module, similar to lens and strides. The
+            // constants are named after their fields, and the module imports the parent module's
+            // items, as the reset expressions can refer to them.
+            pub mod resets {
+                #![allow(non_upper_case_globals)]
+                use super::*;
+                #reset_comment pub const a: <u8 as ::tock_registers::DataType>::Value = 1 << 7;
+            }
+            #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
+            pub trait Bus: ::tock_registers::Address + ::tock_registers::DataTypeBus<u8> +
+                ::tock_registers::DataTypeBus<u8> + sealed::Bus
+            {
+                const SIZE: usize;
+                const ALIGNMENT: usize;
+                const a_offset: usize = 0;
+                const b_offset: usize = 1;
+            }
+            impl Bus for Mmio32 {
+                const SIZE: usize = 1 + <<Real<Mmio32> as Interface>::b
+                    as ::tock_registers::Span>::SIZE;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::b as ::tock_registers::Span>::ALIGNMENT,
+                ]);
+            }
+            impl sealed::Bus for Mmio32 {}
+            impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {
+                const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
+            }
+            impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            const _: () = {
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `a` is misaligned for bus Mmio32");
+                assert!(0 == ::tock_registers::internal::core::convert::identity(0),
+                    "offset mismatch for bus Mmio32");
+                assert!(1 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::b as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `b` is misaligned for bus Mmio32");
+                assert!(1 == ::tock_registers::internal::core::convert::identity(0 +
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::SIZE),
+                    "offset mismatch for bus Mmio32");
+            };
+            mod sealed { pub trait Bus {} }
+            #real_comment #[derive(Clone)] pub struct Real<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> Real<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for Real<B> {}
+            impl<B: Bus> Interface for Real<B> where
+                real_a<B>: ::tock_registers::Register<DataType = u8> + Read + Write,
+                real_b<B>: ::tock_registers::Register<DataType = u8> + Read,
+            {
+                type a = real_a<B>;
+                fn a(self) -> Self::a {
+                    unsafe { Self::a::new(self.address.byte_add(<B as Bus>::a_offset)) }
+                }
+                type b = real_b<B>;
+                fn b(self) -> Self::b {
+                    unsafe { Self::b::new(self.address.byte_add(<B as Bus>::b_offset)) }
+                }
+            }
+            unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
+                type Address = B;
+                const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                ::tock_registers::layout::Layout::Block {
+                    fields: &[
+                        ::tock_registers::layout::FieldInfo::new::<real_a<B> >("a", "", <B as Bus>::a_offset),
+                        ::tock_registers::layout::FieldInfo::new::<real_b<B> >("b", "", <B as Bus>::b_offset),
+                    ]
+                };
+            }
+            #[doc(hidden)]
+            pub fn is_dumped<I: Interface>(_probe: ::tock_registers::internal::dump::Probe<I>) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<I: Interface>(
+                registers: I,
+                f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>,
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct("foo");
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::a(registers))).readable() {
+                    fields.field("a", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::a(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                if (&&&::tock_registers::internal::dump::Probe::of(&<I as Interface>::b(registers))).readable() {
+                    fields.field("b", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_with(<I as Interface>::b(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))));
+                }
+                fields.finish()
+            }
+            #debug_dump_comment
+            pub fn debug_dump<I: Interface>(registers: I) -> ::tock_registers::debug::DebugDump<I> {
+                ::tock_registers::debug::DebugDump::new(registers, fmt_dump::<I>)
+            }
+            #a_comment #[derive(Clone)] pub struct real_a<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> real_a<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                #reset_comment pub const RESET: <u8 as ::tock_registers::DataType>::Value =
+                    resets::a;
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for real_a<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for real_a<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = real_a<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for real_a<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_a<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Write"],
+                    };
+            }
+            Read!(real_impl, real_a, u8,,);
+            Write!(real_impl, real_a, u8,,);
+            #b_comment #[derive(Clone)] pub struct real_b<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> real_b<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for real_b<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for real_b<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = real_b<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for real_b<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_b<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
+            Read!(real_impl, real_b, u8,,);
+            #fake_comment pub struct Fake<'h> {
+                pub a: fake_a<'h>,
+                pub b: fake_b<'h>,
+                _phantom: ::tock_registers::internal::core::marker::PhantomData<&'h ()>,
+            }
+            impl<'a, 'h> Interface for &'a Fake<'h> {
+                type a = &'a fake_a<'h>;
+                fn a(self) -> Self::a { &self.a }
+                type b = &'a fake_b<'h>;
+                fn b(self) -> Self::b { &self.b }
+            }
+            impl<'h> Fake<'h> {
+                #new_fake_comment pub fn new() -> Self where
+                    fake_a<'h>: ::tock_registers::internal::core::default::Default,
+                    fake_b<'h>: ::tock_registers::internal::core::default::Default,
+                {
+                    Fake {
+                        a: ::tock_registers::internal::core::default::Default::default(),
+                        b: ::tock_registers::internal::core::default::Default::default(),
+                        _phantom: ::tock_registers::internal::core::marker::PhantomData,
+                    }
+                }
+            }
+            impl<'h> ::tock_registers::internal::core::default::Default for Fake<'h> where
+                    fake_a<'h>: ::tock_registers::internal::core::default::Default,
+                    fake_b<'h>: ::tock_registers::internal::core::default::Default,
+            {
+                fn default() -> Self { Self::new() }
+            }
+            #a_fake_comment pub struct fake_a<'h>(::tock_registers::FakeCell<'h, u8>);
+            impl<'h> fake_a<'h> {
+                #new_fake_comment pub fn new() -> Self  { Self(::tock_registers::FakeCell::new(resets::a)) }
+            }
+            impl<'h> ::tock_registers::internal::core::default::Default for fake_a<'h>  {
+                fn default() -> Self { Self::new() }
+            }
+            impl<'h> ::tock_registers::internal::core::ops::Deref for fake_a<'h> {
+                type Target = ::tock_registers::FakeCell<'h, u8>;
+                fn deref(&self) -> &::tock_registers::FakeCell<'h, u8> { &self.0 }
+            }
+            impl ::tock_registers::Register for &fake_a<'_> { type DataType = u8; }
+            Read!(fake_impl, fake_a, u8,,);
+            Write!(fake_impl, fake_a, u8,,);
+            #b_fake_comment pub struct fake_b<'h>(::tock_registers::FakeCell<'h, u8>);
+            impl<'h> fake_b<'h> {
+                #new_fake_comment pub fn new() -> Self where ::tock_registers::FakeCell<'h, u8>: ::tock_registers::internal::core::default::Default { Self(::tock_registers::internal::core::default::Default::default()) }
+            }
+            impl<'h> ::tock_registers::internal::core::default::Default for fake_b<'h> where ::tock_registers::FakeCell<'h, u8>: ::tock_registers::internal::core::default::Default {
+                fn default() -> Self { Self::new() }
+            }
+            impl<'h> ::tock_registers::internal::core::ops::Deref for fake_b<'h> {
+                type Target = ::tock_registers::FakeCell<'h, u8>;
+                fn deref(&self) -> &::tock_registers::FakeCell<'h, u8> { &self.0 }
+            }
+            impl ::tock_registers::Register for &fake_b<'_> { type DataType = u8; }
+            Read!(fake_impl, fake_b, u8,,);
+        }
+    };
+    assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
+}
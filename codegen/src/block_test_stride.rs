@@ -0,0 +1,253 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::block::{
+    bus_doc_comment, debug_dump_doc_comment, field_struct_doc_comment, interface_doc_comment,
+    real_doc_comment,
+};
+use crate::{new_doc_comment, register_map, test_util::assert_tokens_eq, Env::ProcMacro};
+use quote::quote;
+use syn::parse_quote;
+
+/// Tests arrays with a `#[stride]` attribute, with both per-bus and single strides.
+#[test]
+fn stride() {
+    let input = quote! {
+        ::tock_registers
+        #[buses(Mmio32, Mmio64)]
+        pub foo {
+            #[stride([8, 16])] 0 => a: [u32; 2] { Read },
+            #[stride(4)] [16, 32] => b: [[c; 2]; 3],
+        }
+    };
+    let interface_comment = interface_doc_comment();
+    let bus_comment = bus_doc_comment();
+    let real_comment = real_doc_comment();
+    let new_comment = new_doc_comment();
+    let a_comment = field_struct_doc_comment(&parse_quote![a]);
+    let debug_dump_comment = debug_dump_doc_comment();
+    let expected = quote! {
+        pub mod foo {
+            #![allow(non_camel_case_types)]
+            use super::*;
+            #interface_comment pub trait Interface: ::tock_registers::internal::core::marker::Copy {
+                type a: ::tock_registers::RegisterArray<lens::a,
+                    Element: ::tock_registers::Register<DataType = u32> + Read>;
+                fn a(self) -> Self::a;
+                type b: ::tock_registers::RegisterArray<lens::b<1usize>,
+                    Element: ::tock_registers::RegisterArray<lens::b<0usize>, Element: c::Interface> >;
+                fn b(self) -> Self::b;
+            }
+            pub mod lens {
+                #[allow(unused_imports)] use super::*;
+                pub enum a {}
+                impl ::tock_registers::array::Len for a { const LEN: usize = 2; }
+                pub enum b<const LEVEL: usize> {}
+                impl ::tock_registers::array::Len for b<0usize> { const LEN: usize = 2; }
+                impl ::tock_registers::array::Len for b<1usize> { const LEN: usize = 3; }
+            }
+            // Each strided array has a type implementing Stride, which reads the stride from the
+            // Bus trait (as the stride can depend on the bus).
+            pub mod strides {
+                pub struct a<B>(::tock_registers::internal::core::marker::PhantomData<B>);
+                impl<B: super::Bus> ::tock_registers::array::Stride for a<B> {
+                    const STRIDE: ::tock_registers::internal::core::option::Option<usize> =
+                        ::tock_registers::internal::core::option::Option::Some(
+                            <B as super::Bus>::a_stride);
+                }
+                pub struct b<B>(::tock_registers::internal::core::marker::PhantomData<B>);
+                impl<B: super::Bus> ::tock_registers::array::Stride for b<B> {
+                    const STRIDE: ::tock_registers::internal::core::option::Option<usize> =
+                        ::tock_registers::internal::core::option::Option::Some(
+                            <B as super::Bus>::b_stride);
+                }
+            }
+            // Strides are stored the same way as offsets.
+            #bus_comment #[allow(clippy::trait_duplication_in_bounds)]
+            pub trait Bus: ::tock_registers::Address + ::tock_registers::DataTypeBus<u32> + c::Bus +
+                sealed::Bus
+            {
+                const SIZE: usize;
+                const ALIGNMENT: usize;
+                const a_offset: usize = 0;
+                const a_stride: usize;
+                const b_offset: usize;
+                const b_stride: usize = 4;
+            }
+            impl Bus for Mmio32 {
+                const SIZE: usize = 16 + <<Real<Mmio32> as Interface>::b
+                    as ::tock_registers::Span>::SIZE;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio32> as Interface>::b as ::tock_registers::Span>::ALIGNMENT,
+                ]);
+                const a_stride: usize = 8;
+                const b_offset: usize = 16;
+            }
+            impl sealed::Bus for Mmio32 {}
+            impl Bus for Mmio64 {
+                const SIZE: usize = 32 + <<Real<Mmio64> as Interface>::b
+                    as ::tock_registers::Span>::SIZE;
+                const ALIGNMENT: usize = ::tock_registers::internal::max_alignment(&[
+                    <<Real<Mmio64> as Interface>::a as ::tock_registers::Span>::ALIGNMENT, <<Real<Mmio64> as Interface>::b as ::tock_registers::Span>::ALIGNMENT,
+                ]);
+                const a_stride: usize = 16;
+                const b_offset: usize = 32;
+            }
+            impl sealed::Bus for Mmio64 {}
+            impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {
+                const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
+                const a_stride: usize = <B as Bus>::a_stride;
+                const b_offset: usize = <B as Bus>::b_offset;
+            }
+            impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            // The size of a strided array is its stride times its length, so the offset tests
+            // account for the stride.
+            const _: () = {
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `a` is misaligned for bus Mmio32");
+                assert!(0 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::a as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `a` is misaligned for bus Mmio64");
+                assert!(0 == ::tock_registers::internal::core::convert::identity(0),
+                    "offset mismatch for bus Mmio32");
+                assert!(0 == ::tock_registers::internal::core::convert::identity(0),
+                    "offset mismatch for bus Mmio64");
+                assert!(16 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio32> as Interface>::b as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `b` is misaligned for bus Mmio32");
+                assert!(32 % ::tock_registers::internal::core::convert::identity(
+                    <<Real<Mmio64> as Interface>::b as ::tock_registers::Span>::ALIGNMENT) == 0,
+                    "field `b` is misaligned for bus Mmio64");
+                assert!(16 == ::tock_registers::internal::core::convert::identity(0 +
+                    <<Real<Mmio32> as Interface>::a as ::tock_registers::Span>::SIZE),
+                    "offset mismatch for bus Mmio32");
+                assert!(32 == ::tock_registers::internal::core::convert::identity(0 +
+                    <<Real<Mmio64> as Interface>::a as ::tock_registers::Span>::SIZE),
+                    "offset mismatch for bus Mmio64");
+            };
+            mod sealed { pub trait Bus {} }
+            #real_comment #[derive(Clone)] pub struct Real<B: Bus> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> Real<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for Real<B> {}
+            impl<B: Bus> Interface for Real<B> where
+                real_a<B>: ::tock_registers::Register<DataType = u32> + Read,
+                c::Real<B>: c::Interface,
+            {
+                // The stride type only applies to the outermost array.
+                type a = ::tock_registers::RealRegisterArray<real_a<B>, lens::a, strides::a<B>>;
+                fn a(self) -> Self::a {
+                    unsafe { Self::a::new(self.address.byte_add(<B as Bus>::a_offset)) }
+                }
+                type b = ::tock_registers::RealRegisterArray<
+                    ::tock_registers::RealRegisterArray<c::Real<B>, lens::b<0usize> >,
+                    lens::b<1usize>, strides::b<B>>;
+                fn b(self) -> Self::b {
+                    unsafe { Self::b::new(self.address.byte_add(<B as Bus>::b_offset)) }
+                }
+            }
+            unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
+                type Address = B;
+                const SIZE: usize = <B as Bus>::SIZE;
+                const ALIGNMENT: usize = <B as Bus>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                ::tock_registers::layout::Layout::Block {
+                    fields: &[
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<
+                                real_a<B>,
+                                lens::a,
+                                strides::a<B>>
+                        >("a", "", <B as Bus>::a_offset),
+                        ::tock_registers::layout::FieldInfo::new::<
+                            ::tock_registers::RealRegisterArray<
+                                ::tock_registers::RealRegisterArray<c::Real<B>, lens::b<0usize> >,
+                                lens::b<1usize>,
+                                strides::b<B>>
+                        >("b", "", <B as Bus>::b_offset),
+                    ]
+                };
+            }
+            #[doc(hidden)]
+            pub fn is_dumped<I: Interface>(_probe: ::tock_registers::internal::dump::Probe<I>) -> bool {
+                true
+            }
+            #[doc(hidden)] #[allow(unused_mut, unused_variables)]
+            pub fn fmt_dump<I: Interface>(
+                registers: I,
+                f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>,
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                let mut fields = f.debug_struct("foo");
+                if (&&&::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::Probe::of(&<I as Interface>::a(registers))
+                )).readable() {
+                    fields.field("a", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface>::a(registers), f,
+                            |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                                .fmt_register(register, f,
+                                    (&&&::tock_registers::internal::dump::Probe::<u32>::new()).value_fmt()))));
+                }
+                if c::is_dumped::<_>(::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        ::tock_registers::internal::dump::Probe::of(&<I as Interface>::b(registers))
+                    )
+                )) {
+                    fields.field("b", &::tock_registers::internal::debug_fn(|f|
+                        ::tock_registers::internal::fmt_array(<I as Interface>::b(registers), f,
+                            |register, f| ::tock_registers::internal::fmt_array(register, f,
+                                c::fmt_dump::<_>))));
+                }
+                fields.finish()
+            }
+            #debug_dump_comment
+            pub fn debug_dump<I: Interface>(registers: I) -> ::tock_registers::debug::DebugDump<I> {
+                ::tock_registers::debug::DebugDump::new(registers, fmt_dump::<I>)
+            }
+            #a_comment #[derive(Clone)] pub struct real_a<B: Bus> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> real_a<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for real_a<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for real_a<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u32>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u32>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = real_a<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for real_a<B> { type DataType = u32; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for real_a<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
+            Read!(real_impl, real_a, u32,,);
+        }
+    };
+    assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
+}
@@ -12,15 +12,29 @@
 //    cases.
 
 mod ast;
+mod bitfields;
 mod block;
 #[cfg(all(test, not(miri)))]
 mod block_test_all_fields;
 #[cfg(all(test, not(miri)))]
+mod block_test_cfg;
+#[cfg(all(test, not(miri)))]
 mod block_test_docs;
 #[cfg(all(test, not(miri)))]
 mod block_test_empty;
 #[cfg(all(test, not(miri)))]
+mod block_test_fake;
+#[cfg(all(test, not(miri)))]
+mod block_test_generic;
+#[cfg(all(test, not(miri)))]
 mod block_test_offsets;
+#[cfg(all(test, not(miri)))]
+mod block_test_reset;
+#[cfg(all(test, not(miri)))]
+mod block_test_restricted;
+#[cfg(all(test, not(miri)))]
+mod block_test_stride;
+mod outcome;
 mod parse;
 #[cfg(all(test, not(miri)))]
 mod parse_tests;
@@ -28,17 +42,28 @@ mod single;
 #[cfg(all(test, not(miri)))]
 mod single_test_array;
 #[cfg(all(test, not(miri)))]
+mod single_test_bitfields;
+#[cfg(all(test, not(miri)))]
 mod single_test_docs;
 #[cfg(all(test, not(miri)))]
+mod single_test_fake;
+#[cfg(all(test, not(miri)))]
+mod single_test_reset;
+#[cfg(all(test, not(miri)))]
 mod single_test_scalar;
 #[cfg(all(test, not(miri)))]
 mod test_util;
 
 use ast::{Input, RegisterSpec, Value};
-use proc_macro2::TokenStream;
-use quote::quote;
+use outcome::Outcome;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
 use std::mem::replace;
-use syn::{parse2, Ident, Path, PathArguments};
+use syn::ext::IdentExt;
+use syn::{
+    parse2, spanned::Spanned, Attribute, Expr, ExprLit, GenericArgument, Ident, Lit, Meta,
+    MetaNameValue, Path, PathArguments, Type, TypePath,
+};
 
 /// Returns the generated code for a `tock_registers_macro::register_map!` invocation.
 ///
@@ -49,12 +74,18 @@ use syn::{parse2, Ident, Path, PathArguments};
 /// `tock_registers` crate.
 ///
 /// # Return value
-/// If an error is encountered, Err() is returned and the contained TokenStream produces a compiler
-/// error.
+/// If an error prevents code generation, Err() is returned and the contained TokenStream produces
+/// a compiler error. The parser recovers from most errors, in which case Ok() is returned and the
+/// contained TokenStream produces the errors as well as the generated code for the layouts that
+/// could still be generated.
 pub fn register_map(input: TokenStream, env: Env) -> Result<TokenStream, TokenStream> {
     use Value::{Block, Single};
-    let input: Input = parse2(input).map_err(|e| e.to_compile_error())?;
-    let mut out = TokenStream::new();
+    let (input, mut out) =
+        match parse2::<Outcome<Input>>(input).map_err(|e| e.to_compile_error())? {
+            Outcome::Ok(input) => (input, TokenStream::new()),
+            Outcome::Continue(input, error) => (input, error.to_compile_error()),
+            Outcome::NoGenerate(error) => return Err(error.to_compile_error()),
+        };
     for layout in input.layouts {
         out.extend(match &layout.value {
             Block(fields) => block::generate(env, &input.tock_registers, &layout, fields),
@@ -77,48 +108,335 @@ pub enum Env {
 
 /// Generates the register accessor struct for a single register definition or register definition
 /// field. `struct_name` is the name of the struct to generate, which does not need to match the
-/// name of the register.
+/// name of the register. `reset` is the path to the constant holding the register's reset value
+/// (only used if the register has a reset value). `cfg` contains `#[cfg]` attributes that are
+/// applied to each generated item, and `visibility` is the visibility of the struct.
+#[allow(clippy::too_many_arguments)]
 fn register_definition(
     tock_registers: &Path,
+    cfg: &TokenStream,
     docs: TokenStream,
+    visibility: &TokenStream,
     bus_default: &TokenStream,
     struct_name: &Ident,
     register: &RegisterSpec,
     operations: &[Path],
+    reset: &TokenStream,
 ) -> TokenStream {
     let new_comment = new_doc_comment();
     let element_type = &register.element_type;
-    let mut op_macros = Vec::with_capacity(operations.len());
-    let mut op_generics = Vec::with_capacity(operations.len());
-    for mut path in operations.iter().cloned() {
-        let last = path.segments.last_mut().expect("empty operation path");
-        op_generics.push(replace(&mut last.arguments, PathArguments::None));
-        op_macros.push(path);
-    }
+    let reset_const = match register.reset {
+        None => quote![],
+        Some(_) => {
+            let reset_comment = reset_doc_comment();
+            let value = value_type(tock_registers, register);
+            quote![#reset_comment pub const RESET: #value = #reset;]
+        }
+    };
+    let Operations {
+        macros: op_macros,
+        generics: op_generics,
+        names: op_names,
+    } = Operations::new(operations);
     quote! {
-        #docs #[derive(Clone)] pub struct #struct_name<B: Bus #bus_default> {
+        #cfg #docs #[derive(Clone)] #visibility struct #struct_name<B: Bus #bus_default> {
             address: B,
             _phantom: #tock_registers::internal::RealPhantom,
         }
-        impl<B: Bus> #struct_name<B> {
+        #cfg impl<B: Bus> #struct_name<B> {
             #new_comment pub const unsafe fn new(address: B) -> Self {
                 Self { address, _phantom: #tock_registers::internal::RealPhantom::new() }
             }
+            #reset_const
         }
-        impl<B: Bus> #tock_registers::internal::core::marker::Copy for #struct_name<B> {}
+        #cfg impl<B: Bus> #tock_registers::internal::core::marker::Copy for #struct_name<B> {}
         // Safety: DataTypeBus' safety invariant requires PADDED_SIZE to be correct.
-        unsafe impl<B: Bus> #tock_registers::Span for #struct_name<B> {
+        #cfg unsafe impl<B: Bus> #tock_registers::Span for #struct_name<B> {
             type Address = B;
             const SIZE: usize = <B as #tock_registers::DataTypeBus<#element_type>>::PADDED_SIZE;
+            const ALIGNMENT: usize =
+                <B as #tock_registers::DataTypeBus<#element_type>>::ALIGNMENT;
             unsafe fn with_addr(address: B) -> Self {
                 Self { address, _phantom: #tock_registers::internal::RealPhantom::new()  }
             }
             type Borrowed<'b> = #struct_name<#tock_registers::BorrowedBus<'b, B>>;
         }
-        impl<B: Bus> #tock_registers::Register for #struct_name<B> {
+        #cfg impl<B: Bus> #tock_registers::Register for #struct_name<B> {
+            type DataType = #element_type;
+        }
+        #cfg impl<B: Bus> #tock_registers::layout::LayoutInfo for #struct_name<B> {
+            const LAYOUT: #tock_registers::layout::Layout =
+                #tock_registers::layout::Layout::Register {
+                    size: <Self as #tock_registers::Span>::SIZE,
+                    operations: &[#(#op_names),*],
+                };
+        }
+        #(#cfg #op_macros!(real_impl, #struct_name, #element_type, #op_generics,);)*
+    }
+}
+
+/// Generates the fake register struct for a single register definition or register definition
+/// field, which wraps a FakeCell and implements only the register's operations. `struct_name` is
+/// the name of the struct to generate, and `reset` is the path to the constant holding the
+/// register's reset value (only used if the register has a reset value). `cfg` and `visibility`
+/// are as in `register_definition`.
+#[allow(clippy::too_many_arguments)]
+fn fake_register_definition(
+    tock_registers: &Path,
+    cfg: &TokenStream,
+    docs: TokenStream,
+    visibility: &TokenStream,
+    struct_name: &Ident,
+    register: &RegisterSpec,
+    operations: &[Path],
+    reset: &TokenStream,
+) -> TokenStream {
+    let new_fake_comment = new_fake_doc_comment();
+    let element_type = &register.element_type;
+    let core = quote![#tock_registers::internal::core];
+    let cell = quote![#tock_registers::FakeCell<'h, #element_type>];
+    // Registers without a reset value use FakeCell's Default impl, which requires the register's
+    // value type to implement Default.
+    let (init, bound) = match register.reset {
+        None => (
+            quote![#core::default::Default::default()],
+            quote![where #cell: #core::default::Default],
+        ),
+        Some(_) => (quote![#tock_registers::FakeCell::new(#reset)], quote![]),
+    };
+    let Operations {
+        macros: op_macros,
+        generics: op_generics,
+        ..
+    } = Operations::new(operations);
+    quote! {
+        #cfg #docs #visibility struct #struct_name<'h>(#cell);
+        #cfg impl<'h> #struct_name<'h> {
+            #new_fake_comment pub fn new() -> Self #bound { Self(#init) }
+        }
+        #cfg impl<'h> #core::default::Default for #struct_name<'h> #bound {
+            fn default() -> Self { Self::new() }
+        }
+        #cfg impl<'h> #core::ops::Deref for #struct_name<'h> {
+            type Target = #cell;
+            fn deref(&self) -> &#cell { &self.0 }
+        }
+        #cfg impl #tock_registers::Register for &#struct_name<'_> {
             type DataType = #element_type;
         }
-        #(#op_macros!(real_impl, #struct_name, #element_type, #op_generics,);)*
+        #(#cfg #op_macros!(fake_impl, #struct_name, #element_type, #op_generics,);)*
+    }
+}
+
+/// A register definition's operations, split into the arguments the code generators pass to the
+/// operation macros.
+struct Operations {
+    /// The path to each operation's macro (the operation's path without generic arguments).
+    macros: Vec<Path>,
+    /// Each operation's generic arguments, which are passed to its macro.
+    generics: Vec<PathArguments>,
+    /// Each operation's name, for the register's LayoutInfo.
+    names: Vec<String>,
+}
+
+impl Operations {
+    fn new(operations: &[Path]) -> Operations {
+        let mut ops = Operations {
+            macros: Vec::with_capacity(operations.len()),
+            generics: Vec::with_capacity(operations.len()),
+            names: Vec::with_capacity(operations.len()),
+        };
+        for mut path in operations.iter().cloned() {
+            let last = path.segments.last_mut().expect("empty operation path");
+            ops.generics
+                .push(replace(&mut last.arguments, PathArguments::None));
+            ops.names.push(last.ident.unraw().to_string());
+            ops.macros.push(path);
+        }
+        ops
+    }
+}
+
+/// Returns the first paragraph of a doc comment, with its lines joined by spaces. Doc attributes
+/// whose values are not string literals (such as `#[doc = include_str!(...)]`) are ignored.
+fn doc_summary(docs: &[Attribute]) -> String {
+    let mut lines = Vec::new();
+    for attr in docs {
+        let Meta::NameValue(MetaNameValue {
+            value: Expr::Lit(ExprLit {
+                lit: Lit::Str(doc), ..
+            }),
+            ..
+        }) = &attr.meta
+        else {
+            continue;
+        };
+        lines.extend(doc.value().split('\n').map(|line| line.trim().to_owned()));
+    }
+    let paragraph: Vec<_> = lines
+        .into_iter()
+        .skip_while(String::is_empty)
+        .take_while(|line| !line.is_empty())
+        .collect();
+    paragraph.join(" ")
+}
+
+/// Returns a `#[cfg]` attribute for each of the given predicates.
+fn cfg_attributes(predicates: &[Meta]) -> TokenStream {
+    quote![#(#[cfg(#predicates)])*]
+}
+
+/// Returns the value type (`<DataType as tock_registers::DataType>::Value`) of a register
+/// definition.
+fn value_type(tock_registers: &Path, register: &RegisterSpec) -> TokenStream {
+    let element_type = &register.element_type;
+    quote![<#element_type as #tock_registers::DataType>::Value]
+}
+
+/// How a register is formatted by the `fmt_dump` functions that implement debug dumps. Whether a
+/// register is read depends on the traits its type implements (see `internal::dump`), so that
+/// the operations' names do not matter.
+struct Dump {
+    /// Expression that is true if the register is dumped. A register definition is dumped if its
+    /// type implements Read and not ReadDestructive (reading a register with read side effects
+    /// could change the hardware's state), and a reference is dumped if the referenced layout's
+    /// `is_dumped` function returns true.
+    dumped: TokenStream,
+    /// Expression that formats the register (or register array).
+    expr: TokenStream,
+}
+
+impl Dump {
+    /// Returns how `register` is formatted. `value` is the register (or register array), `probe`
+    /// is a `Probe` of its type, and the returned expression formats `value` into the Formatter
+    /// `f`.
+    fn new(
+        tock_registers: &Path,
+        register: &RegisterSpec,
+        value: TokenStream,
+        probe: TokenStream,
+    ) -> Dump {
+        let internal = quote![#tock_registers::internal];
+        let dump = quote![#internal::dump];
+        // A register array is dumped if its elements are.
+        let mut probe = probe;
+        for _ in &register.array_sizes {
+            probe = quote![#dump::element_probe(#probe)];
+        }
+        // The expression that determines whether the register is dumped, and the function that
+        // formats a register (or, for arrays, an innermost element).
+        let (dumped, mut element) = match &register.operations {
+            Some(_) => {
+                let element_type = &register.element_type;
+                let value_fmt = quote![(&&&#dump::Probe::<#element_type>::new()).value_fmt()];
+                let element = quote! {
+                    |register, f| (&&&#dump::Probe::of(&register)).fmt_register(register, f, #value_fmt)
+                };
+                (quote![(&&&#probe).readable()], element)
+            }
+            None => {
+                let reference = Reference::new(&register.element_type);
+                let (module, args) = (&reference.module, &reference.args);
+                (
+                    quote![#module::is_dumped::<#(#args,)* _>(#probe)],
+                    quote![#module::fmt_dump::<#(#args,)* _>],
+                )
+            }
+        };
+        // Each level of array nesting formats its elements as a list, using the previous level's
+        // function. The outermost level formats `value` directly.
+        for _ in 1..register.array_sizes.len() {
+            element = quote![|register, f| #internal::fmt_array(register, f, #element)];
+        }
+        let expr = match register.array_sizes.is_empty() {
+            true => quote![#internal::fmt_with(#value, f, #element)],
+            false => quote![#internal::fmt_array(#value, f, #element)],
+        };
+        Dump { dumped, expr }
+    }
+}
+
+/// The layout referred to by a register reference. References to generic register blocks (such as
+/// `timer<4>`) pass their generic arguments to the referenced module's items.
+struct Reference {
+    /// The path of the referenced layout's module.
+    module: TokenStream,
+    /// The generic arguments, which are empty unless the reference is to a generic register block.
+    args: Vec<GenericArgument>,
+    /// The span of the reference's type, which errors about unsupported buses point to.
+    span: Span,
+}
+
+impl Reference {
+    fn new(element_type: &Type) -> Reference {
+        let span = element_type.span();
+        let Type::Path(TypePath { qself: None, path }) = element_type else {
+            return Reference {
+                module: quote![#element_type],
+                args: Vec::new(),
+                span,
+            };
+        };
+        let mut module = path.clone();
+        let last = module.segments.last_mut().expect("empty reference path");
+        let args = match &last.arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter().cloned().collect(),
+            _ => Vec::new(),
+        };
+        if !args.is_empty() {
+            last.arguments = PathArguments::None;
+        }
+        Reference {
+            module: quote![#module],
+            args,
+            span,
+        }
+    }
+
+    /// Returns the referenced layout's Interface trait.
+    fn interface(&self) -> TokenStream {
+        let (module, args) = (&self.module, &self.args);
+        match args.is_empty() {
+            true => quote![#module::Interface],
+            false => quote![#module::Interface<#(#args),*>],
+        }
+    }
+
+    /// Returns the trait that buses must implement to access the referenced layout: its Bus trait,
+    /// or its BusLayout trait if the reference has generic arguments.
+    fn bus(&self) -> TokenStream {
+        let (module, args) = (&self.module, &self.args);
+        match args.is_empty() {
+            true => quote_spanned![self.span=>#module::Bus],
+            false => quote_spanned![self.span=>#module::BusLayout<#(#args),*>],
+        }
+    }
+
+    /// Returns the referenced layout's Real type on bus `B`.
+    fn real(&self) -> TokenStream {
+        let (module, args) = (&self.module, &self.args);
+        quote![#module::Real<#(#args,)* B>]
+    }
+
+    /// Returns the referenced layout's Fake type, with lifetime `'h`.
+    fn fake(&self) -> TokenStream {
+        let (module, args) = (&self.module, &self.args);
+        quote![#module::Fake<'h #(, #args)*>]
+    }
+}
+
+/// Returns the doc comment for a register's reset value constant.
+fn reset_doc_comment() -> TokenStream {
+    quote! {
+        /// The value this register contains after the hardware is reset.
+    }
+}
+
+/// Returns the doc comment for the `new` function of a fake register or register block.
+fn new_fake_doc_comment() -> TokenStream {
+    quote! {
+        /// Constructs a new fake, in which every register contains its reset value (registers
+        /// without a `#[reset]` value contain their default value).
     }
 }
 
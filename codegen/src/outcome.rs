@@ -0,0 +1,170 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+//! Error tracking for parsing. See doc/ParseErrorRecovery.md for the motivation behind this design.
+
+use std::mem::replace;
+use syn::{Error, Result};
+
+/// `Result<T, Error>` only allows us to express two outcomes: perfect success, or immediate error.
+/// However, an immediate error is a pretty harsh outcome: it stops parsing, which prevents the
+/// macro from outputting more than one error at a time, and it prevents code generation, which
+/// will result in many "unknown module" errors from the code that depends on the generated module.
+/// Therefore, for any AST node with non-immediate errors, we parse into `Result<Outcome<T>,
+/// Error>` instead. Note that because `syn::parse::Parse` always returns `Result<Self>`, we still
+/// use `Result::Err` to communicate errors that should immediately stop parsing.
+#[cfg_attr(test, derive(Debug))]
+pub enum Outcome<T> {
+    /// Full success (no errors)
+    Ok(T),
+    /// An error that does not stop parsing or code generation.
+    Continue(T, Error),
+    /// An error that stops code generation but not parsing.
+    NoGenerate(Error),
+}
+
+/// API used to populate an Outcome. Generally, [`Parse`](syn::parse::Parse) impls will use an
+/// `Outcome<()>` to track their errors and to return early if an error prevents them from
+/// generating a T (either an unrecoverable error or a NoGenerate error). On success, the Parse
+/// impls will use [`success`](Outcome::success) to return the Outcome with the newly-parsed value
+/// inside.
+impl Outcome<()> {
+    /// Constructs a new Outcome with empty contents.
+    pub fn new() -> Outcome<()> {
+        Outcome::Ok(())
+    }
+
+    /// Attaches new data to the Outcome and returns the new Outcome wrapped in a [`Result`]. Used
+    /// at the end of [`Parse`](syn::parse::Parse) implementations.
+    pub fn success<T>(self, value: T) -> Result<Outcome<T>> {
+        Ok(match self {
+            Outcome::Ok(()) => Outcome::Ok(value),
+            Outcome::Continue((), err) => Outcome::Continue(value, err),
+            Outcome::NoGenerate(err) => Outcome::NoGenerate(err),
+        })
+    }
+
+    /// Returns this Outcome for a node that could not be constructed because of a no-generate
+    /// error. Must only be called after [`chain_outcome`](Outcome::chain_outcome) has returned
+    /// `None` or [`add_no_generate`](Outcome::add_no_generate) has been called.
+    pub fn failure<T>(self) -> Result<Outcome<T>> {
+        match self {
+            Outcome::NoGenerate(err) => Ok(Outcome::NoGenerate(err)),
+            _ => unreachable!("Outcome::failure called without a no-generate error"),
+        }
+    }
+
+    /// Used to handle an error from an operation that returns `Result<Node>` directly (rather than
+    /// `Result<Outcome<Node>>`). If the operation errored, returns Err (with this Outcome's
+    /// accumulated errors prepended); if the operation succeeded, returns the node.
+    pub fn chain_result<T>(&mut self, result: Result<T>) -> Result<T> {
+        let err2 = match result {
+            Ok(value) => return Ok(value),
+            Err(err2) => err2,
+        };
+        // We have to replace the moved-from error with *something*. Since *self should never be
+        // used again, we can reset it back to an empty Ok.
+        match replace(self, Outcome::Ok(())) {
+            Outcome::Ok(()) => Err(err2),
+            Outcome::Continue((), mut err1) | Outcome::NoGenerate(mut err1) => {
+                err1.combine(err2);
+                Err(err1)
+            }
+        }
+    }
+
+    /// Used to handle an error from an operation whose failure does not prevent code generation
+    /// (because the caller can ignore the node, such as an invalid attribute argument). If the
+    /// operation errored, adds the error to this Outcome and returns `None`.
+    pub fn chain_continue<T>(&mut self, result: Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.add_continue(err);
+                None
+            }
+        }
+    }
+
+    /// Used to handle the result of parsing a child node that has its own Outcome. Unrecoverable
+    /// errors are handled as in [`chain_result`](Outcome::chain_result), and the child's other
+    /// errors are handled as in [`merge`](Outcome::merge).
+    pub fn chain_outcome<T>(&mut self, result: Result<Outcome<T>>) -> Result<Option<T>> {
+        let outcome = self.chain_result(result)?;
+        Ok(self.merge(outcome))
+    }
+
+    /// Adds a child node's errors to this Outcome. If the child has a no-generate error, this
+    /// Outcome becomes no-generate as well and `None` is returned.
+    pub fn merge<T>(&mut self, outcome: Outcome<T>) -> Option<T> {
+        match outcome {
+            Outcome::Ok(value) => Some(value),
+            Outcome::Continue(value, err) => {
+                self.add_continue(err);
+                Some(value)
+            }
+            Outcome::NoGenerate(err) => {
+                self.add_no_generate(err);
+                None
+            }
+        }
+    }
+
+    /// Used to handle the result of parsing a child node that is generated independently of its
+    /// siblings (such as a layout). All of the child's errors (including unrecoverable errors) are
+    /// added to this Outcome as continuable errors, and the child is returned if it can be
+    /// generated.
+    pub fn chain_independent<T>(&mut self, result: Result<Outcome<T>>) -> Option<T> {
+        match result {
+            Ok(Outcome::Ok(value)) => Some(value),
+            Ok(Outcome::Continue(value, err)) => {
+                self.add_continue(err);
+                Some(value)
+            }
+            Ok(Outcome::NoGenerate(err)) | Err(err) => {
+                self.add_continue(err);
+                None
+            }
+        }
+    }
+
+    /// Adds an error that does not prevent code generation.
+    pub fn add_continue(&mut self, err: Error) {
+        match self {
+            Outcome::Ok(()) => *self = Outcome::Continue((), err),
+            Outcome::Continue((), prev) | Outcome::NoGenerate(prev) => prev.combine(err),
+        }
+    }
+
+    /// Adds an error that prevents code generation.
+    pub fn add_no_generate(&mut self, err: Error) {
+        *self = match replace(self, Outcome::Ok(())) {
+            Outcome::Ok(()) => Outcome::NoGenerate(err),
+            Outcome::Continue((), mut prev) | Outcome::NoGenerate(mut prev) => {
+                prev.combine(err);
+                Outcome::NoGenerate(prev)
+            }
+        };
+    }
+
+    /// Prevents code generation without adding a new error, because the problem has already been
+    /// reported by an earlier error. Must only be called after an error has been added.
+    pub fn prevent_generation(&mut self) {
+        *self = match replace(self, Outcome::Ok(())) {
+            Outcome::Continue((), err) | Outcome::NoGenerate(err) => Outcome::NoGenerate(err),
+            Outcome::Ok(()) => unreachable!("prevent_generation called without an error"),
+        };
+    }
+}
+
+impl<T> Outcome<T> {
+    /// Converts the value inside this Outcome (if any), keeping its errors.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Outcome<U> {
+        match self {
+            Outcome::Ok(value) => Outcome::Ok(f(value)),
+            Outcome::Continue(value, err) => Outcome::Continue(f(value), err),
+            Outcome::NoGenerate(err) => Outcome::NoGenerate(err),
+        }
+    }
+}
@@ -5,219 +5,535 @@
 
 //! Input parser. The best reference for what this does is the [ast] module, as the doc comment on
 //! each AST type shows that type's definition syntax.
+//!
+//! Most AST nodes are parsed into an [Outcome], which allows the parser to report multiple errors
+//! and to generate code for the parts of the input that do not contain errors (see
+//! doc/ParseErrorRecovery.md).
 
-use crate::ast::{BusAttr, Field, FieldDef, Input, Layout, PerBusInt, RegisterSpec, Value};
+use crate::ast::{
+    Bitfield, BitfieldValue, BusAttr, ConstInt, Field, FieldDef, Input, Layout, PerBusInt,
+    RegisterSpec, Value,
+};
+use crate::outcome::Outcome;
+use proc_macro2::{Spacing, TokenTree};
+use syn::parse::discouraged::Speculative;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::{Brace, Bracket};
-use syn::{braced, bracketed, AttrStyle, Attribute, Error, LitInt, Meta, Result, Token, Type};
+use syn::{
+    braced, bracketed, parenthesized, AttrStyle, Attribute, ConstParam, Error, Expr, ExprLit,
+    GenericParam, Generics, Ident, Lit, LitInt, Meta, Result, Token, Type, Visibility,
+};
 
-impl Parse for Input {
-    fn parse(input: ParseStream) -> Result<Input> {
+impl Parse for Outcome<Input> {
+    fn parse(input: ParseStream) -> Result<Outcome<Input>> {
+        let mut out = Outcome::new();
         let tock_registers = input.parse()?;
         // Parse attributes that apply to all layouts.
-        let (docs, bus) = layout_attributes(Attribute::parse_inner(input)?)?;
-        let punctuated = Punctuated::<Layout, Token![,]>::parse_terminated(input)?;
-        let mut layouts = Vec::with_capacity(punctuated.len());
-        for mut layout in punctuated {
+        let attributes = Attribute::parse_inner(input)?;
+        let (docs, cfgs, bus, fake, reset) = layout_attributes(&mut out, attributes);
+        // The only no-generate errors layout_attributes reports are for invalid bus attributes, in
+        // which case "no bus specified" errors would be redundant.
+        let bus_invalid = matches!(out, Outcome::NoGenerate(_));
+        if let Some(reset) = reset {
+            out.add_continue(Error::new_spanned(
+                reset,
+                "#[reset] requires a register definition",
+            ));
+        }
+        let mut layouts = Vec::new();
+        // Errors in one layout do not prevent the other layouts from being generated.
+        for layout in parse_list::<Outcome<Layout>>(input) {
+            let Some(mut layout) = out.chain_independent(layout) else {
+                continue;
+            };
             // Prepend the global (inner attribute) docs to each Layout's local (outer attribute)
             // docs).
             layout.docs = docs.iter().cloned().chain(layout.docs).collect();
+            layout.cfgs = cfgs.iter().cloned().chain(layout.cfgs).collect();
+            layout.fake |= fake;
             // Combine the Layout's buses specification with the global buses specification.
             if layout.bus.as_slice().is_empty() {
                 if bus.as_slice().is_empty() {
-                    return Err(Error::new(layout.name.span(), "no bus specified"));
+                    if !bus_invalid {
+                        out.add_continue(Error::new(layout.name.span(), "no bus specified"));
+                    }
+                    continue;
                 }
                 layout.bus = bus.clone();
             }
-            let Value::Block(fields) = &layout.value else {
-                layouts.push(layout);
-                continue;
-            };
-            for field in fields {
-                if let PerBusInt::Array(offsets) = &field.offsets {
-                    if offsets.len() != layout.bus.len() {
-                        return Err(Error::new(
-                            offsets[0].span(),
-                            format!(
-                                "number of offsets ({}) does not match number of buses ({})",
-                                offsets.len(),
-                                layout.bus.len()
-                            ),
-                        ));
-                    };
-                }
-                if let FieldDef::Padding(Some(PerBusInt::Array(sizes))) = &field.field_def {
-                    if sizes.len() != layout.bus.len() {
-                        return Err(Error::new(
-                            sizes[0].span(),
-                            format!(
-                                "number of sizes ({}) does not match number of buses ({})",
-                                sizes.len(),
-                                layout.bus.len()
-                            ),
-                        ));
-                    }
-                }
+            match check_bus_counts(&layout) {
+                Ok(()) => layouts.push(layout),
+                Err(error) => out.add_continue(error),
             }
-            layouts.push(layout);
         }
-        Ok(Input {
+        out.success(Input {
             tock_registers,
             layouts,
         })
     }
 }
 
-impl Parse for Layout {
-    fn parse(input: ParseStream) -> Result<Layout> {
-        let (docs, bus) = layout_attributes(Attribute::parse_outer(input)?)?;
-        Ok(Layout {
+/// Checks that the number of offsets, strides, and padding sizes of each of a block's fields
+/// matches the number of buses of the block.
+fn check_bus_counts(layout: &Layout) -> Result<()> {
+    let Value::Block(fields) = &layout.value else {
+        return Ok(());
+    };
+    for field in fields {
+        if let PerBusInt::Array(offsets) = &field.offsets {
+            if offsets.len() != layout.bus.len() {
+                return Err(Error::new(
+                    offsets[0].span(),
+                    format!(
+                        "number of offsets ({}) does not match number of buses ({})",
+                        offsets.len(),
+                        layout.bus.len()
+                    ),
+                ));
+            };
+        }
+        if let FieldDef::Register {
+            stride: Some(PerBusInt::Array(strides)),
+            ..
+        } = &field.field_def
+        {
+            if strides.len() != layout.bus.len() {
+                return Err(Error::new(
+                    strides[0].span(),
+                    format!(
+                        "number of strides ({}) does not match number of buses ({})",
+                        strides.len(),
+                        layout.bus.len()
+                    ),
+                ));
+            }
+        }
+        if let FieldDef::Padding(Some(PerBusInt::Array(sizes))) = &field.field_def {
+            if sizes.len() != layout.bus.len() {
+                return Err(Error::new(
+                    sizes[0].span(),
+                    format!(
+                        "number of sizes ({}) does not match number of buses ({})",
+                        sizes.len(),
+                        layout.bus.len()
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Parse for Outcome<Layout> {
+    fn parse(input: ParseStream) -> Result<Outcome<Layout>> {
+        let mut out = Outcome::new();
+        let attributes = Attribute::parse_outer(input)?;
+        let (docs, cfgs, bus, fake, reset) = layout_attributes(&mut out, attributes);
+        let visibility = out.chain_result(input.parse())?;
+        let name = out.chain_result(input.parse())?;
+        let generics = match input.peek(Token![<]) {
+            true => out.chain_result(input.parse())?,
+            false => Generics::default(),
+        };
+        let Some(mut value) = out.chain_outcome(input.parse())? else {
+            return out.failure();
+        };
+        let generics = check_generics(&mut out, generics, &value);
+        // A single register's inline bitfields are generated in a hidden module named after the
+        // layout and re-exported, so a bitfield cannot share the layout's name.
+        if let Value::Single(spec) = &mut value {
+            spec.bitfields.retain(|bitfield| {
+                let conflicts = bitfield.name == name;
+                if conflicts {
+                    out.add_continue(Error::new(
+                        bitfield.name.span(),
+                        format!("bitfield name `{name}` conflicts with the register's name"),
+                    ));
+                }
+                !conflicts
+            });
+        }
+        if let Some(attr) = reset {
+            match &mut value {
+                Value::Single(
+                    spec @ RegisterSpec {
+                        operations: Some(_),
+                        ..
+                    },
+                ) => spec.reset = out.chain_continue(attr.parse_args()),
+                _ => out.add_continue(Error::new_spanned(
+                    attr,
+                    "#[reset] requires a register definition",
+                )),
+            }
+        }
+        out.success(Layout {
             docs,
+            cfgs,
             bus,
-            visibility: input.parse()?,
-            name: input.parse()?,
-            value: input.parse()?,
+            fake,
+            visibility,
+            name,
+            generics,
+            value,
         })
     }
 }
 
+/// Names that the generated code uses for its own generic parameters, which a layout's generic
+/// parameters cannot use.
+const RESERVED_GENERIC_NAMES: [&str; 3] = ["B", "LEVEL", "T"];
+
+/// Checks a layout's generic parameters, which must be `const NAME: usize` parameters of a register
+/// block. Returns the names of the generic parameters. Errors are no-generate errors, as the
+/// generated code would not compile.
+fn check_generics(out: &mut Outcome<()>, generics: Generics, value: &Value) -> Vec<Ident> {
+    if matches!(value, Value::Single(_)) && !generics.params.is_empty() {
+        out.add_no_generate(Error::new_spanned(
+            &generics,
+            "only register blocks can have generic parameters",
+        ));
+    }
+    let mut names = Vec::with_capacity(generics.params.len());
+    for param in &generics.params {
+        let GenericParam::Const(ConstParam {
+            attrs,
+            ident,
+            ty,
+            default: None,
+            ..
+        }) = param
+        else {
+            out.add_no_generate(Error::new_spanned(
+                param,
+                "generic parameters must have the form `const NAME: usize`",
+            ));
+            continue;
+        };
+        names.push(ident.clone());
+        if !attrs.is_empty() || !matches!(ty, Type::Path(ty) if ty.path.is_ident("usize")) {
+            out.add_no_generate(Error::new_spanned(
+                param,
+                "generic parameters must have the form `const NAME: usize`",
+            ));
+        } else if RESERVED_GENERIC_NAMES
+            .iter()
+            .any(|reserved| ident == reserved)
+        {
+            out.add_no_generate(Error::new(
+                ident.span(),
+                format!("generic parameter name `{ident}` is used by the generated code"),
+            ));
+        }
+    }
+    names
+}
+
 /// Parses attributes that belong on a Layout. If no `#[bus]` or `#[buses(...)]` is specified,
 /// returns an empty `BusAttr::Buses`. Doc comments are converted into outer attributes and the
-/// attributes are returned in order (docs, buses).
-fn layout_attributes(attributes: Vec<Attribute>) -> Result<(Vec<Attribute>, BusAttr)> {
+/// attributes are returned in order (docs, `#[cfg]` predicates, buses, whether `#[fake]` is
+/// present, the unparsed `#[reset]` attribute). Invalid bus attributes are no-generate errors (and
+/// result in an empty `BusAttr::Buses`); other errors are added to `out` as continuable errors and
+/// the invalid attributes are ignored.
+fn layout_attributes(
+    out: &mut Outcome<()>,
+    attributes: Vec<Attribute>,
+) -> (Vec<Attribute>, Vec<Meta>, BusAttr, bool, Option<Attribute>) {
     let mut docs = Vec::new();
+    let mut cfgs = Vec::new();
     let mut bus: Option<Attribute> = None;
+    let mut fake: Option<Attribute> = None;
+    let mut reset: Option<Attribute> = None;
     for mut attr in attributes {
         attr.style = AttrStyle::Outer;
         match attr.path() {
             p if p.is_ident("doc") => docs.push(attr),
-            p if p.is_ident("bus") || p.is_ident("buses") => {
-                if let Some(prev_bus) = bus {
-                    let mut error = Error::new_spanned(attr, "multiple bus attributes");
-                    error.combine(Error::new_spanned(
-                        prev_bus,
-                        "note: bus already specified here",
-                    ));
-                    return Err(error);
+            p if p.is_ident("cfg") => cfgs.extend(out.chain_continue(attr.parse_args())),
+            p if p.is_ident("bus") || p.is_ident("buses") => match &bus {
+                Some(prev) => out.add_continue(duplicate_attribute(
+                    attr,
+                    prev,
+                    "multiple bus attributes",
+                    "bus",
+                )),
+                None => bus = Some(attr),
+            },
+            p if p.is_ident("fake") => match (&fake, &attr.meta) {
+                (Some(prev), _) => out.add_continue(duplicate_attribute(
+                    attr,
+                    prev,
+                    "multiple #[fake] attributes",
+                    "fake",
+                )),
+                (None, Meta::Path(_)) => fake = Some(attr),
+                (None, _) => {
+                    out.add_continue(Error::new_spanned(attr, "#[fake] cannot have arguments"))
                 }
-                bus = Some(attr);
-            }
-            p => return Err(Error::new(p.span(), "unknown attribute")),
+            },
+            p if p.is_ident("reset") => match &reset {
+                Some(prev) => out.add_continue(duplicate_attribute(
+                    attr,
+                    prev,
+                    "multiple #[reset] attributes",
+                    "reset",
+                )),
+                None => reset = Some(attr),
+            },
+            p => out.add_continue(Error::new(p.span(), "unknown attribute")),
         }
     }
     let bus = match bus {
-        Some(bus) if bus.path().is_ident("bus") => BusAttr::Bus(bus.parse_args()?),
-        Some(buses) => {
-            let punctuated = buses.parse_args_with(Punctuated::<_, Token![,]>::parse_terminated)?;
-            if punctuated.is_empty() {
-                return Err(Error::new_spanned(buses, "buses list cannot be empty"));
-            }
-            BusAttr::Buses(punctuated.into_iter().collect())
-        }
-        None => BusAttr::Buses(Vec::new()),
+        Some(bus) if bus.path().is_ident("bus") => bus.parse_args().map(BusAttr::Bus),
+        Some(buses) => buses
+            .parse_args_with(Punctuated::<_, Token![,]>::parse_terminated)
+            .and_then(|punctuated| match punctuated.is_empty() {
+                true => Err(Error::new_spanned(buses, "buses list cannot be empty")),
+                false => Ok(BusAttr::Buses(punctuated.into_iter().collect())),
+            }),
+        None => Ok(BusAttr::Buses(Vec::new())),
     };
-    Ok((docs, bus))
+    let bus = bus.unwrap_or_else(|error| {
+        out.add_no_generate(error);
+        BusAttr::Buses(Vec::new())
+    });
+    (docs, cfgs, bus, fake.is_some(), reset)
+}
+
+/// Returns the error for an attribute that may only be specified once. `name` is the attribute's
+/// name, which is used in the note pointing at the previous attribute.
+fn duplicate_attribute(attr: Attribute, prev: &Attribute, message: &str, name: &str) -> Error {
+    let mut error = Error::new_spanned(attr, message);
+    error.combine(Error::new_spanned(
+        prev,
+        format!("note: {name} already specified here"),
+    ));
+    error
 }
 
-impl Parse for Value {
-    fn parse(input: ParseStream) -> Result<Value> {
+impl Parse for Outcome<Value> {
+    fn parse(input: ParseStream) -> Result<Outcome<Value>> {
         // Distinguish between a single register and block by looking at the first token, which
         // should be either : (single) or { (block).
         if input.peek(Token![:]) {
-            return input.parse().map(Value::Single);
+            return Ok(input.parse::<Outcome<_>>()?.map(Value::Single));
         }
         if !input.peek(Brace) {
             return Err(input.error("expected one of: `:`, `{`"));
         }
-        let fields;
-        braced!(fields in input);
-        let fields: Vec<Field> = Punctuated::<_, Token![,]>::parse_terminated(&fields)?
-            .into_iter()
-            .collect();
+        let mut out = Outcome::new();
+        let contents;
+        braced!(contents in input);
+        let mut fields = Vec::new();
+        for field in parse_list::<Outcome<Field>>(&contents) {
+            // A field that could not be parsed at all (not even its offset) prevents the block
+            // from being generated, as generating the block without it could result in confusing
+            // errors (such as missing padding).
+            let field = field.unwrap_or_else(Outcome::NoGenerate);
+            if let Some(field) = out.merge(field) {
+                fields.push(field);
+            }
+        }
         for field in fields.iter().rev() {
             match field.field_def {
                 FieldDef::Padding(None) => {
-                    return Err(Error::new(
-                        field.offsets[0].span(),
-                        "last non-aliased field cannot be padding without a size",
-                    ))
+                    // Fields whose definitions fail to parse are replaced by padding without a
+                    // size, so if an error has already been reported it may be the cause of
+                    // this error.
+                    match out {
+                        Outcome::Ok(()) => out.add_no_generate(Error::new(
+                            field.offsets[0].span(),
+                            "last non-aliased field cannot be padding without a size",
+                        )),
+                        _ => out.prevent_generation(),
+                    }
+                    break;
                 }
                 FieldDef::Register { aliased: true, .. } => continue,
                 FieldDef::Padding(Some(_)) | FieldDef::Register { aliased: false, .. } => break,
             }
         }
-        Ok(Value::Block(fields))
+        out.success(Value::Block(fields))
     }
 }
 
-impl Parse for Field {
-    fn parse(input: ParseStream) -> Result<Field> {
+impl Parse for Outcome<Field> {
+    fn parse(input: ParseStream) -> Result<Outcome<Field>> {
+        let mut out = Outcome::new();
         let mut aliased_attr: Option<Attribute> = None;
+        let mut stride_attr: Option<Attribute> = None;
+        let mut reset_attr: Option<Attribute> = None;
         let mut doc_attrs = Vec::new();
+        let mut cfgs: Vec<(Attribute, Meta)> = Vec::new();
         for attr in Attribute::parse_outer(input)? {
             match attr.path() {
                 p if p.is_ident("doc") => doc_attrs.push(attr),
-                p if p.is_ident("aliased") => {
-                    if let Some(prev) = aliased_attr {
-                        let mut error = Error::new_spanned(attr, "multiple #[aliased] attributes");
-                        error.combine(Error::new_spanned(
-                            prev,
-                            "note: aliased already specified here",
-                        ));
-                        return Err(error);
-                    }
-                    let Meta::Path(_) = attr.meta else {
-                        return Err(Error::new_spanned(attr, "#[aliased] cannot have arguments"));
-                    };
-                    aliased_attr = Some(attr);
-                }
-                p => return Err(Error::new(p.span(), "unknown attribute")),
+                p if p.is_ident("cfg") => match attr.parse_args() {
+                    Ok(predicate) => cfgs.push((attr, predicate)),
+                    Err(error) => out.add_continue(error),
+                },
+                p if p.is_ident("aliased") => match (&aliased_attr, &attr.meta) {
+                    (Some(prev), _) => out.add_continue(duplicate_attribute(
+                        attr,
+                        prev,
+                        "multiple #[aliased] attributes",
+                        "aliased",
+                    )),
+                    (None, Meta::Path(_)) => aliased_attr = Some(attr),
+                    (None, _) => out
+                        .add_continue(Error::new_spanned(attr, "#[aliased] cannot have arguments")),
+                },
+                p if p.is_ident("stride") => match &stride_attr {
+                    Some(prev) => out.add_continue(duplicate_attribute(
+                        attr,
+                        prev,
+                        "multiple #[stride] attributes",
+                        "stride",
+                    )),
+                    None => stride_attr = Some(attr),
+                },
+                p if p.is_ident("reset") => match &reset_attr {
+                    Some(prev) => out.add_continue(duplicate_attribute(
+                        attr,
+                        prev,
+                        "multiple #[reset] attributes",
+                        "reset",
+                    )),
+                    None => reset_attr = Some(attr),
+                },
+                p => out.add_continue(Error::new(p.span(), "unknown attribute")),
             }
         }
-        let offsets = input.parse()?;
-        input.parse::<Token![=>]>()?;
-        let mut field_def = input.parse()?;
+        let visibility: Visibility = out.chain_result(input.parse())?;
+        if let Visibility::Restricted(ref restricted) = visibility {
+            if !restricted.path.is_ident("crate") || restricted.in_token.is_some() {
+                out.add_continue(Error::new_spanned(
+                    restricted,
+                    "field visibility must be `pub` or `pub(crate)`",
+                ));
+            }
+        }
+        let offsets = out.chain_result(input.parse())?;
+        out.chain_result(input.parse::<Token![=>]>())?;
+        // If the field definition fails to parse, the field is replaced with padding (which
+        // extends to the next field's offset), so that the rest of the block can be generated.
+        let fork = input.fork();
+        let field_def = match fork.parse() {
+            Ok(field_def) => {
+                input.advance_to(&fork);
+                out.merge(field_def)
+            }
+            Err(error) => {
+                skip_element(input);
+                out.add_continue(error);
+                Some(FieldDef::Padding(None))
+            }
+        };
+        let Some(mut field_def) = field_def else {
+            return out.failure();
+        };
         match field_def {
             FieldDef::Padding(_) => {
                 if let Some(last) = doc_attrs.last() {
-                    return Err(Error::new(last.span(), "padding cannot have doc comments"));
+                    out.add_continue(Error::new(last.span(), "padding cannot have doc comments"));
+                }
+                if let Some((cfg, _)) = cfgs.first() {
+                    out.add_continue(Error::new_spanned(cfg, "padding cannot have #[cfg]"));
+                }
+                if !matches!(visibility, Visibility::Inherited) {
+                    out.add_continue(Error::new_spanned(
+                        visibility,
+                        "padding cannot have a visibility",
+                    ));
                 }
                 if let Some(aliased) = aliased_attr {
-                    return Err(Error::new_spanned(aliased, "padding cannot be aliased"));
+                    out.add_continue(Error::new_spanned(aliased, "padding cannot be aliased"));
+                }
+                if let Some(stride) = stride_attr {
+                    out.add_continue(Error::new_spanned(stride, "padding cannot have a stride"));
+                }
+                if let Some(reset) = reset_attr {
+                    out.add_continue(Error::new_spanned(
+                        reset,
+                        "padding cannot have a reset value",
+                    ));
                 }
             }
             FieldDef::Register {
+                ref name,
                 ref mut docs,
+                cfgs: ref mut predicates,
+                visibility: ref mut field_visibility,
                 ref mut aliased,
+                ref mut stride,
+                ref mut spec,
                 ..
             } => {
                 *docs = doc_attrs;
+                *predicates = cfgs.into_iter().map(|(_, predicate)| predicate).collect();
+                *field_visibility = visibility;
                 *aliased = aliased_attr.is_some();
+                if let Some(attr) = stride_attr {
+                    match spec.array_sizes.is_empty() {
+                        true => out
+                            .add_continue(Error::new_spanned(attr, "#[stride] requires an array")),
+                        false => *stride = out.chain_continue(attr.parse_args()),
+                    }
+                }
+                // Inline bitfields are generated in a module named after the field, next to the
+                // block's generated items. Removing the bitfields lets the register be generated
+                // without them.
+                if !spec.bitfields.is_empty()
+                    && RESERVED_BITFIELD_FIELD_NAMES
+                        .iter()
+                        .any(|reserved| name == reserved)
+                {
+                    out.add_continue(Error::new(
+                        name.span(),
+                        format!(
+                            "field `{name}` with inline bitfields conflicts with a generated item"
+                        ),
+                    ));
+                    spec.bitfields.clear();
+                }
+                if let Some(attr) = reset_attr {
+                    match spec.operations {
+                        None => out.add_continue(Error::new_spanned(
+                            attr,
+                            "#[reset] requires a register definition",
+                        )),
+                        Some(_) => spec.reset = out.chain_continue(attr.parse_args()),
+                    }
+                }
             }
         }
-        Ok(Field { offsets, field_def })
+        out.success(Field { offsets, field_def })
     }
 }
 
-impl Parse for FieldDef {
-    fn parse(input: ParseStream) -> Result<FieldDef> {
+impl Parse for Outcome<FieldDef> {
+    fn parse(input: ParseStream) -> Result<Outcome<FieldDef>> {
         if input.peek(Token![_]) {
             // The underscore tells us this field is padding.
             input.parse::<Token![_]>()?;
             if !input.peek(Token![:]) {
-                return Ok(FieldDef::Padding(None));
+                return Ok(Outcome::Ok(FieldDef::Padding(None)));
             }
             input.parse::<Token![:]>()?;
-            return Ok(FieldDef::Padding(Some(input.parse()?)));
+            return Ok(Outcome::Ok(FieldDef::Padding(Some(input.parse()?))));
         }
-        Ok(FieldDef::Register {
+        let name = input.parse()?;
+        let spec: Outcome<RegisterSpec> = input.parse()?;
+        Ok(spec.map(|spec| FieldDef::Register {
             docs: Vec::new(),
+            cfgs: Vec::new(),
+            visibility: Visibility::Inherited,
             aliased: false,
-            name: input.parse()?,
-            spec: input.parse()?,
-        })
+            stride: None,
+            name,
+            spec,
+        }))
     }
 }
 
@@ -236,12 +552,19 @@ impl Parse for PerBusInt {
     }
 }
 
-impl Parse for RegisterSpec {
-    fn parse(input: ParseStream) -> Result<RegisterSpec> {
+impl Parse for ConstInt {
+    fn parse(input: ParseStream) -> Result<ConstInt> {
+        input.parse().map(ConstInt)
+    }
+}
+
+impl Parse for Outcome<RegisterSpec> {
+    fn parse(input: ParseStream) -> Result<Outcome<RegisterSpec>> {
+        let mut out = Outcome::new();
         input.parse::<Token![:]>()?;
         // Recursive function to parse the type specification (because syn makes it hard to consume
         // individual bracket tokens).
-        fn parse_type(input: ParseStream, array_sizes: &mut Vec<LitInt>) -> Result<Type> {
+        fn parse_type(input: ParseStream, array_sizes: &mut Vec<ConstInt>) -> Result<Type> {
             if !input.peek(Bracket) {
                 return input.parse();
             }
@@ -250,6 +573,7 @@ impl Parse for RegisterSpec {
             let out = parse_type(&inner, array_sizes)?;
             inner.parse::<Token![;]>()?;
             array_sizes.push(inner.parse()?);
+            expect_empty(&inner)?;
             Ok(out)
         }
         let mut array_sizes = Vec::new();
@@ -262,10 +586,285 @@ impl Parse for RegisterSpec {
         } else {
             None
         };
-        Ok(RegisterSpec {
+        let mut bitfields = Vec::new();
+        if operations.is_some() && input.peek(Bracket) {
+            let contents;
+            bracketed!(contents in input);
+            // Invalid bitfields are left out of the generated code.
+            for bitfield in parse_list(&contents) {
+                bitfields.extend(out.chain_continue(bitfield));
+            }
+            bitfields = check_bitfields(&mut out, &element_type, bitfields);
+        }
+        out.success(RegisterSpec {
             element_type,
             array_sizes,
             operations,
+            reset: None,
+            bitfields,
         })
     }
 }
+
+impl Parse for Bitfield {
+    fn parse(input: ParseStream) -> Result<Bitfield> {
+        let docs = doc_attributes(input)?;
+        let name: Ident = input.parse()?;
+        // Parses `KEYWORD(value)`.
+        fn keyword_arg(input: ParseStream, keyword: &str) -> Result<LitInt> {
+            let ident: Ident = input.parse()?;
+            if ident != keyword {
+                return Err(Error::new(ident.span(), format!("expected `{keyword}`")));
+            }
+            let arg;
+            parenthesized!(arg in input);
+            let value = arg.parse()?;
+            expect_empty(&arg)?;
+            Ok(value)
+        }
+        let offset = keyword_arg(input, "OFFSET")?;
+        let numbits = match input.peek(Ident) {
+            true => keyword_arg(input, "NUMBITS")?,
+            false => LitInt::new("1", name.span()),
+        };
+        let mut values = Vec::new();
+        if input.peek(Bracket) {
+            let contents;
+            bracketed!(contents in input);
+            values = Punctuated::<_, Token![,]>::parse_terminated(&contents)?
+                .into_iter()
+                .collect();
+        }
+        Ok(Bitfield {
+            docs,
+            name,
+            offset,
+            numbits,
+            values,
+        })
+    }
+}
+
+impl Parse for BitfieldValue {
+    fn parse(input: ParseStream) -> Result<BitfieldValue> {
+        let docs = doc_attributes(input)?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        Ok(BitfieldValue {
+            docs,
+            name,
+            value: input.parse()?,
+        })
+    }
+}
+
+/// Parses outer attributes, returning an error if any of them are not doc comments.
+fn doc_attributes(input: ParseStream) -> Result<Vec<Attribute>> {
+    let attributes = Attribute::parse_outer(input)?;
+    if let Some(attr) = attributes.iter().find(|a| !a.path().is_ident("doc")) {
+        return Err(Error::new(attr.path().span(), "unknown attribute"));
+    }
+    Ok(attributes)
+}
+
+/// Names of types and modules generated in a register block's module, which fields with inline
+/// bitfields (whose bitfields are generated in a module named after the field) cannot use.
+const RESERVED_BITFIELD_FIELD_NAMES: [&str; 11] = [
+    "Bus",
+    "BusLayout",
+    "Fake",
+    "Interface",
+    "Real",
+    "RestrictedInterface",
+    "cfg_bounds",
+    "lens",
+    "resets",
+    "sealed",
+    "strides",
+];
+
+/// Names of items generated in a register definition's module, which bitfields cannot use.
+const RESERVED_BITFIELD_NAMES: [&str; 10] = [
+    "Bus",
+    "Element",
+    "Fake",
+    "Interface",
+    "Len",
+    "RESET",
+    "Real",
+    "Register",
+    "fmt_dump",
+    "is_dumped",
+];
+
+/// Checks that inline bitfields fit in their register's value type, do not overlap, and have
+/// enumerated values that fit in the bitfield. Returns the bitfields that can be generated: errors
+/// in a bitfield's name or bit range remove that bitfield, while other errors do not.
+fn check_bitfields(
+    out: &mut Outcome<()>,
+    element_type: &Type,
+    bitfields: Vec<Bitfield>,
+) -> Vec<Bitfield> {
+    let width = match element_type {
+        Type::Path(path) if path.qself.is_none() => path.path.get_ident(),
+        _ => None,
+    }
+    .and_then(|ident| match ident.to_string().as_str() {
+        "u8" => Some(8),
+        "u16" => Some(16),
+        "u32" => Some(32),
+        "u64" => Some(64),
+        "u128" => Some(128),
+        _ => None,
+    });
+    let Some(width) = width else {
+        out.add_continue(Error::new(
+            element_type.span(),
+            "inline bitfields require a u8, u16, u32, u64, or u128 register",
+        ));
+        return Vec::new();
+    };
+    // The bitfields that have been checked so far, with their bit ranges [start, end).
+    let mut checked: Vec<(Bitfield, u32, u32)> = Vec::with_capacity(bitfields.len());
+    'bitfields: for bitfield in bitfields {
+        let name = &bitfield.name;
+        if RESERVED_BITFIELD_NAMES
+            .iter()
+            .any(|reserved| name == reserved)
+        {
+            out.add_continue(Error::new(
+                name.span(),
+                format!("bitfield name `{name}` conflicts with a generated item"),
+            ));
+            continue;
+        }
+        let Some(offset) = out.chain_continue(bitfield.offset.base10_parse::<u32>()) else {
+            continue;
+        };
+        let Some(numbits) = out.chain_continue(bitfield.numbits.base10_parse::<u32>()) else {
+            continue;
+        };
+        if numbits == 0 {
+            out.add_continue(Error::new(
+                bitfield.numbits.span(),
+                "NUMBITS must be at least 1",
+            ));
+            continue;
+        }
+        let end = offset.saturating_add(numbits);
+        if end > width {
+            out.add_continue(Error::new(
+                name.span(),
+                format!(
+                    "bitfield `{name}` (bits {offset}..{end}) does not fit in a {width}-bit register"
+                ),
+            ));
+            continue;
+        }
+        for (prev, prev_offset, prev_end) in &checked {
+            if prev.name == *name {
+                let mut error =
+                    Error::new(name.span(), format!("duplicate bitfield name `{name}`"));
+                error.combine(Error::new(prev.name.span(), "note: first defined here"));
+                out.add_continue(error);
+                continue 'bitfields;
+            }
+            if offset < *prev_end && *prev_offset < end {
+                let mut error = Error::new(
+                    name.span(),
+                    format!("bitfield `{name}` overlaps bitfield `{}`", prev.name),
+                );
+                error.combine(Error::new(prev.name.span(), "note: overlapped bitfield"));
+                out.add_continue(error);
+            }
+        }
+        for value in &bitfield.values {
+            let Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            }) = &value.value
+            else {
+                continue;
+            };
+            let Some(parsed) = out.chain_continue(int.base10_parse::<u128>()) else {
+                continue;
+            };
+            if parsed.checked_shr(numbits).unwrap_or(0) != 0 {
+                out.add_continue(Error::new(
+                    int.span(),
+                    format!("value does not fit in the {numbits}-bit bitfield `{name}`"),
+                ));
+            }
+        }
+        checked.push((bitfield, offset, end));
+    }
+    checked.into_iter().map(|(bitfield, ..)| bitfield).collect()
+}
+
+/// Parses a comma-separated list of `T`s (with an optional trailing comma), recovering from
+/// errors in individual elements: if an element fails to parse, the rest of that element is
+/// skipped and parsing continues with the next element. Each element is parsed from a fork of
+/// `input`, so that a failed element's partially-consumed delimited groups do not cause
+/// additional "unexpected token" errors.
+fn parse_list<T: Parse>(input: ParseStream) -> Vec<Result<T>> {
+    let mut elements = Vec::new();
+    while !input.is_empty() {
+        let fork = input.fork();
+        let element =
+            fork.parse()
+                .and_then(|element| match fork.is_empty() || fork.peek(Token![,]) {
+                    true => Ok(element),
+                    false => Err(fork.error("expected `,`")),
+                });
+        if element.is_ok() {
+            input.advance_to(&fork);
+        }
+        skip_element(input);
+        // skip_element stops at a comma or the end of the input, so this cannot fail.
+        let _: Option<Token![,]> = input.parse().expect("comma parse failed");
+        elements.push(element);
+    }
+    elements
+}
+
+/// Skips tokens up to (but not including) the next comma in `input` that is not within generic
+/// arguments (such as `Foo<A, B>`), or to the end of `input` if it does not contain another such
+/// comma. A `<` opens generic arguments if it follows an identifier or `::` (so shifts and
+/// comparisons of literals do not), and a `>` closes them unless it is part of `=>` or `->`.
+fn skip_element(input: ParseStream) {
+    let _ = input.step(|cursor| {
+        let mut rest = *cursor;
+        let mut depth = 0usize;
+        let mut prev: Option<TokenTree> = None;
+        while let Some((token, next)) = rest.token_tree() {
+            if let TokenTree::Punct(punct) = &token {
+                match punct.as_char() {
+                    ',' if depth == 0 => break,
+                    '<' if matches!(&prev, Some(TokenTree::Ident(_)))
+                        || matches!(&prev, Some(TokenTree::Punct(p)) if p.as_char() == ':') =>
+                    {
+                        depth += 1
+                    }
+                    '>' if !matches!(&prev, Some(TokenTree::Punct(p))
+                        if p.spacing() == Spacing::Joint && matches!(p.as_char(), '=' | '-')) =>
+                    {
+                        depth = depth.saturating_sub(1)
+                    }
+                    _ => {}
+                }
+            }
+            prev = Some(token);
+            rest = next;
+        }
+        Ok(((), rest))
+    });
+}
+
+/// Returns an error if `input` (the contents of a delimited group) has not been fully consumed.
+/// Parsing leaves the remainder of a group unconsumed, which syn only reports when the entire
+/// input has been parsed, so checking explicitly allows the error to be recovered from.
+fn expect_empty(input: ParseStream) -> Result<()> {
+    match input.is_empty() {
+        true => Ok(()),
+        false => Err(input.error("unexpected token")),
+    }
+}
@@ -3,74 +3,112 @@
 // Copyright Tock Contributors 2026.
 // Copyright Better Bytes 2026.
 
-use crate::ast::{Field, FieldDef, Input, PerBusInt, RegisterSpec};
+use crate::ast::{
+    Bitfield, BitfieldValue, ConstInt, Field, FieldDef, Input, PerBusInt, RegisterSpec, Value,
+};
+use crate::outcome::Outcome;
+use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{parse2, parse_quote, Type};
+use std::fmt::Debug;
+use syn::parse::Parse;
+use syn::{parse2, parse_quote, Error, Meta, Type, Visibility};
+
+/// Parses `tokens` into a `T`, panicking if any errors are reported.
+#[track_caller]
+fn parse<T: Debug>(tokens: TokenStream) -> T
+where
+    Outcome<T>: Parse,
+{
+    match parse2(tokens) {
+        Ok(Outcome::Ok(value)) => value,
+        result => panic!("unexpected parse result: {result:?}"),
+    }
+}
+
+/// Parses `tokens` into a `T`, panicking if no errors are reported. Returns the messages of all the
+/// reported errors, one per line.
+#[track_caller]
+fn errors<T: Debug>(tokens: TokenStream) -> String
+where
+    Outcome<T>: Parse,
+{
+    match parse2(tokens) {
+        Ok(Outcome::Ok(value)) => panic!("expected an error, parsed {value:?}"),
+        Ok(Outcome::Continue(_, error) | Outcome::NoGenerate(error)) | Err(error) => {
+            messages(error).join("\n")
+        }
+    }
+}
+
+/// Returns the messages of all the errors combined into `error`.
+fn messages(error: Error) -> Vec<String> {
+    error.into_iter().map(|e| e.to_string()).collect()
+}
 
 // Verifies that outer and inner #[bus] and #[buses] attributes are combined correctly.
 #[test]
 fn bus() {
     use crate::ast::BusAttr::{Bus, Buses};
 
-    let error = parse2::<Input>(quote![::tock_registers b: a]).unwrap_err();
-    assert!(error.to_string().contains("no bus specified"));
+    let error = errors::<Input>(quote![::tock_registers b: a]);
+    assert!(error.contains("no bus specified"));
 
     // Shortcuts so the assert_eq!() calls don't line-wrap.
     let mmio32 = || parse_quote![Mmio32];
     let mmio64 = || parse_quote![Mmio64];
     let mmio32null = || parse_quote![Mmio32Nullable];
 
-    let input: Input = parse_quote! {
+    let input: Input = parse(quote! {
         ::tock_registers
         #[bus(Mmio32)] a: r,
         #[buses(Mmio32)] b: r,
         #[buses(Mmio32, Mmio64)] c: r,
-    };
+    });
     assert_eq!(input.layouts[0].bus, Bus(mmio32()));
     assert_eq!(input.layouts[1].bus, Buses(vec![mmio32()]));
     assert_eq!(input.layouts[2].bus, Buses(vec![mmio32(), mmio64()]));
 
-    let input: Input = parse_quote! {
+    let input: Input = parse(quote! {
         ::tock_registers #![bus(Mmio32)]
         a: r,
         #[bus(Mmio64)] b: r,
         #[buses(Mmio64)] c: r,
         #[buses(Mmio32Nullable, Mmio64)] d: r,
-    };
+    });
     assert_eq!(input.layouts[0].bus, Bus(mmio32()));
     assert_eq!(input.layouts[1].bus, Bus(mmio64()));
     assert_eq!(input.layouts[2].bus, Buses(vec![mmio64()]));
     assert_eq!(input.layouts[3].bus, Buses(vec![mmio32null(), mmio64()]));
 
-    let input: Input = parse_quote! {
+    let input: Input = parse(quote! {
         ::tock_registers #![buses(Mmio32)]
         a: r,
         #[bus(Mmio64)] b: r,
         #[buses(Mmio64)] c: r,
         #[buses(Mmio32Nullable, Mmio64)] d: r,
-    };
+    });
     assert_eq!(input.layouts[0].bus, Buses(vec![mmio32()]));
     assert_eq!(input.layouts[1].bus, Bus(mmio64()));
     assert_eq!(input.layouts[2].bus, Buses(vec![mmio64()]));
     assert_eq!(input.layouts[3].bus, Buses(vec![mmio32null(), mmio64()]));
 
-    let input: Input = parse_quote! {
+    let input: Input = parse(quote! {
         ::tock_registers #![buses(Mmio32, Mmio32Nullable)]
         a: r,
         #[bus(Mmio64)] b: r,
         #[buses(Mmio64)] c: r,
         #[buses(Mmio32Nullable, Mmio64)] d: r,
-    };
+    });
     assert_eq!(input.layouts[0].bus, Buses(vec![mmio32(), mmio32null()]));
     assert_eq!(input.layouts[1].bus, Bus(mmio64()));
     assert_eq!(input.layouts[2].bus, Buses(vec![mmio64()]));
     assert_eq!(input.layouts[3].bus, Buses(vec![mmio32null(), mmio64()]));
 
-    let error = parse2::<Input>(quote![::tock_registers #![bus(A)] #![buses(B)] b: a]).unwrap_err();
-    assert!(error.to_string().contains("multiple bus attributes"));
+    let error = errors::<Input>(quote![::tock_registers #![bus(A)] #![buses(B)] b: a]);
+    assert!(error.contains("multiple bus attributes"));
 
-    let error = parse2::<Input>(quote![::tock_registers #[buses(A)] #[bus(B)] b: a]).unwrap_err();
-    assert!(error.to_string().contains("multiple bus attributes"));
+    let error = errors::<Input>(quote![::tock_registers #[buses(A)] #[bus(B)] b: a]);
+    assert!(error.contains("multiple bus attributes"));
 }
 
 // Verifies that an error is correctly returned if the number of offsets of a field or the number
@@ -78,46 +116,205 @@ fn bus() {
 #[test]
 fn bus_count_mismatches() {
     let error =
-        parse2::<Input>(quote![::tock_registers #[bus(Port)] a { [0, 1] => a: u8 { Read } } ])
-            .unwrap_err()
-            .to_string();
+        errors::<Input>(quote![::tock_registers #[bus(Port)] a { [0, 1] => a: u8 { Read } } ]);
     assert!(error.contains("number of offsets (2) does not match number of buses (1)"));
 
     let error =
-        parse2::<Input>(quote![::tock_registers #![buses(Mmio32, Port)] a { 0 => _: [1] } ])
-            .unwrap_err()
-            .to_string();
+        errors::<Input>(quote![::tock_registers #![buses(Mmio32, Port)] a { 0 => _: [1] } ]);
     assert!(error.contains("number of sizes (1) does not match number of buses (2)"));
+
+    let error = errors::<Input>(
+        quote![::tock_registers #![buses(Mmio32, Port)] a { #[stride([4])] 0 => b: [u8; 2] } ],
+    );
+    assert!(error.contains("number of strides (1) does not match number of buses (2)"));
+}
+
+// Verifies that outer and inner #[fake] attributes are combined correctly.
+#[test]
+fn fake() {
+    let input: Input = parse(quote! {
+        ::tock_registers #![bus(Mmio32)]
+        a: r,
+        #[fake] b: r,
+    });
+    assert!(!input.layouts[0].fake);
+    assert!(input.layouts[1].fake);
+
+    let input: Input = parse(quote! {
+        ::tock_registers #![bus(Mmio32)] #![fake]
+        a: r,
+        #[fake] b: r,
+    });
+    assert!(input.layouts[0].fake);
+    assert!(input.layouts[1].fake);
+
+    let error = errors::<Input>(quote![::tock_registers #[fake] #[fake] b: a]);
+    assert!(error.contains("multiple #[fake] attributes"));
+
+    let error = errors::<Input>(quote![::tock_registers #[fake(1)] b: a]);
+    assert!(error.contains("#[fake] cannot have arguments"));
+}
+
+// Verifies that global #[cfg] predicates are prepended to each layout's predicates.
+#[test]
+fn cfg() {
+    let input: Input = parse(quote! {
+        ::tock_registers #![bus(Mmio32)] #![cfg(unix)]
+        a: r,
+        #[cfg(feature = "b")] b: r,
+    });
+    let unix: Meta = parse_quote![unix];
+    assert_eq!(input.layouts[0].cfgs, [unix.clone()]);
+    assert_eq!(input.layouts[1].cfgs, [unix, parse_quote![feature = "b"]]);
+
+    let error = errors::<Input>(quote![::tock_registers #![bus(Mmio32)] #[cfg = "a"] b: a]);
+    assert!(error.contains("expected parentheses: #[cfg(...)]"));
+}
+
+// Verifies that #[reset] attributes on layouts are stored in the layout's RegisterSpec.
+#[test]
+fn reset() {
+    let input: Input = parse(quote! {
+        ::tock_registers #![bus(Mmio32)]
+        #[reset(1 << 3)] a: [u8; 2] { Read },
+        b: u8 { Read },
+    });
+    let Value::Single(spec) = &input.layouts[0].value else {
+        panic!("expected a single register");
+    };
+    assert_eq!(spec.reset, Some(parse_quote![1 << 3]));
+    let Value::Single(spec) = &input.layouts[1].value else {
+        panic!("expected a single register");
+    };
+    assert_eq!(spec.reset, None);
+
+    let error = errors::<Input>(quote![::tock_registers #[reset(0)] b: a]);
+    assert!(error.contains("#[reset] requires a register definition"));
+
+    let error = errors::<Input>(quote![::tock_registers #[reset(0)] b {}]);
+    assert!(error.contains("#[reset] requires a register definition"));
+
+    let error = errors::<Input>(quote![::tock_registers #![reset(0)] b: u8 { Read }]);
+    assert!(error.contains("#[reset] requires a register definition"));
+
+    let error = errors::<Value>(quote![{ #[reset(0)] 0 => b: status }]);
+    assert!(error.contains("#[reset] requires a register definition"));
+
+    let error = errors::<Input>(quote![::tock_registers #[reset(0)] #[reset(0)] b: u8 {}]);
+    assert!(error.contains("multiple #[reset] attributes"));
+}
+
+// Verifies that generic parameters are parsed and that constants can only refer to them.
+#[test]
+fn generics() {
+    let input: Input = parse(quote! {
+        ::tock_registers #![bus(Mmio32)]
+        a<const N: usize, const M: usize> {
+            0 => b: [u8; N] { Read },
+            #[stride(M)] N => c: [u8; 2] { Read },
+            [N] => _: [M],
+        },
+    });
+    let layout = &input.layouts[0];
+    assert_eq!(layout.generics, ["N", "M"]);
+    let Value::Block(fields) = &layout.value else {
+        panic!("expected a block");
+    };
+    let FieldDef::Register { spec, .. } = &fields[0].field_def else {
+        panic!("expected a register");
+    };
+    assert_eq!(spec.array_sizes, [parse_quote![N]]);
+    assert_eq!(fields[1].offsets, PerBusInt::Single(parse_quote![N]));
+
+    let error = errors::<Input>(quote![::tock_registers #![bus(Mmio32)] a<const N: usize>: u8 {}]);
+    assert!(error.contains("only register blocks can have generic parameters"));
+
+    for params in [
+        quote![<T>],
+        quote![<'a>],
+        quote![<const N: u8>],
+        quote![<const N: usize = 1>],
+    ] {
+        let error = errors::<Input>(quote![::tock_registers #![bus(Mmio32)] a #params {}]);
+        assert!(error.contains("generic parameters must have the form `const NAME: usize`"));
+    }
+
+    let error = errors::<Input>(quote![::tock_registers #![bus(Mmio32)] a<const B: usize> {}]);
+    assert!(error.contains("generic parameter name `B` is used by the generated code"));
+}
+
+// Verifies that offsets, padding sizes, and array lengths can be constant expressions, and that
+// they are parenthesized or braced where the generated code needs it.
+#[test]
+fn const_exprs() {
+    let Field { offsets, field_def } = parse(quote![BASE + 4 => a: [[u8; N]; 2 * M] { Read }]);
+    assert_eq!(offsets, PerBusInt::Single(parse_quote![BASE + 4]));
+    let FieldDef::Register { spec, .. } = field_def else {
+        panic!("expected a register");
+    };
+    assert_eq!(spec.array_sizes, [parse_quote![N], parse_quote![2 * M]]);
+    let field: Field = parse(quote![[0, 4] => _: [size_of::<u32>(), SIZE as usize]]);
+    let FieldDef::Padding(Some(sizes)) = field.field_def else {
+        panic!("expected padding");
+    };
+    assert_eq!(
+        sizes,
+        PerBusInt::Array(vec![
+            parse_quote![size_of::<u32>()],
+            parse_quote![SIZE as usize]
+        ])
+    );
+
+    let (lit, param, path, expr): (ConstInt, ConstInt, ConstInt, ConstInt) = (
+        parse_quote![0x10],
+        parse_quote![N],
+        parse_quote![consts::N],
+        parse_quote![N + 1],
+    );
+    assert_eq!(
+        quote![#lit #param #path #expr].to_string(),
+        "0x10 N consts :: N (N + 1)"
+    );
+    let generic_args = [&lit, &param, &path, &expr].map(ConstInt::generic_arg);
+    assert_eq!(
+        quote![#(#generic_args)*].to_string(),
+        "0x10 N { consts :: N } { N + 1 }"
+    );
 }
 
 #[test]
 fn field() {
-    let field: Field = parse_quote! {
+    let field: Field = parse(quote! {
         ///A
         ///B
         1 => a: b
-    };
+    });
     assert_eq!(
         field,
         Field {
             offsets: PerBusInt::Single(parse_quote![1]),
             field_def: FieldDef::Register {
                 docs: vec![parse_quote![#[doc = r"A"]], parse_quote![#[doc = r"B"]]],
+                cfgs: vec![],
+                visibility: Visibility::Inherited,
                 aliased: false,
+                stride: None,
                 name: parse_quote![a],
                 spec: RegisterSpec {
                     element_type: parse_quote![b],
                     array_sizes: vec![],
                     operations: None,
+                    reset: None,
+                    bitfields: vec![],
                 },
             },
         },
     );
 
-    let error = parse2::<Field>(quote![#[aliased] 1 => _: 2]).unwrap_err();
-    assert!(error.to_string().contains("padding cannot be aliased"));
+    let error = errors::<Field>(quote![#[aliased] 1 => _: 2]);
+    assert!(error.contains("padding cannot be aliased"));
 
-    let field: Field = parse_quote![1 => _: 2];
+    let field: Field = parse(quote![1 => _: 2]);
     assert_eq!(
         field,
         Field {
@@ -126,53 +323,111 @@ fn field() {
         },
     );
 
-    let field: Field = parse_quote![#[aliased] [1, 2] => a: u8 { Read }];
+    let error = errors::<Field>(quote![#[cfg(unix)] 1 => _: 2]);
+    assert!(error.contains("padding cannot have #[cfg]"));
+
+    let error = errors::<Field>(quote![#[cfg] 1 => a: u8 { Read }]);
+    assert!(error.contains("expected attribute arguments in parentheses"));
+
+    let error = errors::<Field>(quote![pub(crate) 1 => _: 2]);
+    assert!(error.contains("padding cannot have a visibility"));
+
+    let error = errors::<Field>(quote![pub(super) 1 => a: u8 { Read }]);
+    assert!(error.contains("field visibility must be `pub` or `pub(crate)`"));
+
+    let field: Field = parse(quote![#[aliased] #[cfg(unix)] pub(crate) [1, 2] => a: u8 { Read }]);
     assert_eq!(
         field,
         Field {
             offsets: PerBusInt::Array(vec![parse_quote![1], parse_quote![2]]),
             field_def: FieldDef::Register {
                 docs: vec![],
+                cfgs: vec![parse_quote![unix]],
+                visibility: parse_quote![pub(crate)],
                 aliased: true,
+                stride: None,
                 name: parse_quote![a],
                 spec: RegisterSpec {
                     element_type: parse_quote![u8],
                     array_sizes: vec![],
                     operations: Some(vec![parse_quote![Read]]),
+                    reset: None,
+                    bitfields: vec![],
                 },
             },
         },
     );
 
-    let error = parse2::<Field>(quote![#[aliased] #[aliased] 1 => a: status]).unwrap_err();
-    assert!(error.to_string().contains("multiple #[aliased] attributes"));
+    let error = errors::<Field>(quote![#[aliased] #[aliased] 1 => a: status]);
+    assert!(error.contains("multiple #[aliased] attributes"));
+
+    let error = errors::<Field>(quote![#[aliased = 3] 1 => a: status]);
+    assert!(error.contains("cannot have arguments"));
+
+    let error = errors::<Field>(quote![#[aliased(3)] 1 => a: status]);
+    assert!(error.contains("cannot have arguments"));
+
+    let error = errors::<Field>(quote![0 => lens: u8 { Read } [A OFFSET(0)]]);
+    assert!(error.contains("field `lens` with inline bitfields conflicts with a generated item"));
+    let field: Field = parse(quote![0 => lens: u8 { Read }]);
+    assert!(matches!(field.field_def, FieldDef::Register { .. }));
+
+    let field: Field = parse(quote![#[stride([8, 16])] 0 => a: [[u8; 2]; 4] { Read }]);
+    let FieldDef::Register { stride, .. } = field.field_def else {
+        panic!("expected a register field");
+    };
+    let expected = PerBusInt::Array(vec![parse_quote![8], parse_quote![16]]);
+    assert_eq!(stride, Some(expected));
+
+    let error = errors::<Field>(quote![#[stride(8)] 1 => a: status]);
+    assert!(error.contains("#[stride] requires an array"));
+
+    let error = errors::<Field>(quote![#[stride(8)] 1 => _: 2]);
+    assert!(error.contains("padding cannot have a stride"));
+
+    let error = errors::<Field>(quote![#[stride(8)] #[stride(8)] 1 => a: [u8; 2]]);
+    assert!(error.contains("multiple #[stride] attributes"));
+
+    let field: Field = parse(quote![#[reset(0x80)] 0 => a: [u8; 2] { Read }]);
+    let FieldDef::Register { spec, .. } = field.field_def else {
+        panic!("expected a register field");
+    };
+    assert_eq!(spec.reset, Some(parse_quote![0x80]));
+
+    let error = errors::<Field>(quote![#[reset(0)] 1 => a: status]);
+    assert!(error.contains("#[reset] requires a register definition"));
 
-    let error = parse2::<Field>(quote![#[aliased = 3] 1 => a: status]).unwrap_err();
-    assert!(error.to_string().contains("cannot have arguments"));
+    let error = errors::<Field>(quote![#[reset(0)] 1 => _: 2]);
+    assert!(error.contains("padding cannot have a reset value"));
 
-    let error = parse2::<Field>(quote![#[aliased(3)] 1 => a: status]).unwrap_err();
-    assert!(error.to_string().contains("cannot have arguments"));
+    let error = errors::<Field>(quote![#[reset(0)] #[reset(1)] 1 => a: u8 { Read }]);
+    assert!(error.contains("multiple #[reset] attributes"));
 }
 
 #[test]
 fn field_def() {
-    let field: FieldDef = parse_quote![_: 3];
+    let field: FieldDef = parse(quote![_: 3]);
     assert_eq!(field, FieldDef::Padding(Some(parse_quote![3])));
 
-    let field: FieldDef = parse_quote![_];
+    let field: FieldDef = parse(quote![_]);
     assert_eq!(field, FieldDef::Padding(None));
 
-    let field: FieldDef = parse_quote![a: status];
+    let field: FieldDef = parse(quote![a: status]);
     assert_eq!(
         field,
         FieldDef::Register {
             docs: vec![],
+            cfgs: vec![],
+            visibility: Visibility::Inherited,
             aliased: false,
+            stride: None,
             name: parse_quote![a],
             spec: RegisterSpec {
                 element_type: parse_quote![status],
                 array_sizes: vec![],
                 operations: None,
+                reset: None,
+                bitfields: vec![],
             }
         }
     );
@@ -190,23 +445,189 @@ fn per_bus_int() {
 
 #[test]
 fn register_def() {
-    let register: RegisterSpec = parse_quote![: <Foo as Bar>::Associated { Read, Write }];
+    let register: RegisterSpec = parse(quote![: <Foo as Bar>::Associated { Read, Write }]);
     let expected_type: Type = parse_quote![<Foo as Bar>::Associated];
     assert_eq!(register.element_type, expected_type);
     assert_eq!(register.array_sizes, []);
     let expected_operations = vec![parse_quote![Read], parse_quote![Write]];
     assert_eq!(register.operations, Some(expected_operations));
 
-    let register: RegisterSpec = parse_quote![: status];
+    let register: RegisterSpec = parse(quote![: status]);
     let expected_type: Type = parse_quote![status];
     assert_eq!(register.element_type, expected_type);
     assert_eq!(register.array_sizes, []);
     assert_eq!(register.operations, None);
 
-    let register: RegisterSpec = parse_quote![: [[[*mut u8; 2]; 3]; 4]];
+    let register: RegisterSpec = parse(quote![: [[[*mut u8; 2]; 3]; 4]]);
     let expected_type: Type = parse_quote![*mut u8];
     assert_eq!(register.element_type, expected_type);
     let expected_sizes = [parse_quote![2], parse_quote![3], parse_quote![4]];
     assert_eq!(register.array_sizes, expected_sizes);
     assert_eq!(register.operations, None);
 }
+
+// Verifies that inline bitfields are parsed and validated.
+#[test]
+fn bitfields() {
+    let register: RegisterSpec = parse(quote! {
+        : u16 { Read } [
+            A OFFSET(0),
+            /// Doc comment
+            B OFFSET(1) NUMBITS(15) [X = 0x7fff],
+        ]
+    });
+    let expected = vec![
+        Bitfield {
+            docs: vec![],
+            name: parse_quote![A],
+            offset: parse_quote![0],
+            numbits: parse_quote![1],
+            values: vec![],
+        },
+        Bitfield {
+            docs: vec![parse_quote![#[doc = r" Doc comment"]]],
+            name: parse_quote![B],
+            offset: parse_quote![1],
+            numbits: parse_quote![15],
+            values: vec![BitfieldValue {
+                docs: vec![],
+                name: parse_quote![X],
+                value: parse_quote![0x7fff],
+            }],
+        },
+    ];
+    assert_eq!(register.bitfields, expected);
+
+    let error = errors::<RegisterSpec>;
+    assert!(error(quote![: Ctrl { Read } [A OFFSET(0)]]).contains("inline bitfields require a u8"));
+    assert!(error(quote![: u8 { Read } [A NUMBITS(1)]]).contains("expected `OFFSET`"));
+    assert!(error(quote![: u8 { Read } [A OFFSET(0) SIZE(1)]]).contains("expected `NUMBITS`"));
+    assert!(error(quote![: u8 { Read } [A OFFSET(0) NUMBITS(0)]]).contains("at least 1"));
+    assert!(error(quote![: u8 { Read } [A OFFSET(4) NUMBITS(5)]])
+        .contains("bitfield `A` (bits 4..9) does not fit in a 8-bit register"));
+    assert!(
+        error(quote![: u8 { Read } [A OFFSET(0) NUMBITS(3), B OFFSET(2)]])
+            .contains("bitfield `B` overlaps bitfield `A`")
+    );
+    assert!(error(quote![: u8 { Read } [A OFFSET(0), A OFFSET(1)]])
+        .contains("duplicate bitfield name `A`"));
+    assert!(
+        error(quote![: u8 { Read } [A OFFSET(0) NUMBITS(2) [X = 4]]])
+            .contains("value does not fit in the 2-bit bitfield `A`")
+    );
+    assert!(error(quote![: u8 { Read } [RESET OFFSET(0)]])
+        .contains("bitfield name `RESET` conflicts with a generated item"));
+    assert!(
+        error(quote![: u8 { Read } [#[allow(unused)] A OFFSET(0)]]).contains("unknown attribute")
+    );
+    assert!(
+        errors::<Input>(quote![::tock_registers #[bus(Mmio32)] A: u8 { Read } [A OFFSET(0)]])
+            .contains("bitfield name `A` conflicts with the register's name")
+    );
+}
+
+// Verifies that errors that do not prevent code generation are reported alongside the parsed
+// input, and that errors in one layout do not prevent the other layouts from being generated.
+#[test]
+fn recovery() {
+    // Invalid attributes are reported and ignored.
+    let Ok(Outcome::Continue(input, error)) = parse2::<Outcome<Input>>(quote! {
+        ::tock_registers #![bus(Mmio32)] #![reset(0)]
+        #[unknown] #[fake] #[fake] a: u8 { Read },
+        #[reset(0)] b: status,
+    }) else {
+        panic!("expected a continuable error");
+    };
+    assert_eq!(input.layouts.len(), 2);
+    assert!(input.layouts[0].fake);
+    assert_eq!(
+        messages(error),
+        [
+            "#[reset] requires a register definition",
+            "unknown attribute",
+            "multiple #[fake] attributes",
+            "note: fake already specified here",
+            "#[reset] requires a register definition",
+        ]
+    );
+
+    // Layouts that cannot be generated are left out, and the layouts after them are still parsed.
+    let Ok(Outcome::Continue(input, error)) = parse2::<Outcome<Input>>(quote! {
+        ::tock_registers #![bus(Mmio32)]
+        a: u8 { Read },
+        #[buses()] b: u8 { Read },
+        c 3,
+        d { 0 => x: u8 { Read }, 1 => _ },
+        e { 0 => x: u8 { Read } },
+    }) else {
+        panic!("expected a continuable error");
+    };
+    let names: Vec<_> = input.layouts.iter().map(|l| l.name.to_string()).collect();
+    assert_eq!(names, ["a", "e"]);
+    assert_eq!(
+        messages(error),
+        [
+            "buses list cannot be empty",
+            "expected one of: `:`, `{`",
+            "last non-aliased field cannot be padding without a size",
+        ]
+    );
+
+    // A field whose definition fails to parse is replaced with padding.
+    let Ok(Outcome::Continue(value, error)) = parse2::<Outcome<Value>>(quote! {
+        { 0 => x: [u8; 2 3] { Read }, 1 => y: u8 { Read } }
+    }) else {
+        panic!("expected a continuable error");
+    };
+    let Value::Block(fields) = value else {
+        panic!("expected a block");
+    };
+    assert_eq!(fields[0].field_def, FieldDef::Padding(None));
+    assert_eq!(messages(error), ["unexpected token"]);
+
+    // Commas within generic arguments do not end the skipped field definition or layout.
+    let Ok(Outcome::Continue(value, error)) = parse2::<Outcome<Value>>(quote! {
+        { 0 => x: Foo<A, B [u8]> { Read }, 1 => y: u8 { Read } }
+    }) else {
+        panic!("expected a continuable error");
+    };
+    let Value::Block(fields) = value else {
+        panic!("expected a block");
+    };
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].field_def, FieldDef::Padding(None));
+    assert_eq!(messages(error).len(), 1);
+    let Ok(Outcome::Continue(input, error)) = parse2::<Outcome<Input>>(quote! {
+        ::tock_registers #![bus(Mmio32)]
+        a: Foo<A, B> 3,
+        b: u8 { Read },
+    }) else {
+        panic!("expected a continuable error");
+    };
+    let names: Vec<_> = input.layouts.iter().map(|l| l.name.to_string()).collect();
+    assert_eq!(names, ["b"]);
+    assert_eq!(messages(error).len(), 1);
+
+    // If that field is the last field, the block cannot be generated, but no additional error is
+    // reported.
+    let Ok(Outcome::NoGenerate(error)) = parse2::<Outcome<Value>>(quote! {
+        { 0 => x: u8 { Read }, 1 => y: [u8; 2 3] { Read } }
+    }) else {
+        panic!("expected a no-generate error");
+    };
+    assert_eq!(messages(error).len(), 1);
+
+    // Invalid bitfields are left out.
+    let Ok(Outcome::Continue(register, error)) = parse2::<Outcome<RegisterSpec>>(quote! {
+        : u8 { Read } [A OFFSET(0), B OFFSET, C OFFSET(1), D OFFSET(9)]
+    }) else {
+        panic!("expected a continuable error");
+    };
+    let names: Vec<_> = register
+        .bitfields
+        .iter()
+        .map(|b| b.name.to_string())
+        .collect();
+    assert_eq!(names, ["A", "C"]);
+    assert_eq!(messages(error).len(), 2);
+}
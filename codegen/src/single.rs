@@ -4,10 +4,13 @@
 // Copyright Better Bytes 2026.
 
 use crate::ast::{Layout, RegisterSpec};
-use crate::{register_definition, Env};
+use crate::{
+    bitfields, cfg_attributes, fake_register_definition, register_definition, reset_doc_comment,
+    value_type, Dump, Env, Reference,
+};
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, Ident, Path};
+use syn::{parse_quote, spanned::Spanned, Ident, Path};
 
 /// Generates the module for a single register definition.
 pub fn generate(
@@ -38,10 +41,34 @@ pub fn generate(
         Env::External => quote![#![allow(dead_code)]],
         Env::ProcMacro => quote![],
     };
+    // Registers with inline bitfields use the generated bitfield register type as their DataType.
+    // The bitfields are defined in a hidden module named after the register (as
+    // register_bitfields! names the register after its module), and re-exported from this module.
+    let bitfield_register;
+    let (register, bitfield_definitions) = match register.bitfields.is_empty() {
+        true => (register, quote![]),
+        false => {
+            bitfield_register = bitfields::with_data_type(register, parse_quote![Register]);
+            let definitions = bitfields::generate(
+                tock_registers,
+                &quote![#[doc(hidden)]],
+                &layout.name,
+                register,
+            );
+            let layout_name = &layout.name;
+            let names = register.bitfields.iter().map(|b| &b.name);
+            let definitions = quote! {
+                #definitions
+                pub use self::#layout_name::{Register #(, #names)*};
+            };
+            (&bitfield_register, definitions)
+        }
+    };
     let is_scalar = register.array_sizes.is_empty();
     let is_definition = register.operations.is_some();
     let element_type = &register.element_type;
     let docs = &layout.docs;
+    let cfg = cfg_attributes(&layout.cfgs);
     let visibility = &layout.visibility;
     let name = &layout.name;
     let interface_comment = interface_doc_comment();
@@ -50,7 +77,11 @@ pub fn generate(
     let bus_bound;
     let buses = layout.bus.as_slice();
     let element_definition;
+    let mut fake_element_definition = quote![];
+    let mut reset_definition = quote![];
+    let mut interface_items = quote![];
     let mut real;
+    let mut fake;
 
     // Step 2: Work through different parts of the input to set the output variables.
 
@@ -67,18 +98,49 @@ pub fn generate(
         };
         element_definition = register_definition(
             tock_registers,
+            &quote![],
             struct_doc_comment(is_scalar),
+            &quote![pub],
             &layout.bus.generic_default(),
             &Ident::new(struct_name, Span::call_site()),
             register,
             operations,
+            &quote![RESET],
         );
+        // If statement that adds the reset value constants, if a reset value was specified.
+        if let Some(reset) = &register.reset {
+            let reset_comment = reset_doc_comment();
+            let value = value_type(tock_registers, register);
+            reset_definition = quote![#reset_comment pub const RESET: #value = #reset;];
+            interface_items = quote![#reset_comment const RESET: #value = RESET;];
+        }
+        // Scalar register definitions use the fake register struct as their Fake type.
+        let (fake_name, fake_docs) = match is_scalar {
+            true => ("Fake", fake_doc_comment()),
+            false => ("FakeElement", fake_element_doc_comment()),
+        };
+        let fake_name = Ident::new(fake_name, Span::call_site());
+        if layout.fake {
+            fake_element_definition = fake_register_definition(
+                tock_registers,
+                &quote![],
+                fake_docs,
+                &quote![pub],
+                &fake_name,
+                register,
+                operations,
+                &quote![RESET],
+            );
+        }
         real = quote![Element<B>];
+        fake = quote![#fake_name<'h>];
     } else {
-        element_bound = quote![#element_type::Interface];
-        bus_bound = quote_spanned![element_type.span()=>#element_type::Bus];
+        let reference = Reference::new(element_type);
+        element_bound = reference.interface();
+        bus_bound = reference.bus();
         element_definition = quote![];
-        real = quote![#element_type::Real<B>];
+        real = reference.real();
+        fake = reference.fake();
     }
     let mut interface_bound = element_bound.clone();
     // match that handles the difference between scalar registers, non-nested array registers, and
@@ -105,6 +167,8 @@ pub fn generate(
             impl #tock_registers::array::Len for #len_type { const LEN: usize = #size; }
         });
         real = quote![#tock_registers::RealRegisterArray<#real, #len_type>];
+        let size_arg = size.generic_arg();
+        fake = quote![#tock_registers::FakeArray<#fake, #len_type, #size_arg>];
     }
     // If statement that switches on whether this is a scalar register definition or not.
     let real_alias = if is_scalar && is_definition {
@@ -118,15 +182,43 @@ pub fn generate(
     let impl_bound_type = match (is_scalar, is_definition) {
         (true, _) => quote![Self],
         (false, true) => quote![Element<B>],
-        (false, false) => quote![#element_type::Real<B>],
+        (false, false) => Reference::new(element_type).real(),
+    };
+
+    // How debug dumps read this register. Register blocks that contain this register call fmt_dump
+    // if is_dumped returns true.
+    let core = quote![#tock_registers::internal::core];
+    let dump = quote![#tock_registers::internal::dump];
+    let Dump { dumped, expr } =
+        Dump::new(tock_registers, register, quote![register], quote![probe]);
+
+    // If statement that switches on whether a Fake implementation was requested. Scalar register
+    // definitions define Fake in fake_element_definition; other registers have a Fake alias.
+    let fake_definition = match layout.fake {
+        false => quote![],
+        true => {
+            let fake_alias = match is_scalar && is_definition {
+                true => quote![],
+                false => {
+                    let fake_comment = fake_doc_comment();
+                    quote![#fake_comment pub type Fake<'h> = #fake;]
+                }
+            };
+            quote! {
+                #fake_element_definition
+                #fake_alias
+                impl<'a, 'h> Interface for &'a Fake<'h> {}
+            }
+        }
     };
 
     // Step 3: the final quote! call that puts everything together.
     quote! {
-        #(#docs)*
+        #(#docs)* #cfg
         #visibility mod #name {
             #allows use super::*;
-            #interface_comment pub trait Interface: #interface_bound {}
+            #interface_comment pub trait Interface: #interface_bound { #interface_items }
+            #reset_definition
             #len_definition
             #bus_comment pub trait Bus: #bus_bound + sealed::Bus {}
             #(impl Bus for #buses {})*
@@ -134,9 +226,23 @@ pub fn generate(
             impl<B: Bus> Bus for #tock_registers::BorrowedBus<'_, B> {}
             impl<B: Bus> sealed::Bus for #tock_registers::BorrowedBus<'_, B> {}
             mod sealed { pub trait Bus {} }
+            #bitfield_definitions
             #element_definition
             #real_alias
             impl<B: Bus> Interface for Real<B> where #impl_bound_type: #element_bound {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: #dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use #dump::traits::*;
+                #dumped
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(register: R, f: &mut #core::fmt::Formatter<'_>)
+                -> #core::fmt::Result
+            {
+                #[allow(unused_imports)] use #dump::traits::*;
+                #expr
+            }
+            #fake_definition
         }
     }
 }
@@ -172,3 +278,19 @@ pub fn real_alias_doc_comment() -> TokenStream {
         /// Implementation of [Interface] for use with real hardware.
     }
 }
+
+pub fn fake_doc_comment() -> TokenStream {
+    quote! {
+        /// In-memory implementation of [Interface] for unit testing. `&Fake` implements
+        /// [Interface]; hooks can be attached to each register's `FakeCell` to add side effects.
+        /// `Fake::new()` constructs a fake containing the register's reset value.
+    }
+}
+
+pub fn fake_element_doc_comment() -> TokenStream {
+    quote! {
+        /// In-memory implementation of an element of this register array for unit testing. This
+        /// implements the tock_registers::Register trait as well as any operation traits specified
+        /// in the register definition.
+    }
+}
@@ -54,12 +54,20 @@ fn flat_array_definition_example() {
             unsafe impl<B: Bus> ::tock_registers::Span for Element<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
                 type Borrowed<'b> = Element<::tock_registers::BorrowedBus<'b, B>>;
             }
             impl<B: Bus> ::tock_registers::Register for Element<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Element<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Write"],
+                    };
+            }
             Read!(real_impl, Element, u8,,);
             Write!(real_impl, Element, u8,,);
             #real_alias_comment
@@ -70,6 +78,23 @@ fn flat_array_definition_example() {
                 // copy the bounds on the innermost element type, for the same reasons as for
                 // scalar registers.
                 Element<B>: ::tock_registers::Register<DataType = u8> + Read + Write {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                (&&&::tock_registers::internal::dump::element_probe(
+                    probe
+                )).readable()
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_array(register, f,
+                    |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                        .fmt_register(register, f,
+                            (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))
+            }
         }
     };
     assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
@@ -125,18 +150,46 @@ fn nested_array_definition_example() {
             unsafe impl<B: Bus> ::tock_registers::Span for Element<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
                 type Borrowed<'b> = Element<::tock_registers::BorrowedBus<'b, B>>;
             }
             impl<B: Bus> ::tock_registers::Register for Element<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Element<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Write"],
+                    };
+            }
             Read!(real_impl, Element, u8,,);
             Write!(real_impl, Element, u8,,);
             #real_alias_comment pub type Real<B> = ::tock_registers::RealRegisterArray<
                 ::tock_registers::RealRegisterArray<Element<B>, Len<0usize> >, Len<1usize> >;
             impl<B: Bus> Interface for Real<B> where
                 Element<B>: ::tock_registers::Register<DataType = u8> + Read + Write {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                (&&&::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        probe
+                    )
+                )).readable()
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_array(register, f,
+                    |register, f| ::tock_registers::internal::fmt_array(register, f,
+                        |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                            .fmt_register(register, f,
+                                (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt())))
+            }
         }
     };
     assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
@@ -172,6 +225,21 @@ fn flat_array_reference_example() {
             #real_alias_comment
             pub type Real<B> = ::tock_registers::RealRegisterArray<status::Real<B>, Len>;
             impl<B: Bus> Interface for Real<B> where status::Real<B>: status::Interface {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                status::is_dumped::<_>(::tock_registers::internal::dump::element_probe(
+                    probe
+                ))
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_array(register, f,
+                    status::fmt_dump::<_>)
+            }
         }
     };
     assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
@@ -209,6 +277,24 @@ fn nested_array_reference_example() {
             #real_alias_comment pub type Real<B> = ::tock_registers::RealRegisterArray<
                 ::tock_registers::RealRegisterArray<status::Real<B>, Len<0usize> >, Len<1usize> >;
             impl<B: Bus> Interface for Real<B> where status::Real<B>: status::Interface {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                status::is_dumped::<_>(::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        probe
+                    )
+                ))
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_array(register, f,
+                    |register, f| ::tock_registers::internal::fmt_array(register, f,
+                        status::fmt_dump::<_>))
+            }
         }
     };
     assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
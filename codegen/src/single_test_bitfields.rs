@@ -0,0 +1,95 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::single::{bus_doc_comment, interface_doc_comment, struct_doc_comment};
+use crate::{new_doc_comment, register_map, test_util::assert_tokens_eq, Env::ProcMacro};
+use quote::quote;
+
+/// Tests a scalar register definition with inline bitfields.
+#[test]
+fn bitfields() {
+    let input = quote! {
+        ::tock_registers
+        #[bus(Mmio32)]
+        pub foo: u8 { Read } [
+            A OFFSET(0),
+            /// Doc comment
+            B OFFSET(1) NUMBITS(2) [X = 1],
+        ]
+    };
+    let interface_comment = interface_doc_comment();
+    let bus_comment = bus_doc_comment();
+    let struct_comment = struct_doc_comment(true);
+    let new_comment = new_doc_comment();
+    let expected = quote! {
+        pub mod foo {
+            use super::*;
+            // The generated bitfield register type is the register's DataType.
+            #interface_comment
+            pub trait Interface: ::tock_registers::Register<DataType = Register> + Read {}
+            #bus_comment pub trait Bus: ::tock_registers::DataTypeBus<Register> + sealed::Bus {}
+            impl Bus for Mmio32 {}
+            impl sealed::Bus for Mmio32 {}
+            impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            mod sealed { pub trait Bus {} }
+            // The bitfields are defined by register_bitfields!, in a hidden module named after the
+            // register (which register_bitfields! uses as the register's name). Every bitfield
+            // specifies its width and values, as register_bitfields! requires every bitfield of a
+            // register to have the same form.
+            ::tock_registers::register_bitfields![u8, #[doc(hidden)] pub foo [
+                A OFFSET(0) NUMBITS(1) [],
+                /// Doc comment
+                B OFFSET(1) NUMBITS(2) [X = 1]
+            ]];
+            pub use self::foo::{Register, A, B};
+            #struct_comment #[derive(Clone)] pub struct Real<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> Real<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for Real<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<Register>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<Register>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for Real<B> { type DataType = Register; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
+            Read!(real_impl, Real, Register,,);
+            impl<B: Bus> Interface for Real<B>
+                where Self: ::tock_registers::Register<DataType = Register> + Read {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                (&&&probe).readable()
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_with(register, f,
+                    |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                        .fmt_register(register, f,
+                            (&&&::tock_registers::internal::dump::Probe::<Register>::new()).value_fmt()))
+            }
+        }
+    };
+    assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
+}
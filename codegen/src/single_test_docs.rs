@@ -70,16 +70,39 @@ fn scalar_definition() {
             unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
                 type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
             }
             impl<B: Bus> ::tock_registers::Register for Real<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Write"],
+                    };
+            }
             Read!(real_impl, Real, u8,,);
             Write!(real_impl, Real, u8,,);
             impl<B: Bus> Interface for Real<B> where
                 Self: ::tock_registers::Register<DataType = u8> + Read + Write {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                (&&&probe).readable()
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_with(register, f,
+                    |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                        .fmt_register(register, f,
+                            (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))
+            }
         }
     };
     assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
@@ -149,18 +172,43 @@ fn array_definition() {
             unsafe impl<B: Bus> ::tock_registers::Span for Element<B> {
                 type Address = B;
                 const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
                 unsafe fn with_addr(address: B) -> Self {
                     Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
                 }
                 type Borrowed<'b> = Element<::tock_registers::BorrowedBus<'b, B>>;
             }
             impl<B: Bus> ::tock_registers::Register for Element<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Element<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Write"],
+                    };
+            }
             Read!(real_impl, Element, u8,,);
             Write!(real_impl, Element, u8,,);
             /// Implementation of [Interface] for use with real hardware.
             pub type Real<B> = ::tock_registers::RealRegisterArray<Element<B>, Len>;
             impl<B: Bus> Interface for Real<B> where
                 Element<B>: ::tock_registers::Register<DataType = u8> + Read + Write {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                (&&&::tock_registers::internal::dump::element_probe(
+                    probe
+                )).readable()
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_array(register, f,
+                    |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                        .fmt_register(register, f,
+                            (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))
+            }
         }
     };
     assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
@@ -203,6 +251,19 @@ fn scalar_reference() {
             /// Implementation of [Interface] for use with real hardware.
             pub type Real<B> = status::Real<B>;
             impl<B: Bus> Interface for Real<B> where Self: status::Interface {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                status::is_dumped::<_>(probe)
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_with(register, f,
+                    status::fmt_dump::<_>)
+            }
         }
     };
     assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
@@ -251,6 +312,24 @@ fn array_reference() {
             pub type Real<B> = ::tock_registers::RealRegisterArray<
                 ::tock_registers::RealRegisterArray<status::Real<B>, Len<0usize> >, Len<1usize> >;
             impl<B: Bus> Interface for Real<B> where status::Real<B>: status::Interface {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                status::is_dumped::<_>(::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        probe
+                    )
+                ))
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_array(register, f,
+                    |register, f| ::tock_registers::internal::fmt_array(register, f,
+                        status::fmt_dump::<_>))
+            }
         }
     };
     assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
@@ -0,0 +1,173 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::single::{
+    bus_doc_comment, fake_doc_comment, interface_doc_comment, real_alias_doc_comment,
+    struct_doc_comment,
+};
+use crate::{
+    new_doc_comment, new_fake_doc_comment, register_map, test_util::assert_tokens_eq,
+    Env::ProcMacro,
+};
+use quote::quote;
+
+/// Tests the Fake type generated for a scalar register definition.
+#[test]
+fn scalar_definition() {
+    let input = quote! {
+        ::tock_registers
+        #[bus(Mmio32)]
+        #[fake]
+        pub foo: u8 { Read }
+    };
+    let interface_comment = interface_doc_comment();
+    let bus_comment = bus_doc_comment();
+    let struct_comment = struct_doc_comment(true);
+    let new_comment = new_doc_comment();
+    let fake_comment = fake_doc_comment();
+    let new_fake_comment = new_fake_doc_comment();
+    let expected = quote! {
+        pub mod foo {
+            use super::*;
+            #interface_comment
+            pub trait Interface: ::tock_registers::Register<DataType = u8> + Read {}
+            #bus_comment pub trait Bus: ::tock_registers::DataTypeBus<u8> + sealed::Bus {}
+            impl Bus for Mmio32 {}
+            impl sealed::Bus for Mmio32 {}
+            impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            mod sealed { pub trait Bus {} }
+            #struct_comment #[derive(Clone)] pub struct Real<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> Real<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for Real<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for Real<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<u8>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<u8>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = Real<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for Real<B> { type DataType = u8; }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Real<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read"],
+                    };
+            }
+            Read!(real_impl, Real, u8,,);
+            impl<B: Bus> Interface for Real<B>
+                where Self: ::tock_registers::Register<DataType = u8> + Read {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                (&&&probe).readable()
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_with(register, f,
+                    |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                        .fmt_register(register, f,
+                            (&&&::tock_registers::internal::dump::Probe::<u8>::new()).value_fmt()))
+            }
+            // The Fake for a register definition wraps a FakeCell, and implements only the
+            // register's operations.
+            #fake_comment pub struct Fake<'h>(::tock_registers::FakeCell<'h, u8>);
+            // Without a reset value, new uses FakeCell's Default impl. The bound keeps the module
+            // compiling for value types that do not implement Default (such as pointers).
+            impl<'h> Fake<'h> {
+                #new_fake_comment pub fn new() -> Self
+                    where ::tock_registers::FakeCell<'h, u8>:
+                        ::tock_registers::internal::core::default::Default
+                {
+                    Self(::tock_registers::internal::core::default::Default::default())
+                }
+            }
+            impl<'h> ::tock_registers::internal::core::default::Default for Fake<'h>
+                where ::tock_registers::FakeCell<'h, u8>:
+                    ::tock_registers::internal::core::default::Default
+            {
+                fn default() -> Self { Self::new() }
+            }
+            impl<'h> ::tock_registers::internal::core::ops::Deref for Fake<'h> {
+                type Target = ::tock_registers::FakeCell<'h, u8>;
+                fn deref(&self) -> &::tock_registers::FakeCell<'h, u8> { &self.0 }
+            }
+            impl ::tock_registers::Register for &Fake<'_> { type DataType = u8; }
+            Read!(fake_impl, Fake, u8,,);
+            impl<'a, 'h> Interface for &'a Fake<'h> {}
+        }
+    };
+    assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
+}
+
+/// Tests the Fake type generated for a nested array register reference.
+#[test]
+fn nested_array_reference() {
+    let input = quote! {
+        ::tock_registers
+        #[bus(Mmio32)]
+        #[fake]
+        pub foo: [[status; 2]; 3],
+    };
+    let interface_comment = interface_doc_comment();
+    let bus_comment = bus_doc_comment();
+    let real_alias_comment = real_alias_doc_comment();
+    let fake_comment = fake_doc_comment();
+    let expected = quote! {
+        pub mod foo {
+            use super::*;
+            #interface_comment pub trait Interface: ::tock_registers::RegisterArray<Len<1usize>,
+                Element: ::tock_registers::RegisterArray<Len<0usize>, Element: status::Interface>
+            > {}
+            pub enum Len<const N: usize> {}
+            impl ::tock_registers::array::Len for Len<0usize> { const LEN: usize = 2; }
+            impl ::tock_registers::array::Len for Len<1usize> { const LEN: usize = 3; }
+            #bus_comment pub trait Bus: status::Bus + sealed::Bus {}
+            impl Bus for Mmio32 {}
+            impl sealed::Bus for Mmio32 {}
+            impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            mod sealed { pub trait Bus {} }
+            #real_alias_comment pub type Real<B> = ::tock_registers::RealRegisterArray<
+                ::tock_registers::RealRegisterArray<status::Real<B>, Len<0usize> >, Len<1usize> >;
+            impl<B: Bus> Interface for Real<B> where status::Real<B>: status::Interface {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                status::is_dumped::<_>(::tock_registers::internal::dump::element_probe(
+                    ::tock_registers::internal::dump::element_probe(
+                        probe
+                    )
+                ))
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_array(register, f,
+                    |register, f| ::tock_registers::internal::fmt_array(register, f,
+                        status::fmt_dump::<_>))
+            }
+            // Referenced layouts must also be #[fake], as their Fake type is used as the element.
+            #fake_comment pub type Fake<'h> = ::tock_registers::FakeArray<
+                ::tock_registers::FakeArray<status::Fake<'h>, Len<0usize>, 2>, Len<1usize>, 3>;
+            impl<'a, 'h> Interface for &'a Fake<'h> {}
+        }
+    };
+    assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
+}
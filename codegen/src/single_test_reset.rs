@@ -0,0 +1,136 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::single::{
+    bus_doc_comment, fake_doc_comment, fake_element_doc_comment, interface_doc_comment,
+    real_alias_doc_comment, struct_doc_comment,
+};
+use crate::{
+    new_doc_comment, new_fake_doc_comment, register_map, reset_doc_comment,
+    test_util::assert_tokens_eq, Env::ProcMacro,
+};
+use quote::quote;
+
+/// Tests an array register definition with a reset value.
+#[test]
+fn array_definition() {
+    let input = quote! {
+        ::tock_registers
+        #[bus(Mmio32)]
+        #[fake]
+        #[reset(0x80)]
+        pub foo: [Ctrl::Register; 2] { Read, Write }
+    };
+    let interface_comment = interface_doc_comment();
+    let reset_comment = reset_doc_comment();
+    let bus_comment = bus_doc_comment();
+    let struct_comment = struct_doc_comment(false);
+    let new_comment = new_doc_comment();
+    let real_alias_comment = real_alias_doc_comment();
+    let fake_comment = fake_doc_comment();
+    let fake_element_comment = fake_element_doc_comment();
+    let new_fake_comment = new_fake_doc_comment();
+    let expected = quote! {
+        pub mod foo {
+            use super::*;
+            // The reset value is a constant in the module, which Interface and the real struct
+            // expose as associated constants.
+            #interface_comment pub trait Interface: ::tock_registers::RegisterArray<Len,
+                Element: ::tock_registers::Register<DataType = Ctrl::Register> + Read + Write>
+            {
+                #reset_comment const RESET:
+                    <Ctrl::Register as ::tock_registers::DataType>::Value = RESET;
+            }
+            #reset_comment pub const RESET: <Ctrl::Register as ::tock_registers::DataType>::Value =
+                0x80;
+            pub enum Len {}
+            impl ::tock_registers::array::Len for Len { const LEN: usize = 2; }
+            #bus_comment
+            pub trait Bus: ::tock_registers::DataTypeBus<Ctrl::Register> + sealed::Bus {}
+            impl Bus for Mmio32 {}
+            impl sealed::Bus for Mmio32 {}
+            impl<B: Bus> Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            impl<B: Bus> sealed::Bus for ::tock_registers::BorrowedBus<'_, B> {}
+            mod sealed { pub trait Bus {} }
+            #struct_comment #[derive(Clone)] pub struct Element<B: Bus = Mmio32> {
+                address: B,
+                _phantom: ::tock_registers::internal::RealPhantom,
+            }
+            impl<B: Bus> Element<B> {
+                #new_comment pub const unsafe fn new(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                #reset_comment pub const RESET:
+                    <Ctrl::Register as ::tock_registers::DataType>::Value = RESET;
+            }
+            impl<B: Bus> ::tock_registers::internal::core::marker::Copy for Element<B> {}
+            unsafe impl<B: Bus> ::tock_registers::Span for Element<B> {
+                type Address = B;
+                const SIZE: usize = <B as ::tock_registers::DataTypeBus<Ctrl::Register>>::PADDED_SIZE;
+                const ALIGNMENT: usize = <B as ::tock_registers::DataTypeBus<Ctrl::Register>>::ALIGNMENT;
+                unsafe fn with_addr(address: B) -> Self {
+                    Self { address, _phantom: ::tock_registers::internal::RealPhantom::new() }
+                }
+                type Borrowed<'b> = Element<::tock_registers::BorrowedBus<'b, B>>;
+            }
+            impl<B: Bus> ::tock_registers::Register for Element<B> {
+                type DataType = Ctrl::Register;
+            }
+            impl<B: Bus> ::tock_registers::layout::LayoutInfo for Element<B> {
+                const LAYOUT: ::tock_registers::layout::Layout =
+                    ::tock_registers::layout::Layout::Register {
+                        size: <Self as ::tock_registers::Span>::SIZE,
+                        operations: &["Read", "Write"],
+                    };
+            }
+            Read!(real_impl, Element, Ctrl::Register,,);
+            Write!(real_impl, Element, Ctrl::Register,,);
+            #real_alias_comment
+            pub type Real<B> = ::tock_registers::RealRegisterArray<Element<B>, Len>;
+            impl<B: Bus> Interface for Real<B> where
+                Element<B>: ::tock_registers::Register<DataType = Ctrl::Register> + Read + Write {}
+            #[doc(hidden)]
+            pub fn is_dumped<R: Interface>(probe: ::tock_registers::internal::dump::Probe<R>) -> bool {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                (&&&::tock_registers::internal::dump::element_probe(
+                    probe
+                )).readable()
+            }
+            #[doc(hidden)]
+            pub fn fmt_dump<R: Interface>(
+                register: R, f: &mut ::tock_registers::internal::core::fmt::Formatter<'_>
+            ) -> ::tock_registers::internal::core::fmt::Result {
+                #[allow(unused_imports)] use ::tock_registers::internal::dump::traits::*;
+                ::tock_registers::internal::fmt_array(register, f,
+                    |register, f| (&&&::tock_registers::internal::dump::Probe::of(&register))
+                        .fmt_register(register, f,
+                            (&&&::tock_registers::internal::dump::Probe::<Ctrl::Register>::new()).value_fmt()))
+            }
+            // Every element of the fake array starts with the reset value.
+            #fake_element_comment
+            pub struct FakeElement<'h>(::tock_registers::FakeCell<'h, Ctrl::Register>);
+            impl<'h> FakeElement<'h> {
+                #new_fake_comment pub fn new() -> Self {
+                    Self(::tock_registers::FakeCell::new(RESET))
+                }
+            }
+            impl<'h> ::tock_registers::internal::core::default::Default for FakeElement<'h> {
+                fn default() -> Self { Self::new() }
+            }
+            impl<'h> ::tock_registers::internal::core::ops::Deref for FakeElement<'h> {
+                type Target = ::tock_registers::FakeCell<'h, Ctrl::Register>;
+                fn deref(&self) -> &::tock_registers::FakeCell<'h, Ctrl::Register> { &self.0 }
+            }
+            impl ::tock_registers::Register for &FakeElement<'_> {
+                type DataType = Ctrl::Register;
+            }
+            Read!(fake_impl, FakeElement, Ctrl::Register,,);
+            Write!(fake_impl, FakeElement, Ctrl::Register,,);
+            #fake_comment pub type Fake<'h> =
+                ::tock_registers::FakeArray<FakeElement<'h>, Len, 2>;
+            impl<'a, 'h> Interface for &'a Fake<'h> {}
+        }
+    };
+    assert_tokens_eq(register_map(input, ProcMacro).unwrap(), expected);
+}
@@ -5,6 +5,7 @@
 
 use crate::{internal::RealPhantom, Address, Span};
 use core::marker::PhantomData;
+use core::ops::{Bound, RangeBounds};
 
 /// Interface for an array of registers (or register blocks, or register arrays). Each register
 /// type should only implement RegisterArray for a single Len.
@@ -31,6 +32,384 @@ pub trait RegisterArray<L: Len>: Copy {
             )
         })
     }
+
+    /// Returns the `I`-th element of this array, for an index known at compile time.
+    /// ```
+    /// # fn main() { get_const_test() }
+    /// use core::ptr::NonNull;
+    /// use tock_registers::{mmio32_register_map, Mmio32, RegisterArray, Write};
+    /// mmio32_register_map! {
+    ///     dma {
+    ///         0 => channels: [u32; 4] { Write },
+    ///     }
+    /// }
+    ///
+    /// fn get_const_test() {
+    ///     use dma::Interface;
+    ///     let mut memory = [0u32; 4];
+    ///     let mmio = Mmio32::new(NonNull::from(&mut memory).cast());
+    ///     // Safety: `memory` has the layout of the `dma` register block and is only accessed
+    ///     // through `registers`.
+    ///     let registers = unsafe { dma::Real::new(mmio) };
+    ///     registers.channels().get_const::<3>().set(4);
+    ///     assert_eq!(memory, [0, 0, 0, 4]);
+    /// }
+    /// ```
+    /// Using an out-of-bounds index is a compile error:
+    /// ```compile_fail
+    /// # fn main() { let _: fn(dma::Real) -> u32 = read_channel_4; }
+    /// # use tock_registers::{mmio32_register_map, Read, RegisterArray};
+    /// mmio32_register_map! {
+    ///     dma {
+    ///         0 => channels: [u32; 4] { Read },
+    ///     }
+    /// }
+    /// fn read_channel_4<D: dma::Interface>(registers: D) -> u32 {
+    ///     registers.channels().get_const::<4>().get()
+    /// }
+    /// ```
+    /// Note that this error is reported when the function is monomorphized, so `cargo check` may
+    /// not report it (`cargo build` will).
+    fn get_const<const I: usize>(self) -> Self::Element {
+        const { assert!(I < L::LEN, "get_const index out of bounds") };
+        // Safety: The above assertion guarantees that I < L::LEN.
+        unsafe { self.get_unchecked(I) }
+    }
+
+    /// Returns an iterator over the elements of this array, in index order.
+    ///
+    /// ```
+    /// # fn main() { iter_test() }
+    /// use core::ptr::NonNull;
+    /// use tock_registers::{mmio32_register_map, Mmio32, Read, RegisterArray, Write};
+    /// mmio32_register_map! {
+    ///     dma {
+    ///         0 => channels: [u32; 4] { Read, Write },
+    ///     }
+    /// }
+    ///
+    /// fn idle_channel<D: dma::Interface>(registers: D) -> Option<usize> {
+    ///     registers.channels().iter().position(|channel| channel.get() == 0)
+    /// }
+    ///
+    /// fn iter_test() {
+    ///     use dma::Interface;
+    ///     let mut memory = [5u32, 6, 0, 8];
+    ///     let mmio = Mmio32::new(NonNull::from(&mut memory).cast());
+    ///     // Safety: `memory` has the layout of the `dma` register block and is only accessed
+    ///     // through `registers`.
+    ///     let registers = unsafe { dma::Real::new(mmio) };
+    ///     assert_eq!(idle_channel(registers), Some(2));
+    ///     let mut channels = registers.channels().iter();
+    ///     assert_eq!(channels.next_back().map(|c| c.get()), Some(8));
+    ///     assert_eq!(channels.len(), 3);
+    ///     // RealRegisterArray also implements IntoIterator.
+    ///     for channel in registers.channels() {
+    ///         channel.set(channel.get() + 1);
+    ///     }
+    ///     assert_eq!(memory, [6, 7, 1, 9]);
+    /// }
+    /// ```
+    fn iter(self) -> ArrayIter<L, Self> {
+        ArrayIter {
+            array: self,
+            front: 0,
+            back: L::LEN,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a slice of this array covering the indices in `range`, or `None` if `range` is out
+    /// of bounds.
+    ///
+    /// The default implementation returns a [`DefaultSlice`], which stores this array along with
+    /// the start and length of the slice. RealRegisterArray overrides it to return a
+    /// [`RealSlice`], which only stores the address of the slice's first element and its length.
+    ///
+    /// Code that is generic over the array type cannot name the returned type. To store a slice
+    /// of any `RegisterArray` (e.g. in a struct field), construct a `DefaultSlice` directly:
+    /// ```
+    /// # fn main() {}
+    /// use tock_registers::array::DefaultSlice;
+    /// use tock_registers::{mmio32_register_map, Write};
+    /// mmio32_register_map! {
+    ///     gpio {
+    ///         0 => pins: [u32; 8] { Write },
+    ///     }
+    /// }
+    ///
+    /// /// A driver for a group of GPIO pins within a bank.
+    /// struct PinGroup<G: gpio::Interface> {
+    ///     pins: DefaultSlice<gpio::lens::pins, G::pins>,
+    /// }
+    ///
+    /// impl<G: gpio::Interface> PinGroup<G> {
+    ///     fn new(registers: G, first: usize, len: usize) -> Option<Self> {
+    ///         let pins = DefaultSlice::new(registers.pins(), first..first + len)?;
+    ///         Some(Self { pins })
+    ///     }
+    /// }
+    /// ```
+    fn slice<R: RangeBounds<usize>>(
+        self,
+        range: R,
+    ) -> Option<impl RegisterSlice<Element = Self::Element>> {
+        DefaultSlice::new(self, range)
+    }
+}
+
+/// Iterator over the elements of a [`RegisterArray`], returned by [`RegisterArray::iter`].
+pub struct ArrayIter<L: Len, A: RegisterArray<L>> {
+    array: A,
+    // Invariant: front <= back <= L::LEN
+    front: usize,
+    back: usize,
+    _phantom: PhantomData<L>,
+}
+
+impl<L: Len, A: RegisterArray<L>> Clone for ArrayIter<L, A> {
+    fn clone(&self) -> Self {
+        ArrayIter {
+            _phantom: PhantomData,
+            ..*self
+        }
+    }
+}
+
+impl<L: Len, A: RegisterArray<L>> Iterator for ArrayIter<L, A> {
+    type Item = A::Element;
+
+    fn next(&mut self) -> Option<A::Element> {
+        if self.front == self.back {
+            return None;
+        }
+        // Safety: front < back <= L::LEN
+        let element = unsafe { self.array.get_unchecked(self.front) };
+        self.front += 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<L: Len, A: RegisterArray<L>> DoubleEndedIterator for ArrayIter<L, A> {
+    fn next_back(&mut self) -> Option<A::Element> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        // Safety: front <= back < L::LEN
+        Some(unsafe { self.array.get_unchecked(self.back) })
+    }
+}
+
+impl<L: Len, A: RegisterArray<L>> ExactSizeIterator for ArrayIter<L, A> {}
+
+impl<L: Len, A: RegisterArray<L>> core::iter::FusedIterator for ArrayIter<L, A> {}
+
+/// A contiguous subrange of a [`RegisterArray`], returned by [`RegisterArray::slice`].
+///
+/// Unlike a `RegisterArray`, the length of a `RegisterSlice` is only known at runtime. As with
+/// `RegisterArray`, a default implementation of
+/// [`get_unchecked`](RegisterSlice::get_unchecked) is provided that panics if called with an
+/// out-of-bounds index.
+///
+/// ```
+/// # fn main() { slice_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::array::RealSlice;
+/// use tock_registers::{mmio32_register_map, Mmio32, Read, RegisterArray, RegisterSlice};
+/// mmio32_register_map! {
+///     gpio {
+///         0 => pins: [u32; 8] { Read },
+///     }
+/// }
+///
+/// fn slice_test() {
+///     use gpio::Interface;
+///     let mut memory: [u32; 8] = core::array::from_fn(|i| i as u32);
+///     // Safety: `memory` has the layout of the `gpio` register block and is only accessed
+///     // through `registers`.
+///     let registers = unsafe { gpio::Real::new(Mmio32::new(NonNull::from(&mut memory).cast())) };
+///     let middle: RealSlice<_> = registers.pins().slice(2..5).unwrap();
+///     assert_eq!(middle.len(), 3);
+///     assert_eq!(middle.get(2).unwrap().get(), 4);
+///     assert!(middle.get(3).is_none());
+///     assert_eq!(middle.iter().rev().map(|p| p.get()).collect::<Vec<_>>(), [4, 3, 2]);
+///     // Subslices are relative to the slice they are taken from.
+///     let sub = middle.slice(1..).unwrap();
+///     assert_eq!(sub.iter().map(|p| p.get()).collect::<Vec<_>>(), [3, 4]);
+///     assert!(middle.slice(..4).is_none());
+///     assert!(registers.pins().slice(8..).unwrap().is_empty());
+///     assert!(registers.pins().slice(9..).is_none());
+/// }
+/// ```
+pub trait RegisterSlice: Copy {
+    /// The type of each element of this slice.
+    type Element: Copy;
+
+    /// Returns the number of elements in this slice.
+    fn len(self) -> usize;
+
+    /// Returns `true` if this slice contains no elements.
+    fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the `index`-th element of this slice, or `None` if `index >= self.len()`.
+    fn get(self, index: usize) -> Option<Self::Element>;
+
+    /// Returns the `index`-th element of this slice.
+    /// # Safety
+    /// `index` must be less than `self.len()`
+    unsafe fn get_unchecked(self, index: usize) -> Self::Element {
+        self.get(index).unwrap_or_else(|| {
+            panic!(
+                "get_unchecked called with out-of-bounds index {index}; len = {}",
+                self.len()
+            )
+        })
+    }
+
+    /// Returns a subslice of this slice covering the indices in `range`, or `None` if `range` is
+    /// out of bounds.
+    fn slice<R: RangeBounds<usize>>(self, range: R) -> Option<Self>;
+
+    /// Returns an iterator over the elements of this slice.
+    fn iter(self) -> SliceIter<Self> {
+        SliceIter {
+            slice: self,
+            front: 0,
+            back: self.len(),
+        }
+    }
+}
+
+/// Iterator over the elements of a [`RegisterSlice`], returned by [`RegisterSlice::iter`].
+#[derive(Clone)]
+pub struct SliceIter<S: RegisterSlice> {
+    slice: S,
+    // Invariant: front <= back <= slice.len()
+    front: usize,
+    back: usize,
+}
+
+impl<S: RegisterSlice> Iterator for SliceIter<S> {
+    type Item = S::Element;
+
+    fn next(&mut self) -> Option<S::Element> {
+        if self.front == self.back {
+            return None;
+        }
+        // Safety: front < back <= slice.len()
+        let element = unsafe { self.slice.get_unchecked(self.front) };
+        self.front += 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<S: RegisterSlice> DoubleEndedIterator for SliceIter<S> {
+    fn next_back(&mut self) -> Option<S::Element> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        // Safety: front <= back < slice.len()
+        Some(unsafe { self.slice.get_unchecked(self.back) })
+    }
+}
+
+impl<S: RegisterSlice> ExactSizeIterator for SliceIter<S> {}
+
+impl<S: RegisterSlice> core::iter::FusedIterator for SliceIter<S> {}
+
+/// Converts `range` into a `(start, end)` pair of indices into a slice of length `len`, returning
+/// `None` if `range` is out of bounds.
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Option<(usize, usize)> {
+    let end = match range.end_bound() {
+        Bound::Included(&end) if end < len => end + 1,
+        Bound::Included(_) => return None,
+        Bound::Excluded(&end) if end <= len => end,
+        Bound::Excluded(_) => return None,
+        Bound::Unbounded => len,
+    };
+    let start = match range.start_bound() {
+        Bound::Included(&start) if start <= end => start,
+        Bound::Included(_) => return None,
+        Bound::Excluded(&start) if start < end => start + 1,
+        Bound::Excluded(_) => return None,
+        Bound::Unbounded => 0,
+    };
+    Some((start, end))
+}
+
+/// RegisterSlice implementation that works with any [`RegisterArray`], returned by the default
+/// implementation of [`RegisterArray::slice`].
+// Invariant: start + len <= L::LEN
+pub struct DefaultSlice<L: Len, A: RegisterArray<L>> {
+    array: A,
+    start: usize,
+    len: usize,
+    _phantom: PhantomData<L>,
+}
+
+impl<L: Len, A: RegisterArray<L>> Clone for DefaultSlice<L, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<L: Len, A: RegisterArray<L>> Copy for DefaultSlice<L, A> {}
+
+impl<L: Len, A: RegisterArray<L>> DefaultSlice<L, A> {
+    /// Returns a slice of `array` covering the indices in `range`, or `None` if `range` is out of
+    /// bounds.
+    pub fn new<R: RangeBounds<usize>>(array: A, range: R) -> Option<Self> {
+        let (start, end) = resolve_range(range, L::LEN)?;
+        Some(DefaultSlice {
+            array,
+            start,
+            len: end - start,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<L: Len, A: RegisterArray<L>> RegisterSlice for DefaultSlice<L, A> {
+    type Element = A::Element;
+
+    fn len(self) -> usize {
+        self.len
+    }
+
+    fn get(self, index: usize) -> Option<A::Element> {
+        if index >= self.len {
+            return None;
+        }
+        self.array.get(self.start + index)
+    }
+
+    unsafe fn get_unchecked(self, index: usize) -> A::Element {
+        // Safety: The caller guaranteed `index < self.len`, so by DefaultSlice's invariant
+        // `self.start + index < L::LEN`.
+        unsafe { self.array.get_unchecked(self.start + index) }
+    }
+
+    fn slice<R: RangeBounds<usize>>(self, range: R) -> Option<Self> {
+        let (start, end) = resolve_range(range, self.len)?;
+        Some(DefaultSlice {
+            start: self.start + start,
+            len: end - start,
+            ..self
+        })
+    }
 }
 
 /// Trait providing the length of a register array.
@@ -96,55 +475,117 @@ pub trait Len {
     const LEN: usize;
 }
 
-/// Real implementation of RegisterArray.
-// Safety invariant: `address` points to an array of `L::LEN` consecutive `Element` registers.
-pub struct RealRegisterArray<Element: Span, L: Len> {
+/// Trait providing the distance between consecutive elements of a [`RealRegisterArray`].
+///
+/// Most register arrays are densely packed, and use [`Dense`]. Arrays declared with a
+/// `#[stride(...)]` attribute in [`register_map`](crate::register_map) use a generated type whose
+/// stride depends on the bus.
+pub trait Stride {
+    /// The distance (in bytes) between the start of consecutive elements, or `None` if the
+    /// elements are densely packed (in which case the distance is the element's size).
+    const STRIDE: Option<usize>;
+}
+
+/// [`Stride`] for densely packed register arrays.
+pub enum Dense {}
+
+impl Stride for Dense {
+    const STRIDE: Option<usize> = None;
+}
+
+/// Returns the distance between consecutive elements of an array of `Element`s with stride `S`.
+/// # Panics
+/// Panics if the stride is smaller than the size of `Element`, or if it is not a multiple of the
+/// alignment of `Element`. This is only called in const contexts, so this is a compile-time error.
+pub(crate) const fn element_stride<Element: Span, S: Stride>() -> usize {
+    let stride = match S::STRIDE {
+        None => Element::SIZE,
+        Some(stride) => {
+            assert!(
+                stride >= Element::SIZE,
+                "register array stride is smaller than its element size"
+            );
+            stride
+        }
+    };
+    assert!(
+        stride % Element::ALIGNMENT == 0,
+        "register array elements are misaligned"
+    );
+    stride
+}
+
+/// Real implementation of RegisterArray. `S` specifies the distance between consecutive elements
+/// (by default, the elements are densely packed).
+// Safety invariant: `address` points to an array of `L::LEN` `Element` registers, spaced
+// `element_stride::<Element, S>()` bytes apart.
+pub struct RealRegisterArray<Element: Span, L: Len, S: Stride = Dense> {
     address: Element::Address,
-    _phantom: (RealPhantom, PhantomData<L>),
+    _phantom: (RealPhantom, PhantomData<(L, S)>),
 }
 
-impl<Element: Span, L: Len> RealRegisterArray<Element, L> {
+impl<Element: Span, L: Len, S: Stride> RealRegisterArray<Element, L, S> {
     /// Constructs an accessor for the register array at the given address.
     /// # Safety
     /// 1. `address` must point to a register array on the bus corresponding to `Self::Address`.
-    /// 2. The element type `Element` and the length `L` must correctly describe the pointed-to
-    ///    register array.
+    /// 2. The element type `Element`, the length `L` and the stride `S` must correctly describe
+    ///    the pointed-to register array.
     /// 3. The returned register array accessor must not be used in a way that causes data races.
     ///    The exact requirements depend on the hardware, but it's usually best to access registers
     ///    from only one thread at a time.
-    pub const unsafe fn new(address: Element::Address) -> RealRegisterArray<Element, L> {
+    pub const unsafe fn new(address: Element::Address) -> RealRegisterArray<Element, L, S> {
         RealRegisterArray {
             address,
             _phantom: (RealPhantom::new(), PhantomData),
         }
     }
+
+    /// The distance from the start of this array to the end of its last element. This is smaller
+    /// than `SIZE` if the stride is larger than the element size. Used by register_map!'s layout
+    /// checks.
+    #[doc(hidden)]
+    pub const END: usize = match L::LEN {
+        0 => 0,
+        len => (len - 1) * element_stride::<Element, S>() + Element::SIZE,
+    };
 }
 
 // Safety: Element::SIZE must be correct (Span's safety requirement), and there must be an array of
-// L `Element`s at `address` (RealRegisterArray's safety invariant). Therefore SIZE's calculation
-// is correct.
-unsafe impl<Element: Span, L: Len> Span for RealRegisterArray<Element, L> {
+// L `Element`s at `address` spaced by the stride (RealRegisterArray's safety invariant). Each
+// element occupies one stride, which is at least Element::SIZE, so SIZE's calculation is correct.
+unsafe impl<Element: Span, L: Len, S: Stride> Span for RealRegisterArray<Element, L, S> {
     type Address = Element::Address;
-    const SIZE: usize = Element::SIZE * L::LEN;
+    const SIZE: usize = element_stride::<Element, S>() * L::LEN;
+    const ALIGNMENT: usize = Element::ALIGNMENT;
 
-    unsafe fn with_addr(address: Element::Address) -> RealRegisterArray<Element, L> {
+    unsafe fn with_addr(address: Element::Address) -> RealRegisterArray<Element, L, S> {
         RealRegisterArray {
             address,
             _phantom: Default::default(),
         }
     }
 
-    type Borrowed<'b> = RealRegisterArray<Element::Borrowed<'b>, L>;
+    // BorrowedBus has the same stride as the bus it wraps, so S is unchanged.
+    type Borrowed<'b> = RealRegisterArray<Element::Borrowed<'b>, L, S>;
 }
 
-impl<Element: Span, L: Len> Clone for RealRegisterArray<Element, L> {
+impl<Element: Span, L: Len, S: Stride> Clone for RealRegisterArray<Element, L, S> {
     fn clone(&self) -> Self {
         *self
     }
 }
-impl<Element: Span, L: Len> Copy for RealRegisterArray<Element, L> {}
+impl<Element: Span, L: Len, S: Stride> Copy for RealRegisterArray<Element, L, S> {}
+
+impl<Element: Span, L: Len, S: Stride> IntoIterator for RealRegisterArray<Element, L, S> {
+    type Item = Element;
+    type IntoIter = ArrayIter<L, Self>;
+
+    fn into_iter(self) -> ArrayIter<L, Self> {
+        self.iter()
+    }
+}
 
-impl<Element: Span, L: Len> RegisterArray<L> for RealRegisterArray<Element, L> {
+impl<Element: Span, L: Len, S: Stride> RegisterArray<L> for RealRegisterArray<Element, L, S> {
     type Element = Element;
 
     fn get(self, index: usize) -> Option<Element> {
@@ -157,10 +598,10 @@ impl<Element: Span, L: Len> RegisterArray<L> for RealRegisterArray<Element, L> {
     }
 
     unsafe fn get_unchecked(self, index: usize) -> Element {
-        let offset = index * Element::SIZE;
+        let offset = index * const { element_stride::<Element, S>() };
         // Safety:
         // We know `address` points to an array of `L::LEN` `Element`s. The caller guaranteed that
-        // `index < L::LEN`, so index * Element::SIZE is within the array's bounds. That guarantees
+        // `index < L::LEN`, so index * stride is within the array's bounds. That guarantees
         // that this offset falls within the bounds of a register span (as the array itself is a
         // register span).
         let address = unsafe { self.address.byte_add(offset) };
@@ -168,4 +609,96 @@ impl<Element: Span, L: Len> RegisterArray<L> for RealRegisterArray<Element, L> {
         // element type is `Element`, so `address` points to an `Element`.
         unsafe { Element::with_addr(address) }
     }
+
+    fn get_const<const I: usize>(self) -> Element {
+        let offset = const {
+            assert!(I < L::LEN, "get_const index out of bounds");
+            I * element_stride::<Element, S>()
+        };
+        // Safety: The above assertion guarantees that I < L::LEN, so this offset is within the
+        // array (see get_unchecked).
+        let address = unsafe { self.address.byte_add(offset) };
+        // Safety: `address` points to the `I`-th `Element` of the array.
+        unsafe { Element::with_addr(address) }
+    }
+
+    // Returning the concrete type lets code that uses RealRegisterArray name the slice type.
+    #[allow(refining_impl_trait)]
+    fn slice<R: RangeBounds<usize>>(self, range: R) -> Option<RealSlice<Element, S>> {
+        // Safety: By RealRegisterArray's safety invariant, `self.address` points to an array of
+        // `L::LEN` `Element`s.
+        unsafe { RealSlice::<Element, S>::new(self.address, L::LEN, range) }
+    }
+}
+
+/// Real implementation of RegisterSlice, returned by [`RealRegisterArray`]'s
+/// [`slice`](RegisterArray::slice) method.
+// Safety invariant: `address` points to an array of `len` `Element` registers, spaced
+// `element_stride::<Element, S>()` bytes apart.
+pub struct RealSlice<Element: Span, S: Stride = Dense> {
+    address: Element::Address,
+    len: usize,
+    _phantom: (RealPhantom, PhantomData<S>),
+}
+
+impl<Element: Span, S: Stride> Clone for RealSlice<Element, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Element: Span, S: Stride> Copy for RealSlice<Element, S> {}
+
+impl<Element: Span, S: Stride> RealSlice<Element, S> {
+    /// Constructs a slice covering `range` of the array of `len` `Element`s at `address`, or
+    /// returns `None` if `range` is out of bounds.
+    /// # Safety
+    /// `address` must point to an array of `len` `Element` registers with stride `S`.
+    unsafe fn new<R: RangeBounds<usize>>(
+        address: Element::Address,
+        len: usize,
+        range: R,
+    ) -> Option<Self> {
+        let (start, end) = resolve_range(range, len)?;
+        // Safety: `start <= len`, so this offset is within (or one past the end of) the array,
+        // which is a register span.
+        let address = unsafe { address.byte_add(start * const { element_stride::<Element, S>() }) };
+        Some(RealSlice {
+            address,
+            len: end - start,
+            _phantom: (RealPhantom::new(), PhantomData),
+        })
+    }
+}
+
+impl<Element: Span, S: Stride> RegisterSlice for RealSlice<Element, S> {
+    type Element = Element;
+
+    fn len(self) -> usize {
+        self.len
+    }
+
+    fn get(self, index: usize) -> Option<Element> {
+        if index >= self.len {
+            return None;
+        }
+        // Safety: We returned early if `index >= self.len`.
+        Some(unsafe { self.get_unchecked(index) })
+    }
+
+    unsafe fn get_unchecked(self, index: usize) -> Element {
+        // Safety: The caller guaranteed that `index < self.len`, so this offset is within the
+        // array (RealSlice's safety invariant).
+        let address = unsafe {
+            self.address
+                .byte_add(index * const { element_stride::<Element, S>() })
+        };
+        // Safety: `address` points to the `index`-th `Element` of the array.
+        unsafe { Element::with_addr(address) }
+    }
+
+    fn slice<R: RangeBounds<usize>>(self, range: R) -> Option<Self> {
+        // Safety: By RealSlice's safety invariant, `self.address` points to an array of
+        // `self.len` `Element`s.
+        unsafe { RealSlice::new(self.address, self.len, range) }
+    }
 }
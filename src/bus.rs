@@ -8,8 +8,9 @@
 //! tock-registers supports many different types of registers, including:
 //! 1. MMIO registers
 //! 2. Several types of LiteX registers (these are MMIO, but have padding within the registers that
-//!    depends on the chip's configuration and the register's data type)
-//! 3. x86 port IO
+//!    depends on the chip's configuration and the register's data type, e.g.
+//!    [`LiteXC8B32`](crate::LiteXC8B32))
+//! 3. x86 port IO ([`PortIo`](crate::PortIo))
 //!
 //! tock-registers calls each of these classes a "bus", as the mechanism used to access the
 //! registers is different. The mechanism in which a register is addressed depends on the bus, so
@@ -22,6 +23,16 @@
 //! have a generic argument for their address type. That address type implements the `Address`
 //! trait. In addition, address types should implement [`Bus<T>`] for every value type `T` that
 //! they support.
+//!
+//! Buses can also wrap other buses to change how values are transferred. For example,
+//! [`BigEndian`](crate::BigEndian) byte-swaps values for big-endian registers, and
+//! [`Mmio32Be`](crate::Mmio32Be) is an alias for `BigEndian<Mmio32>`. [`Traced`](crate::Traced)
+//! reports every register access to a [`TraceSink`](crate::TraceSink), which is useful when
+//! bringing up new hardware.
+//!
+//! With the `alloc` feature, `RecordingBus` and `ReplayBus` back registers with in-memory storage
+//! for golden-trace tests: `RecordingBus` records the accesses a driver makes, and `ReplayBus`
+//! checks that a driver makes exactly a previously recorded sequence of accesses.
 
 use crate::DataType;
 use core::marker::PhantomData;
@@ -48,6 +59,11 @@ pub unsafe trait Bus<T>: Address {
     // This exists because some peripherals, such as LiteX peripherals and the 16550 UART, have
     // different register spacing on different systems.
     const PADDED_SIZE: usize;
+
+    /// The alignment that the offset of a register of type T must have in this bus' address
+    /// space. register_map! checks that each register's offset within its register block is a
+    /// multiple of this value.
+    const ALIGNMENT: usize = 1;
 }
 
 /// Used to construct the register accessors, including fields of register blocks and elements of
@@ -60,6 +76,10 @@ pub unsafe trait Span: Copy {
     /// Size this register span occupies in the address space. Depends on the address type.
     const SIZE: usize;
 
+    /// The alignment that this register span's offset must have in the address space, which is
+    /// the largest alignment of the registers it contains.
+    const ALIGNMENT: usize = 1;
+
     /// Constructs an accessor for a register span.
     /// # Safety
     /// 1. `address` must point to register(s) on the bus corresponding to `Self::Address`.
@@ -89,6 +109,9 @@ pub unsafe trait DataTypeBus<D: DataType>: Bus<D::Value> {
     /// Alias for Bus::PADDED_SIZE.
     // Safety: Bus' safety condition is that Bus::PADDED_SIZE is correct.
     const PADDED_SIZE: usize = <Self as Bus<D::Value>>::PADDED_SIZE;
+
+    /// Alias for Bus::ALIGNMENT.
+    const ALIGNMENT: usize = <Self as Bus<D::Value>>::ALIGNMENT;
 }
 // Safety: The provided value for PADDED_SIZE is correct.
 unsafe impl<D: DataType, T: Bus<D::Value>> DataTypeBus<D> for T {}
@@ -133,6 +156,7 @@ impl<'b, A: Address> Address for BorrowedBus<'b, A> {
 /// Safety: We are the same bus as A, so the padded size of each register type is the same.
 unsafe impl<'b, T, A: Address + Bus<T>> Bus<T> for BorrowedBus<'b, A> {
     const PADDED_SIZE: usize = A::PADDED_SIZE;
+    const ALIGNMENT: usize = A::ALIGNMENT;
 }
 
 /// A utility for sharing a register span between threads.
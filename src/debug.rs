@@ -8,13 +8,17 @@
 //! register types implementing the [`RegisterDebugInfo`] trait. This
 //! information can then be used by the [`RegisterDebugValue`] type to produce a
 //! human-readable representation of a register's fields and values.
+//!
+//! Register blocks generated by [`register_map!`](crate::register_map) also
+//! have a `debug_dump` function, which returns a [`DebugDump`] that prints
+//! every readable register in the block.
 
 use core::fmt;
 use core::marker::PhantomData;
 
 use crate::{
     fields::{Field, TryFromValue},
-    RegisterLongName, UIntLike,
+    DataType, RegisterLongName, UIntLike,
 };
 
 /// `FieldValueEnumSeq` is a debug helper trait representing a sequence of
@@ -203,3 +207,60 @@ where
         debug_struct.finish()
     }
 }
+
+/// Formats register values of a [`DataType`] for debugging.
+///
+/// Implemented for [`UIntLike`] types and raw pointers (which are formatted
+/// using their [`fmt::Debug`] impls), and for the bitfield register types
+/// generated by [`crate::register_bitfields`] and
+/// [`register_map!`](crate::register_map)'s inline bitfields (which are
+/// formatted by [`RegisterDebugValue`]).
+pub trait DebugDataType: DataType {
+    /// Formats `value`, which was read from a register of this data type.
+    fn fmt_value(value: Self::Value, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl<U: UIntLike> DebugDataType for U {
+    fn fmt_value(value: U, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&value, f)
+    }
+}
+
+impl<T: Sized> DebugDataType for *const T {
+    fn fmt_value(value: *const T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&value, f)
+    }
+}
+
+impl<T: Sized> DebugDataType for *mut T {
+    fn fmt_value(value: *mut T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&value, f)
+    }
+}
+
+/// `DebugDump` prints the registers of a register block.
+///
+/// It is returned by the `debug_dump` function of register blocks generated by
+/// [`register_map!`](crate::register_map). Its [`fmt::Debug`] implementation
+/// reads every readable register in the block (recursing into register arrays
+/// and nested register blocks) and prints their values.
+///
+/// Unlike [`RegisterDebugValue`], `DebugDump` does not contain a copy of the
+/// registers' values: the registers are read each time it is formatted.
+pub struct DebugDump<T: Copy> {
+    registers: T,
+    fmt: fn(T, &mut fmt::Formatter<'_>) -> fmt::Result,
+}
+
+impl<T: Copy> DebugDump<T> {
+    /// Returns a `DebugDump` that formats `registers` using `fmt`.
+    pub fn new(registers: T, fmt: fn(T, &mut fmt::Formatter<'_>) -> fmt::Result) -> Self {
+        Self { registers, fmt }
+    }
+}
+
+impl<T: Copy> fmt::Debug for DebugDump<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.fmt)(self.registers, f)
+    }
+}
@@ -0,0 +1,93 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::{Address, Bus, BusRead, BusWrite, Mmio32, Mmio64};
+
+/// A bus wrapper for big-endian registers.
+///
+/// `BigEndian<A>` accesses registers through the bus `A`, converting values between big-endian
+/// and the CPU's native endianness. This happens inside [`BusRead::read`] and
+/// [`BusWrite::write`], so fields, `FieldValue`s, and `debug()` all operate on the logical value
+/// of the register.
+///
+/// ```
+/// # fn main() { nic_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::{register_map, Mmio32, Mmio32Be, Read, Write};
+/// register_map! {
+///     #[bus(Mmio32Be)]
+///     nic {
+///         0 => control: u32 { Read, Write },
+///         4 => id: u16 { Read },
+///         6 => _: 2,
+///     }
+/// }
+///
+/// fn nic_test() {
+///     use nic::Interface;
+///     let mut memory = [0u32, u32::from_ne_bytes([0x12, 0x34, 0, 0])];
+///     let bus = Mmio32Be::new(Mmio32::new(NonNull::from(&mut memory).cast()));
+///     // Safety: `memory` has the layout of the `nic` register block and is only accessed through
+///     // `registers`.
+///     let registers = unsafe { nic::Real::new(bus) };
+///     assert_eq!(registers.id().get(), 0x1234);
+///     registers.control().set(0x0300_0001);
+///     assert_eq!(memory[0].to_ne_bytes(), [0x03, 0, 0, 0x01]);
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct BigEndian<A: Address>(A);
+
+/// MMIO register bus for big-endian registers on 32-bit systems.
+pub type Mmio32Be = BigEndian<Mmio32>;
+
+/// MMIO register bus for big-endian registers on 64-bit systems.
+pub type Mmio64Be = BigEndian<Mmio64>;
+
+impl<A: Address> BigEndian<A> {
+    /// Returns a new big-endian bus that accesses registers through `inner`.
+    pub const fn new(inner: A) -> Self {
+        Self(inner)
+    }
+
+    /// Returns the wrapped bus.
+    pub fn inner(self) -> A {
+        self.0
+    }
+}
+
+impl<A: Address> Address for BigEndian<A> {
+    unsafe fn byte_add(self, offset: usize) -> Self {
+        // Safety: BigEndian<A> addresses the same registers as A, so the caller has satisfied
+        // A::byte_add's requirements.
+        Self(unsafe { self.0.byte_add(offset) })
+    }
+}
+
+/// Macro to implement the Bus traits for BigEndian.
+macro_rules! big_endian_impls {
+    [$($value:ty)*] => {$(
+        /// Safety: Byte swapping does not change the register's size.
+        unsafe impl<A: Bus<$value>> Bus<$value> for BigEndian<A> {
+            const PADDED_SIZE: usize = A::PADDED_SIZE;
+            const ALIGNMENT: usize = A::ALIGNMENT;
+        }
+        impl<A: BusRead<$value>> BusRead<$value> for BigEndian<A> {
+            unsafe fn read(self) -> $value {
+                // Safety: BigEndian<A> addresses the same registers as A, so the caller has
+                // satisfied A::read's requirements.
+                <$value>::from_be(unsafe { self.0.read() })
+            }
+        }
+        impl<A: BusWrite<$value>> BusWrite<$value> for BigEndian<A> {
+            unsafe fn write(self, value: $value) {
+                // Safety: BigEndian<A> addresses the same registers as A, so the caller has
+                // satisfied A::write's requirements.
+                unsafe { self.0.write(value.to_be()) }
+            }
+        }
+    )*}
+}
+
+big_endian_impls![u8 u16 u32 u64 u128 usize];
@@ -0,0 +1,195 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+//! Storage types used by the `Fake` structs that [`register_map!`](crate::register_map)
+//! generates for layouts marked `#[fake]`.
+
+use crate::array::Len;
+use crate::{DataType, RegisterArray, UIntLike};
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+/// In-memory storage for a single fake register.
+///
+/// The fake register types generated by [`register_map!`](crate::register_map) store their value
+/// in a `FakeCell`, and deref to it. They implement only the operations their register
+/// definition lists, which read and write the stored value. Hooks can be attached with
+/// [`on_read`](FakeCell::on_read) and [`on_write`](FakeCell::on_write) to give the register side
+/// effects. The hooks are references to closures (with lifetime `'h`) so that they can refer to
+/// other parts of the fake peripheral.
+///
+/// ```
+/// # fn main() { hook_test() }
+/// use core::cell::Cell;
+/// use tock_registers::{mmio32_register_map, Read, Write};
+/// mmio32_register_map! {
+///     #[fake]
+///     #[reset(3)]
+///     data: u8 { Read, Write },
+/// }
+///
+/// fn hook_test() {
+///     let reads = Cell::new(0);
+///     let count_reads = || {
+///         reads.set(reads.get() + 1);
+///         7
+///     };
+///     let register = data::Fake::new();
+///     assert_eq!(register.value(), 3);
+///     (&register).set(4);
+///     assert_eq!(register.value(), 4);
+///     register.on_read(&count_reads);
+///     assert_eq!((&register).get(), 7);
+///     assert_eq!(reads.get(), 1);
+/// }
+/// ```
+pub struct FakeCell<'h, D: DataType> {
+    value: Cell<D::Value>,
+    read_hook: Cell<Option<&'h dyn Fn() -> D::Value>>,
+    write_hook: Cell<Option<&'h dyn Fn(D::Value)>>,
+}
+
+impl<'h, D: DataType> FakeCell<'h, D> {
+    /// Constructs a new `FakeCell` containing `value`, with no hooks.
+    pub const fn new(value: D::Value) -> Self {
+        Self {
+            value: Cell::new(value),
+            read_hook: Cell::new(None),
+            write_hook: Cell::new(None),
+        }
+    }
+
+    /// Returns the stored value, without calling the read hook.
+    pub fn value(&self) -> D::Value {
+        self.value.get()
+    }
+
+    /// Replaces the stored value, without calling the write hook.
+    pub fn set_value(&self, value: D::Value) {
+        self.value.set(value)
+    }
+
+    /// Sets a hook that is called instead of reading the stored value. The hook's return value is
+    /// the result of the read. The hook can use [`value`](Self::value) to retrieve the stored
+    /// value.
+    pub fn on_read(&self, hook: &'h dyn Fn() -> D::Value) {
+        self.read_hook.set(Some(hook))
+    }
+
+    /// Sets a hook that is called instead of updating the stored value when the register is
+    /// written. The hook is passed the written value, and can use
+    /// [`set_value`](Self::set_value) to update the stored value.
+    pub fn on_write(&self, hook: &'h dyn Fn(D::Value)) {
+        self.write_hook.set(Some(hook))
+    }
+
+    /// Removes the read and write hooks.
+    pub fn clear_hooks(&self) {
+        self.read_hook.set(None);
+        self.write_hook.set(None);
+    }
+
+    /// Performs a read, calling the read hook if one is set.
+    pub(crate) fn fake_read(&self) -> D::Value {
+        match self.read_hook.get() {
+            Some(hook) => hook(),
+            None => self.value.get(),
+        }
+    }
+
+    /// Performs a write, calling the write hook if one is set.
+    pub(crate) fn fake_write(&self, value: D::Value) {
+        match self.write_hook.get() {
+            Some(hook) => hook(value),
+            None => self.value.set(value),
+        }
+    }
+}
+
+// When no write hook is set, the write-1-to-clear and write-1-to-set operations update the stored
+// value the way the hardware would. Write hooks are passed the raw written mask.
+impl<D: DataType> FakeCell<'_, D>
+where
+    D::Value: UIntLike,
+{
+    /// Performs a write-1-to-clear write of `mask`.
+    pub(crate) fn fake_clear(&self, mask: D::Value) {
+        match self.write_hook.get() {
+            Some(hook) => hook(mask),
+            None => self.value.set(self.value.get() & !mask),
+        }
+    }
+
+    /// Performs a write-1-to-set write of `mask`.
+    pub(crate) fn fake_set_bits(&self, mask: D::Value) {
+        match self.write_hook.get() {
+            Some(hook) => hook(mask),
+            None => self.value.set(self.value.get() | mask),
+        }
+    }
+}
+
+impl<D: DataType> Default for FakeCell<'_, D>
+where
+    D::Value: Default,
+{
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+/// In-memory storage for a fake register array. `&FakeArray<T, L, N>` implements
+/// [`RegisterArray<L>`](RegisterArray), with `&T` as its element type.
+///
+/// `N` must equal `L::LEN`; this is checked when the array is constructed. `FakeArray` derefs to
+/// `[T; N]`, so individual elements can be accessed by indexing.
+pub struct FakeArray<T, L: Len, const N: usize> {
+    elements: [T; N],
+    _phantom: PhantomData<L>,
+}
+
+impl<T, L: Len, const N: usize> FakeArray<T, L, N> {
+    /// Constructs a new `FakeArray` containing the given elements.
+    /// # Panics
+    /// Panics if `N != L::LEN`.
+    pub const fn from_elements(elements: [T; N]) -> Self {
+        assert!(N == L::LEN, "FakeArray length does not match Len");
+        Self {
+            elements,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Default, L: Len, const N: usize> FakeArray<T, L, N> {
+    /// Constructs a new `FakeArray` whose elements are `T::default()`. For the fake types
+    /// generated by [`register_map!`](crate::register_map), that is the element's reset value.
+    /// # Panics
+    /// Panics if `N != L::LEN`.
+    pub fn new() -> Self {
+        Self::from_elements(core::array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T: Default, L: Len, const N: usize> Default for FakeArray<T, L, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, L: Len, const N: usize> Deref for FakeArray<T, L, N> {
+    type Target = [T; N];
+    fn deref(&self) -> &[T; N] {
+        &self.elements
+    }
+}
+
+impl<'a, T, L: Len, const N: usize> RegisterArray<L> for &'a FakeArray<T, L, N> {
+    type Element = &'a T;
+
+    fn get(self, index: usize) -> Option<&'a T> {
+        self.elements.get(index)
+    }
+}
@@ -0,0 +1,248 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+//! A configurable fake register, for unit testing code that uses the `Interface` traits generated
+//! by [`register_map!`](crate::register_map). See `doc/UnitTesting.md` in the tock-registers
+//! repository for an example.
+
+use crate::{
+    DataType, LocalRegisterCopy, Read, ReadDestructive, Register, Write, Write1Clear, Write1Set,
+};
+#[cfg(feature = "register_types")]
+use crate::{UnsafeRead, UnsafeWrite};
+use core::marker::PhantomData;
+
+/// Marker type for a [`FakeRegister`] that implements [`Read`](trait@crate::Read) or
+/// [`Write`](trait@crate::Write).
+pub enum Safe {}
+
+/// Marker type for a [`FakeRegister`] that implements `UnsafeRead` or `UnsafeWrite`.
+pub enum Unsafe {}
+
+/// Marker type for a [`FakeRegister`] that implements
+/// [`ReadDestructive`](trait@crate::ReadDestructive) (used as its read operation).
+pub enum Destructive {}
+
+/// Marker type for a [`FakeRegister`] that implements [`Write1Clear`](trait@crate::Write1Clear)
+/// (used as its write operation).
+pub enum ClearBits {}
+
+/// Marker type for a [`FakeRegister`] that implements [`Write1Set`](trait@crate::Write1Set) (used
+/// as its write operation).
+pub enum SetBits {}
+
+/// Marker type for a [`FakeRegister`] operation that the register does not support (e.g. a
+/// read-only register would use `NoAccess` for its write safety).
+pub enum NoAccess {}
+
+/// A fake register, whose behavior is provided by user-specified functions.
+///
+/// `FakeRegister` is intended to be returned by a fake implementation of a generated `Interface`
+/// trait. Its generic arguments are:
+///
+/// 1. `C`: A context value that is passed to the read and write handlers. This is usually a
+///    reference to the fake peripheral that contains the register's state.
+/// 2. `D`: The register's [`DataType`].
+/// 3. `R`: The register's read operation: [`Safe`] (`Read`), [`Unsafe`] (`UnsafeRead`),
+///    [`Destructive`] (`ReadDestructive`), or [`NoAccess`].
+/// 4. `W`: The register's write operation: [`Safe`] (`Write`), [`Unsafe`] (`UnsafeWrite`),
+///    [`ClearBits`] (`Write1Clear`), [`SetBits`] (`Write1Set`), or [`NoAccess`].
+///
+/// `FakeRegister` implements only the operations selected by `R` and `W`, so it cannot stand in
+/// for a register with more than one read operation or more than one write operation (such as a
+/// register with both `Write` and `Write1Set`). Those registers need a custom fake type.
+/// `Write1Clear` and `Write1Set` pass the raw written mask to the write handler.
+///
+/// The handlers are function pointers rather than arbitrary closures so that `FakeRegister` can
+/// be named in an `Interface` impl and so that it is [`Copy`]. Any state the handlers need should
+/// be stored in the context value. Closures that do not capture anything can be passed to
+/// [`on_read`](FakeRegister::on_read) and [`on_write`](FakeRegister::on_write).
+///
+/// ```
+/// use core::cell::Cell;
+/// use tock_registers::{ClearBits, FakeRegister, NoAccess, Read, Safe, Write, Write1Clear};
+///
+/// let value = Cell::new(3u8);
+/// let register = FakeRegister::<_, u8, Safe, Safe>::new(&value)
+///     .on_read(|value| value.get())
+///     .on_write(|value, new| value.set(new));
+/// assert_eq!(register.get(), 3);
+/// register.set(4);
+/// assert_eq!(value.get(), 4);
+///
+/// // A write-only register.
+/// let register = FakeRegister::<_, u8, NoAccess, Safe>::new(&value)
+///     .on_write(|value, new| value.set(new + 1));
+/// register.set(7);
+/// assert_eq!(value.get(), 8);
+///
+/// // A write-1-to-clear register, which does not implement Write.
+/// let register = FakeRegister::<_, u8, NoAccess, ClearBits>::new(&value)
+///     .on_write(|value, mask| value.set(value.get() & !mask));
+/// register.clear_raw(0b1000);
+/// assert_eq!(value.get(), 0);
+/// ```
+///
+/// A fake peripheral implements the generated `Interface` trait on a reference to the struct that
+/// holds its state, returning a `FakeRegister` for each field:
+/// ```
+/// # fn main() { rng_test() }
+/// use core::cell::Cell;
+/// use tock_registers::{mmio32_register_map, FakeRegister, NoAccess, Read, Safe};
+///
+/// mmio32_register_map! {
+///     rng {
+///         0 => random_byte: u8 { Read },
+///     }
+/// }
+///
+/// #[derive(Default)]
+/// struct FakeRng {
+///     state: Cell<u8>,
+/// }
+/// impl rng::Interface for &FakeRng {
+///     type random_byte = FakeRegister<Self, u8, Safe, NoAccess>;
+///     fn random_byte(self) -> FakeRegister<Self, u8, Safe, NoAccess> {
+///         FakeRegister::new(self).on_read(|this| {
+///             this.state.set(this.state.get() + 1);
+///             this.state.get()
+///         })
+///     }
+/// }
+///
+/// fn rng_test() {
+///     use rng::Interface;
+///     let rng = FakeRng::default();
+///     assert_eq!(rng.random_byte().get(), 1);
+///     assert_eq!(rng.random_byte().get(), 2);
+/// }
+/// ```
+///
+/// Accessing a register without the corresponding handler panics:
+/// ```should_panic
+/// use tock_registers::{FakeRegister, NoAccess, Read, Safe};
+/// FakeRegister::<_, u8, Safe, NoAccess>::new(()).get();
+/// ```
+pub struct FakeRegister<C: Copy, D: DataType, R, W> {
+    context: C,
+    read: Option<fn(C) -> D::Value>,
+    write: Option<fn(C, D::Value)>,
+    _phantom: PhantomData<(R, W)>,
+}
+
+impl<C: Copy, D: DataType, R, W> FakeRegister<C, D, R, W> {
+    /// Constructs a new `FakeRegister` with the given context and no handlers. Handlers should be
+    /// attached using [`on_read`](Self::on_read) and [`on_write`](Self::on_write).
+    pub const fn new(context: C) -> Self {
+        Self {
+            context,
+            read: None,
+            write: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets the function that is called when the register is read. The function is passed the
+    /// context value and returns the register's value.
+    pub const fn on_read(mut self, read: fn(C) -> D::Value) -> Self {
+        self.read = Some(read);
+        self
+    }
+
+    /// Sets the function that is called when the register is written. The function is passed the
+    /// context value and the value being written.
+    pub const fn on_write(mut self, write: fn(C, D::Value)) -> Self {
+        self.write = Some(write);
+        self
+    }
+
+    /// Returns the context value this `FakeRegister` was constructed with.
+    pub fn context(self) -> C {
+        self.context
+    }
+
+    /// Calls the read handler.
+    /// # Panics
+    /// Panics if no read handler was set.
+    #[track_caller]
+    fn call_read(self) -> D::Value {
+        let Some(read) = self.read else {
+            panic!("FakeRegister read without an on_read handler");
+        };
+        read(self.context)
+    }
+
+    /// Calls the write handler.
+    /// # Panics
+    /// Panics if no write handler was set.
+    #[track_caller]
+    fn call_write(self, value: D::Value) {
+        let Some(write) = self.write else {
+            panic!("FakeRegister written without an on_write handler");
+        };
+        write(self.context, value)
+    }
+}
+
+impl<C: Copy, D: DataType, R, W> Clone for FakeRegister<C, D, R, W> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<C: Copy, D: DataType, R, W> Copy for FakeRegister<C, D, R, W> {}
+
+impl<C: Copy, D: DataType, R, W> Register for FakeRegister<C, D, R, W> {
+    type DataType = D;
+}
+
+impl<C: Copy, D: DataType, W> Read for FakeRegister<C, D, Safe, W> {
+    #[track_caller]
+    fn get(self) -> D::Value {
+        self.call_read()
+    }
+}
+
+impl<C: Copy, D: DataType, W> ReadDestructive for FakeRegister<C, D, Destructive, W> {
+    #[track_caller]
+    fn take(self) -> LocalRegisterCopy<D::Value, D::LongName> {
+        LocalRegisterCopy::new(self.call_read())
+    }
+}
+
+impl<C: Copy, D: DataType, R> Write for FakeRegister<C, D, R, Safe> {
+    #[track_caller]
+    fn set(self, value: D::Value) {
+        self.call_write(value)
+    }
+}
+
+impl<C: Copy, D: DataType, R> Write1Clear for FakeRegister<C, D, R, ClearBits> {
+    #[track_caller]
+    fn clear_raw(self, mask: D::Value) {
+        self.call_write(mask)
+    }
+}
+
+impl<C: Copy, D: DataType, R> Write1Set for FakeRegister<C, D, R, SetBits> {
+    #[track_caller]
+    fn set_bits_raw(self, mask: D::Value) {
+        self.call_write(mask)
+    }
+}
+
+#[cfg(feature = "register_types")]
+impl<C: Copy, D: DataType, W> UnsafeRead for FakeRegister<C, D, Unsafe, W> {
+    #[track_caller]
+    unsafe fn get(self) -> D::Value {
+        self.call_read()
+    }
+}
+
+#[cfg(feature = "register_types")]
+impl<C: Copy, D: DataType, R> UnsafeWrite for FakeRegister<C, D, R, Unsafe> {
+    #[track_caller]
+    unsafe fn set(self, value: D::Value) {
+        self.call_write(value)
+    }
+}
@@ -603,6 +603,15 @@ macro_rules! register_bitfields {
                     type Value = $valtype;
                     type LongName = Self;
                 }
+                impl $crate::debug::DebugDataType for Register {
+                    fn fmt_value(
+                        value: $valtype,
+                        f: &mut $crate::internal::core::fmt::Formatter<'_>,
+                    ) -> $crate::internal::core::fmt::Result {
+                        let value = $crate::LocalRegisterCopy::<$valtype, Self>::new(value);
+                        $crate::internal::core::fmt::Debug::fmt(&value.debug(), f)
+                    }
+                }
 
                 use $crate::fields::Field;
 
@@ -9,6 +9,7 @@
 
 #![doc(hidden)]
 
+use core::fmt;
 use core::marker::PhantomData;
 
 /// It's possible for a crate that is not libcore to be named `core` in a calling crate. Re-export
@@ -26,3 +27,213 @@ impl RealPhantom {
         Self(PhantomData)
     }
 }
+
+/// Returns the largest of the given alignments (or 1 if there are none). Used to compute the
+/// alignment of a register block.
+pub const fn max_alignment(alignments: &[usize]) -> usize {
+    let mut max = 1;
+    let mut i = 0;
+    while i < alignments.len() {
+        if alignments[i] > max {
+            max = alignments[i];
+        }
+        i += 1;
+    }
+    max
+}
+
+/// Returns true if the padding range `[padding_start, padding_end)` overlaps the register range
+/// `[start, end)`. Used to check that aliased registers do not overlap padding.
+pub const fn overlaps_padding(
+    padding_start: usize,
+    padding_end: usize,
+    start: usize,
+    end: usize,
+) -> bool {
+    padding_start < padding_end && start < padding_end && padding_start < end
+}
+
+/// Returns a value whose Debug impl calls `fmt`. Used by register_map!'s `fmt_dump` functions to
+/// format each field of a register block.
+pub fn debug_fn<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result>(fmt: F) -> impl fmt::Debug {
+    struct DebugFn<F>(F);
+    impl<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result> fmt::Debug for DebugFn<F> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            (self.0)(f)
+        }
+    }
+    DebugFn(fmt)
+}
+
+/// Formats `register` using `element`. Used by register_map!'s `fmt_dump` functions, so that the
+/// type of `element`'s parameter is known when `element` is type checked.
+pub fn fmt_with<R>(
+    register: R,
+    f: &mut fmt::Formatter<'_>,
+    element: impl Fn(R, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    element(register, f)
+}
+
+/// Formats each element of `array` using `element`, as a list. Used by register_map!'s `fmt_dump`
+/// functions.
+#[cfg(feature = "register_types")]
+pub fn fmt_array<L: crate::array::Len, A: crate::RegisterArray<L>>(
+    array: A,
+    f: &mut fmt::Formatter<'_>,
+    element: impl Fn(A::Element, &mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let element = &element;
+    f.debug_list()
+        .entries(array.iter().map(|e| debug_fn(move |f| element(e, f))))
+        .finish()
+}
+
+/// Autoref-specialized traits used by register_map!'s debug dumps.
+///
+/// These traits select how to dump a register based on the traits its type implements. The
+/// generated code calls their methods on `&&&Probe<T>`: method resolution tries the impls for
+/// `&&Probe<T>` first, then `&Probe<T>`, then `Probe<T>`. This works for the generic register
+/// types of an `Interface`, as their operations are bounds of the `Interface` trait, so it does not
+/// depend on how the operations are named.
+pub mod dump {
+    use crate::debug::DebugDataType;
+    use crate::{DataType, Read, ReadDestructive, Register};
+    use core::fmt::{self, Result};
+    use core::marker::PhantomData;
+
+    /// Zero-sized stand-in for a value of type `T`, whose methods are provided by the traits in
+    /// this module.
+    pub struct Probe<T>(PhantomData<T>);
+
+    impl<T> Probe<T> {
+        /// Returns the probe of type `T`.
+        pub fn new() -> Self {
+            Self(PhantomData)
+        }
+
+        /// Returns the probe of `value`'s type.
+        pub fn of(_value: &T) -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    /// Returns the probe of the element type of the register array `A`.
+    #[cfg(feature = "register_types")]
+    pub fn element_probe<L: crate::array::Len, A: crate::RegisterArray<L>>(
+        _array: Probe<A>,
+    ) -> Probe<A::Element> {
+        Probe::new()
+    }
+
+    /// The traits in this module, imported anonymously so that importing them does not shadow any
+    /// names.
+    pub mod traits {
+        pub use super::{
+            DebugValue as _, DestructiveDump as _, OpaqueValue as _, RawValue as _, ReadDump as _,
+            SkippedDump as _,
+        };
+    }
+
+    /// Formats a register's value.
+    pub type ValueFmt<D> = fn(<D as DataType>::Value, &mut fmt::Formatter<'_>) -> Result;
+
+    /// Registers with the ReadDestructive operation are not read, as reading them has side
+    /// effects.
+    pub trait DestructiveDump<R: Register> {
+        fn readable(&self) -> bool {
+            false
+        }
+        fn fmt_register(&self, _: R, _: &mut fmt::Formatter, _: ValueFmt<R::DataType>) -> Result {
+            Ok(())
+        }
+    }
+    impl<R: ReadDestructive> DestructiveDump<R> for &&Probe<R> {}
+
+    /// Other registers with the Read operation are read, and their value is formatted by
+    /// `value_fmt`.
+    pub trait ReadDump<R: Register> {
+        fn readable(&self) -> bool;
+        fn fmt_register(
+            &self,
+            register: R,
+            f: &mut fmt::Formatter,
+            value_fmt: ValueFmt<R::DataType>,
+        ) -> Result;
+    }
+    impl<R: Read> ReadDump<R> for &Probe<R> {
+        fn readable(&self) -> bool {
+            true
+        }
+        fn fmt_register(
+            &self,
+            register: R,
+            f: &mut fmt::Formatter,
+            value_fmt: ValueFmt<R::DataType>,
+        ) -> Result {
+            value_fmt(register.get(), f)
+        }
+    }
+
+    /// Registers without the Read operation are not read.
+    pub trait SkippedDump<R: Register> {
+        fn readable(&self) -> bool {
+            false
+        }
+        fn fmt_register(&self, _: R, _: &mut fmt::Formatter, _: ValueFmt<R::DataType>) -> Result {
+            Ok(())
+        }
+    }
+    impl<R: Register> SkippedDump<R> for Probe<R> {}
+
+    /// Values of a [`DebugDataType`] (such as bitfield registers) are formatted by the data type.
+    pub trait DebugValue<D: DataType> {
+        fn value_fmt(&self) -> ValueFmt<D>;
+    }
+    impl<D: DebugDataType> DebugValue<D> for &&Probe<D> {
+        fn value_fmt(&self) -> ValueFmt<D> {
+            D::fmt_value
+        }
+    }
+
+    /// Values of other data types are formatted as their raw value.
+    pub trait RawValue<D: DataType> {
+        fn value_fmt(&self) -> ValueFmt<D>;
+    }
+    impl<D: DataType<Value: fmt::Debug>> RawValue<D> for &Probe<D> {
+        fn value_fmt(&self) -> ValueFmt<D> {
+            |value, f| fmt::Debug::fmt(&value, f)
+        }
+    }
+
+    /// Values without a Debug impl are elided.
+    pub trait OpaqueValue<D: DataType> {
+        fn value_fmt(&self) -> ValueFmt<D> {
+            |_, f| f.write_str("..")
+        }
+    }
+    impl<D: DataType> OpaqueValue<D> for Probe<D> {}
+}
+
+/// Fake register operations, used by the `fake_impl` arms of the operation macros. The fake
+/// register types generated by register_map! wrap a FakeCell, which these functions access.
+#[cfg(feature = "register_types")]
+pub mod fake {
+    use crate::{DataType, FakeCell, UIntLike};
+
+    pub fn read<D: DataType>(cell: &FakeCell<'_, D>) -> D::Value {
+        cell.fake_read()
+    }
+
+    pub fn write<D: DataType>(cell: &FakeCell<'_, D>, value: D::Value) {
+        cell.fake_write(value)
+    }
+
+    pub fn write_1_clear<D: DataType<Value: UIntLike>>(cell: &FakeCell<'_, D>, mask: D::Value) {
+        cell.fake_clear(mask)
+    }
+
+    pub fn write_1_set<D: DataType<Value: UIntLike>>(cell: &FakeCell<'_, D>, mask: D::Value) {
+        cell.fake_set_bits(mask)
+    }
+}
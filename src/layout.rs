@@ -0,0 +1,125 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+//! Layout metadata for registers and register blocks.
+//!
+//! [`register_map`](crate::register_map) implements [`LayoutInfo`] for the `Real` type of every
+//! register and register block it generates. The layout describes the register block's fields
+//! (their names, offsets, sizes, array lengths, and operations), which allows tools such as
+//! register dump commands to work with any peripheral:
+//! ```
+//! # fn main() { layout_test() }
+//! use tock_registers::layout::{Layout, LayoutInfo};
+//! use tock_registers::{mmio32_register_map, Read, Write};
+//! mmio32_register_map! {
+//!     uart {
+//!         /// Control register.
+//!         ///
+//!         /// Only the summary is included in the layout.
+//!         0 => ctrl: u16 { Read, Write },
+//!         2 => status: u8 { Read },
+//!         3 => _,
+//!         #[stride(8)] 8 => fifo: [u32; 2] { Read },
+//!         // pub(crate) fields are not included in the layout.
+//!         #[aliased] pub(crate) 12 => key: u32 { Write },
+//!     }
+//! }
+//!
+//! /// Describes the offset of every field of a register block.
+//! fn describe_offsets<R: LayoutInfo>() -> Vec<String> {
+//!     let Layout::Block { fields } = R::LAYOUT else { return Vec::new() };
+//!     fields
+//!         .iter()
+//!         .map(|field| format!("{:#x}: {} ({})", field.offset, field.name, field.summary))
+//!         .collect()
+//! }
+//!
+//! fn layout_test() {
+//!     let offsets = describe_offsets::<uart::Real>();
+//!     assert_eq!(offsets, ["0x0: ctrl (Control register.)", "0x2: status ()", "0x8: fifo ()"]);
+//!     let Layout::Block { fields } = <uart::Real as LayoutInfo>::LAYOUT else { unreachable!() };
+//!     let Layout::Array { len: 2, stride: 8, element } = fields[2].layout else { unreachable!() };
+//!     assert!(matches!(element, Layout::Register { size: 4, operations: ["Read"] }));
+//!     assert_eq!(fields[2].size, 16);
+//! }
+//! ```
+
+use crate::array::{element_stride, Len, RealRegisterArray, Stride};
+use crate::Span;
+
+/// Implemented by registers, register arrays, and register blocks to describe their layout.
+///
+/// The layout depends on the bus, so (for example) `uart::Real<Mmio32>` and `uart::Real<Mmio64>`
+/// may have different layouts.
+pub trait LayoutInfo: Span {
+    /// This type's layout. The offsets of a block's fields are relative to the base address of
+    /// the block. Fields with `pub(crate)` visibility are excluded, and fields whose `#[cfg]`
+    /// predicates are false are absent.
+    const LAYOUT: Layout;
+}
+
+/// The layout of a register, register array, or register block.
+#[derive(Clone, Copy, Debug)]
+pub enum Layout {
+    /// A single register.
+    Register {
+        /// The size (in bytes) of the register in its bus' address space.
+        size: usize,
+        /// The names of the register's operations (such as `"Read"` and `"Write"`), in the order
+        /// they were specified in the register definition.
+        operations: &'static [&'static str],
+    },
+    /// An array of registers or register blocks.
+    Array {
+        /// The number of elements in the array.
+        len: usize,
+        /// The distance (in bytes) between the start of consecutive elements.
+        stride: usize,
+        /// The layout of each element.
+        element: &'static Layout,
+    },
+    /// A register block. Padding fields and `pub(crate)` fields are not included.
+    Block { fields: &'static [FieldInfo] },
+}
+
+/// A register block field.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldInfo {
+    /// The field's name.
+    pub name: &'static str,
+    /// The first paragraph of the field's doc comment (empty if it has no doc comment).
+    pub summary: &'static str,
+    /// The offset (in bytes) of the field within the register block.
+    pub offset: usize,
+    /// The size (in bytes) of the field.
+    pub size: usize,
+    /// The layout of the field's register, register array, or register block.
+    pub layout: Layout,
+}
+
+impl FieldInfo {
+    /// Returns the FieldInfo of a field whose register, register array, or register block type is
+    /// `R`.
+    pub const fn new<R: LayoutInfo>(
+        name: &'static str,
+        summary: &'static str,
+        offset: usize,
+    ) -> FieldInfo {
+        FieldInfo {
+            name,
+            summary,
+            offset,
+            size: R::SIZE,
+            layout: R::LAYOUT,
+        }
+    }
+}
+
+impl<Element: LayoutInfo, L: Len, S: Stride> LayoutInfo for RealRegisterArray<Element, L, S> {
+    const LAYOUT: Layout = Layout::Array {
+        len: L::LEN,
+        stride: element_stride::<Element, S>(),
+        element: &Element::LAYOUT,
+    };
+}
@@ -63,10 +63,13 @@
 #![cfg_attr(not(feature = "register_types"), forbid(unsafe_code))]
 #![forbid(unsafe_op_in_unsafe_fn)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(feature = "register_types")]
 pub mod array;
 #[cfg(feature = "register_types")]
-pub use array::{RealRegisterArray, RegisterArray};
+pub use array::{RealRegisterArray, RegisterArray, RegisterSlice};
 
 #[cfg(feature = "register_types")]
 mod bus;
@@ -77,10 +80,32 @@ mod data_type;
 pub use data_type::{DataType, Register};
 
 pub mod debug;
+
+#[cfg(feature = "register_types")]
+mod endian;
+#[cfg(feature = "register_types")]
+pub use endian::{BigEndian, Mmio32Be, Mmio64Be};
+
+#[cfg(feature = "register_types")]
+mod fake_block;
+#[cfg(feature = "register_types")]
+pub use fake_block::{FakeArray, FakeCell};
+
+mod fake_register;
+pub use fake_register::{ClearBits, Destructive, FakeRegister, NoAccess, Safe, SetBits, Unsafe};
+
 pub mod fields;
 pub mod interfaces;
 pub mod internal;
 
+#[cfg(feature = "register_types")]
+pub mod layout;
+
+#[cfg(feature = "register_types")]
+mod litex;
+#[cfg(feature = "register_types")]
+pub use litex::{LiteXC32B32, LiteXC32B64, LiteXC8B32, LiteXC8B64};
+
 mod local_register;
 pub use local_register::LocalRegisterCopy;
 
@@ -94,19 +119,55 @@ mod mmio;
 #[cfg(feature = "register_types")]
 pub use mmio::{Mmio32, Mmio32Nullable, Mmio64, Mmio64Nullable};
 
+#[cfg(feature = "register_types")]
+mod port_io;
+#[cfg(feature = "register_types")]
+pub use port_io::{FakePortSpace, PortIo, PortSpace, X86Ports};
+
 mod read;
 #[cfg(feature = "register_types")]
 pub use read::BusRead;
-pub use read::Read;
+pub use read::{Read, WaitResult};
+
+mod read_destructive;
+pub use read_destructive::ReadDestructive;
+
+#[cfg(feature = "alloc")]
+mod recording;
+#[cfg(feature = "alloc")]
+pub use recording::{RecordedAccess, Recording, RecordingBus, Replay, ReplayBus};
 
 #[cfg(feature = "register_types")]
 pub mod registers;
 
+#[cfg(feature = "register_types")]
+mod trace;
+#[cfg(feature = "register_types")]
+pub use trace::{Access, AccessKind, TraceSink, Traced};
+
+// UnsafeRead and UnsafeWrite have unsafe methods, so they are only available when the crate is
+// allowed to contain unsafe code.
+#[cfg(feature = "register_types")]
+mod unsafe_read;
+#[cfg(feature = "register_types")]
+pub use unsafe_read::UnsafeRead;
+
+#[cfg(feature = "register_types")]
+mod unsafe_write;
+#[cfg(feature = "register_types")]
+pub use unsafe_write::UnsafeWrite;
+
 mod write;
 #[cfg(feature = "register_types")]
 pub use write::BusWrite;
 pub use write::Write;
 
+mod write1_clear;
+pub use write1_clear::Write1Clear;
+
+mod write1_set;
+pub use write1_set::Write1Set;
+
 use core::fmt::Debug;
 use core::ops::{BitAnd, BitOr, BitOrAssign, Not, Shl, Shr};
 
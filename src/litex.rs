@@ -0,0 +1,193 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+//! LiteX CSR buses.
+//!
+//! LiteX peripherals expose their registers as CSRs (control and status registers). A CSR is split
+//! into subregisters that are `CSR_DATA_WIDTH` (8 or 32) bits wide, and each subregister is
+//! aligned to the CSR alignment (the CPU's word size, which is LiteX's default). The most
+//! significant subregister is at the lowest address. For example, with an 8-bit data width and a
+//! 32-bit CPU, a `u32` register occupies four 32-bit words, each of which contains one byte of
+//! the register's value.
+//!
+//! The LiteX buses are named after their data width and alignment: `LiteXC8B32` has an 8-bit CSR
+//! data width and 32-bit alignment, and is used on 32-bit CPUs. Every register starts at a
+//! subregister boundary, so `register_map!` requires register offsets on these buses to be
+//! multiples of the CSR alignment.
+//!
+//! ```
+//! # fn main() { timer_test() }
+//! use core::ptr::NonNull;
+//! use tock_registers::{register_map, LiteXC32B32, LiteXC8B32, Read, Write};
+//! register_map! {
+//!     #[buses(LiteXC8B32, LiteXC32B32)]
+//!     timer {
+//!         [0, 0] => load: u64 { Read, Write },
+//!         [32, 8] => status: u16 { Read, Write },
+//!     }
+//! }
+//!
+//! fn timer_test() {
+//!     use timer::Interface;
+//!     let mut memory = [0u32; 10];
+//!     memory[8..10].copy_from_slice(&[0xFF12, 0xFF34]);
+//!     let bus = LiteXC8B32::new(NonNull::from(&mut memory).cast());
+//!     // Safety: `memory` is large enough for the `timer` block on this bus and is only accessed
+//!     // through `registers`.
+//!     let registers = unsafe { timer::Real::new(bus) };
+//!     // Bits outside the subregister's data width are ignored.
+//!     assert_eq!(registers.status().get(), 0x1234);
+//!     registers.load().set(0x0102_0304_0506_0708);
+//!     assert_eq!(memory[..8], [1, 2, 3, 4, 5, 6, 7, 8]);
+//!
+//!     let mut memory = [0u32; 3];
+//!     let bus = LiteXC32B32::new(NonNull::from(&mut memory).cast());
+//!     // Safety: `memory` is large enough for the `timer` block on this bus and is only accessed
+//!     // through `registers`.
+//!     let registers = unsafe { timer::Real::new(bus) };
+//!     registers.load().set(0x0102_0304_0506_0708);
+//!     registers.status().set(0x1234);
+//!     assert_eq!(memory, [0x0102_0304, 0x0506_0708, 0x1234]);
+//! }
+//! ```
+
+use crate::{Address, Bus, BusRead, BusWrite, Mmio32, Mmio64};
+use core::ptr::NonNull;
+
+/// Reads a value split across `count` subregisters of `width` bits each, with a distance of
+/// `stride` bytes between subregisters.
+/// # Safety
+/// There must be `count` readable subregisters starting at `address`, and the caller is
+/// responsible for avoiding data races.
+unsafe fn read_subregisters<A: Address + BusRead<u32>>(
+    address: A,
+    count: usize,
+    stride: usize,
+    width: u32,
+) -> u64 {
+    let mask = u64::MAX >> (64 - width);
+    let mut value = 0;
+    for i in 0..count {
+        // Safety: The caller guaranteed subregister i exists at this offset.
+        let subregister = unsafe { address.byte_add(i * stride).read() };
+        value = (value << width) | (u64::from(subregister) & mask);
+    }
+    value
+}
+
+/// Writes a value split across `count` subregisters of `width` bits each, with a distance of
+/// `stride` bytes between subregisters.
+/// # Safety
+/// There must be `count` writable subregisters starting at `address`, and the caller is
+/// responsible for avoiding data races.
+unsafe fn write_subregisters<A: Address + BusWrite<u32>>(
+    address: A,
+    count: usize,
+    stride: usize,
+    width: u32,
+    value: u64,
+) {
+    let mask = u64::MAX >> (64 - width);
+    for i in 0..count {
+        let shift = (count - 1 - i) as u32 * width;
+        let subregister = (value >> shift) & mask;
+        // Safety: The caller guaranteed subregister i exists at this offset.
+        unsafe { address.byte_add(i * stride).write(subregister as u32) }
+    }
+}
+
+/// Macro to declare the LiteX bus structs and provide their impls.
+macro_rules! litex_buses {
+    [$($(#[$docs:meta])* $name:ident($mmio:ident, $width:literal, $stride:literal))*] => {$(
+        $(#[$docs])*
+        #[derive(Clone, Copy)]
+        pub struct $name($mmio);
+
+        impl $name {
+            /// Returns a new LiteX CSR address containing the given pointer.
+            pub const fn new(ptr: NonNull<()>) -> Self {
+                Self($mmio::new(ptr))
+            }
+
+            /// Constructs a new LiteX CSR bus pointing to the given address. See
+            #[doc = concat!("[`", stringify!($mmio), "::from_addr`]")]
+            /// for details.
+            /// # Panics
+            /// Panics if the provided address is null.
+            #[track_caller]
+            pub const fn from_addr(addr: usize) -> Self {
+                Self($mmio::from_addr(addr))
+            }
+        }
+
+        impl Address for $name {
+            unsafe fn byte_add(self, offset: usize) -> Self {
+                // Safety: The caller upheld Address::byte_add's requirements, which are the same
+                // for the underlying MMIO bus.
+                Self(unsafe { self.0.byte_add(offset) })
+            }
+        }
+
+        litex_bus_impls!($name, $width, $stride, u8 u16 u32 u64);
+    )*}
+}
+
+/// Macro to implement the Bus traits for a LiteX bus.
+macro_rules! litex_bus_impls {
+    [$name:ident, $width:literal, $stride:literal, $($value:ty)*] => {$(
+        /// Safety: PADDED_SIZE is the number of subregisters times the subregister stride.
+        unsafe impl Bus<$value> for $name {
+            const PADDED_SIZE: usize = <$value>::BITS.div_ceil($width) as usize * $stride;
+            // Every register starts at a subregister boundary.
+            const ALIGNMENT: usize = $stride;
+        }
+        impl BusRead<$value> for $name {
+            unsafe fn read(self) -> $value {
+                // BusRead::read's preconditions guarantee that a readable register with value type
+                // $value exists at self, which consists of the subregisters read here. The caller
+                // is responsible for avoiding data races and satisfying any other unsafe
+                // invariants of the register.
+                (unsafe {
+                    read_subregisters(
+                        self.0,
+                        <$value>::BITS.div_ceil($width) as usize,
+                        $stride,
+                        $width,
+                    )
+                }) as $value
+            }
+        }
+        impl BusWrite<$value> for $name {
+            unsafe fn write(self, value: $value) {
+                // BusWrite::write's preconditions guarantee that a writable register with value
+                // type $value exists at self, which consists of the subregisters written here. The
+                // caller is responsible for avoiding data races and satisfying any other unsafe
+                // invariants of the register.
+                unsafe {
+                    write_subregisters(
+                        self.0,
+                        <$value>::BITS.div_ceil($width) as usize,
+                        $stride,
+                        $width,
+                        value.into(),
+                    )
+                }
+            }
+        }
+    )*}
+}
+
+litex_buses! {
+    /// LiteX CSR bus with an 8-bit CSR data width, for 32-bit CPUs.
+    LiteXC8B32(Mmio32, 8, 4)
+
+    /// LiteX CSR bus with a 32-bit CSR data width, for 32-bit CPUs.
+    LiteXC32B32(Mmio32, 32, 4)
+
+    /// LiteX CSR bus with an 8-bit CSR data width, for 64-bit CPUs.
+    LiteXC8B64(Mmio64, 8, 8)
+
+    /// LiteX CSR bus with a 32-bit CSR data width, for 64-bit CPUs.
+    LiteXC32B64(Mmio64, 32, 8)
+}
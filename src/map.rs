@@ -40,8 +40,7 @@
 ///
 /// To unit test code that uses the generated register, modify the code that you want to test so
 /// that it takes a `C: ctrl::Interface` rather than a `Real`. Then, in the unit test, you can pass
-// TODO: Link FakeRegister using [`FakeRegister`](crate::FakeRegister)
-/// in a `FakeRegister` with whatever behavior the test case needs. See
+/// in a [`FakeRegister`](crate::FakeRegister) with whatever behavior the test case needs. See
 /// `doc/UnitTesting.md` in the tock-registers repository for more information.
 ///
 /// # mmio32_register_map and mmio64_register_map
@@ -61,6 +60,64 @@
 /// mmio32_register_map![ctrl: Control::Register { Read, Write }];
 /// ```
 ///
+/// Alternatively, the bitfields can be defined inline, in brackets after the register's
+/// operations. Inline bitfields use the same syntax as `register_bitfields!` (except that
+/// `NUMBITS` defaults to 1), and are expanded by `register_bitfields!`: the register's module
+/// contains a `Register` type and each bitfield's `Field` constant and module. For a register block
+/// field, these items are in a module named after the field, within the block's module. The
+/// bitfields' offsets and widths are checked when the macro is expanded, so overlapping bitfields
+/// or bitfields that do not fit in the register are compile errors. A bitfield cannot share its
+/// register's name. As the module shares the block's module with its generated items, a field with
+/// inline bitfields cannot be named `Bus`, `BusLayout`, `Fake`, `Interface`, `Real`,
+/// `RestrictedInterface`, `cfg_bounds`, `lens`, `resets`, `sealed`, or `strides`.
+/// ```
+/// # fn main() { start_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::debug::RegisterDebugInfo;
+/// use tock_registers::{mmio32_register_map, Mmio32, Read, Write};
+/// mmio32_register_map! {
+///     #![fake]
+///     ctrl: u8 { Read, Write } [
+///         ON OFFSET(0),
+///         SPEED OFFSET(1) NUMBITS(2) [Slow = 0, Medium = 1, Fast = 2],
+///     ],
+///     uart {
+///         0 => status: u8 { Read } [BUSY OFFSET(0), QUEUED OFFSET(4) NUMBITS(4) []],
+///         1 => ctrl: ctrl,
+///         2 => _: 2,
+///     }
+/// }
+///
+/// /// Enables the UART if it is idle, and returns the number of queued bytes.
+/// fn start<U: uart::Interface>(registers: U) -> u8 {
+///     let status = registers.status();
+///     if !status.is_set(uart::status::BUSY) {
+///         registers.ctrl().write(ctrl::ON::SET + ctrl::SPEED::Fast);
+///     }
+///     status.read(uart::status::QUEUED)
+/// }
+///
+/// fn start_test() {
+///     assert_eq!(ctrl::SPEED::Fast.value, 0b100);
+///     assert_eq!(ctrl::SPEED.read_as_enum(0b100), Some(ctrl::SPEED::Value::Fast));
+///     assert_eq!(ctrl::SPEED.read_as_enum::<ctrl::SPEED::Value>(0b110), None);
+///     assert_eq!(uart::status::Register::field_names(), ["BUSY", "QUEUED"]);
+///
+///     let mut memory = [u32::from_ne_bytes([0x30, 0, 0, 0])];
+///     let mmio = Mmio32::new(NonNull::from(&mut memory).cast());
+///     // Safety: `memory` has the layout of the `uart` register block and is only accessed
+///     // through `registers`.
+///     let registers = unsafe { uart::Real::new(mmio) };
+///     assert_eq!(start(registers), 3);
+///     assert_eq!(memory[0].to_ne_bytes(), [0x30, 0b101, 0, 0]);
+///
+///     let fake = uart::Fake::new();
+///     fake.status.set_value(0x21);
+///     assert_eq!(start(&fake), 2);
+///     assert_eq!(fake.ctrl.value(), 0);
+/// }
+/// ```
+///
 /// # Register arrays
 /// You can define register arrays as well:
 /// ```
@@ -98,13 +155,13 @@
 /// use tock_registers::{mmio32_register_map, Read, Write};
 /// mmio32_register_map! {
 ///     uart {
-///         0 => status: u8 { Read },
-///         1 => ctrl: u16 { Read, Write },
+///         0 => ctrl: u16 { Read, Write },
+///         2 => status: u8 { Read },
 ///         3 => buffer: u8 { Read, Write },
 ///     }
 /// }
 /// ```
-/// The leading integers (0, 1, 3) are address offsets (the value added to the base address to get
+/// The leading integers (0, 2, 3) are address offsets (the value added to the base address to get
 /// the register's address). The generated module looks like:
 /// ```
 /// # fn main() {}
@@ -112,10 +169,10 @@
 /// mod uart {
 ///     use super::*;
 ///     pub trait Interface: Copy {
-///         type status: tock_registers::Register<DataType = u8> + Read;
-///         fn status(self) -> Self::status;
 ///         type ctrl: tock_registers::Register<DataType = u16> + Read + Write;
 ///         fn ctrl(self) -> Self::ctrl;
+///         type status: tock_registers::Register<DataType = u8> + Read;
+///         fn status(self) -> Self::status;
 ///         type buffer: tock_registers::Register<DataType = u8> + Read + Write;
 ///         fn buffer(self) -> Self::buffer;
 ///     }
@@ -126,7 +183,20 @@
 /// }
 /// ```
 /// As before, `Real` implements `Interface`. The generated code includes tests that verify the
-/// offsets are correct (the registers must not overlap or have gaps between them).
+/// offsets are correct (the registers must not overlap or have gaps between them, and each
+/// register's offset must be aligned as its bus requires, e.g. a `u32` on [`Mmio32`](crate::Mmio32)
+/// must be at a multiple of 4):
+/// ```compile_fail
+/// # fn main() {}
+/// use tock_registers::{mmio32_register_map, Read};
+/// mmio32_register_map! {
+///     uart {
+///         0 => status: u16 { Read },
+///         // Error: field `data` is misaligned for bus Mmio32.
+///         2 => data: u32 { Read },
+///     }
+/// }
+/// ```
 ///
 /// # Register blocks with references
 /// The fields of register blocks can be arrays, references, and arrays of references:
@@ -173,6 +243,43 @@
 /// }
 /// ```
 ///
+/// # Constant expressions
+/// Offsets, padding sizes, strides, and array lengths do not have to be integer literals: they can
+/// be any constant expression of type `usize`, and can use constants that are in scope where the
+/// macro is invoked. The offsets are still checked at compile time.
+/// ```
+/// # fn main() { adc_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::{mmio32_register_map, Mmio32, Read, RegisterArray, Span, Write};
+/// const CHANNELS: usize = 4;
+/// const DATA_BASE: usize = 0x40;
+/// mod consts {
+///     pub const FIFO_DEPTH: u8 = 2;
+/// }
+/// mmio32_register_map! {
+///     #![fake]
+///     adc {
+///         0 => ctrl: u32 { Read, Write },
+///         4 => results: [u16; CHANNELS] { Read },
+///         4 + 2 * CHANNELS => _: DATA_BASE - (4 + 2 * CHANNELS),
+///         DATA_BASE => data: [u32; consts::FIFO_DEPTH as usize] { Read },
+///     }
+/// }
+///
+/// fn adc_test() {
+///     use adc::Interface;
+///     assert_eq!(<adc::Real<Mmio32> as Span>::SIZE, DATA_BASE + 8);
+///     let mut memory = [0u32; DATA_BASE / 4 + 2];
+///     memory[DATA_BASE / 4 + 1] = 7;
+///     let mmio = Mmio32::new(NonNull::from(&mut memory).cast());
+///     // Safety: `memory` has the layout of the `adc` register block and is only accessed
+///     // through `registers`.
+///     let registers = unsafe { adc::Real::new(mmio) };
+///     assert_eq!(registers.data().get(1).unwrap().get(), 7);
+///     assert_eq!(adc::Fake::new().results.len(), CHANNELS);
+/// }
+/// ```
+///
 /// # Aliased fields
 /// Sometimes, the offset tests are too restrictive. In that case, you can mark a register as
 /// `#[aliased]`, which removes it from the offset calculation logic entirely. This allows you to
@@ -194,13 +301,351 @@
 ///     }
 /// }
 /// ```
-/// One word of warning: the offset calculation logic also calculates the total size of a register
-/// block. That size is used when a register block is embedded into another register type (like
-/// another register block or array). Marking a register `#[aliased]` therefore makes it not affect
-/// the register's block size. If a `#[aliased]` register extends beyond the end of the
-/// non-`#[aliased]` registers, then that register will exist outside the register block's
-/// boundaries! That may or may not be what you wanted. If in doubt, put a padding field at the end
-/// to make sure the total size is correct.
+/// The offset calculation logic also calculates the total size of a register block. That size is
+/// used when a register block is embedded into another register type (like another register block
+/// or array). Marking a register `#[aliased]` therefore makes it not affect the register's block
+/// size. A `#[aliased]` register must not extend beyond the end of the non-`#[aliased]` registers
+/// (if it needs to, put a padding field at the end to make the block larger), and it must not
+/// overlap a padding field. Both are checked at compile time, as is the alignment of `#[aliased]`
+/// registers:
+/// ```compile_fail
+/// # fn main() {}
+/// use tock_registers::{mmio32_register_map, Read};
+/// mmio32_register_map! {
+///     uart {
+///         0 => status: u32 { Read },
+///         // Error: aliased field `data` extends beyond the block for bus Mmio32.
+///         #[aliased]
+///         4 => data: u32 { Read },
+///     }
+/// }
+/// ```
+/// ```compile_fail
+/// # fn main() {}
+/// use tock_registers::{mmio32_register_map, Read};
+/// mmio32_register_map! {
+///     uart {
+///         0 => status: u32 { Read },
+///         4 => _: 4,
+///         // Error: aliased field `data` overlaps padding for bus Mmio32.
+///         #[aliased]
+///         4 => data: u32 { Read },
+///     }
+/// }
+/// ```
+///
+/// # Strided arrays
+/// By default, the elements of a register array are densely packed. Some peripherals instead space
+/// out per-channel registers, interleaving them with other channels' registers. A `#[stride]`
+/// attribute on an array field sets the distance (in bytes) between consecutive elements. Like
+/// offsets, the stride can be a single value or an array of per-bus values. For nested arrays,
+/// the stride applies to the outermost array.
+/// ```
+/// # fn main() { stride_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::{mmio32_register_map, Mmio32, Read, RegisterArray, Write};
+/// mmio32_register_map! {
+///     /// Each channel has a control register followed by a status register.
+///     dma {
+///         #[stride(8)] 0 => control: [u32; 4] { Read, Write },
+///         #[aliased] #[stride(8)] 4 => status: [u32; 4] { Read },
+///     }
+/// }
+///
+/// fn stride_test() {
+///     use dma::Interface;
+///     let mut memory = [0, 0, 0, 1, 0, 0, 0, 0];
+///     let mmio = Mmio32::new(NonNull::from(&mut memory).cast());
+///     // Safety: `memory` has the layout of the `dma` register block and is only accessed
+///     // through `registers`.
+///     let registers = unsafe { dma::Real::new(mmio) };
+///     assert_eq!(registers.status().get_const::<1>().get(), 1);
+///     registers.control().get_const::<2>().set(5);
+///     assert_eq!(memory, [0, 0, 0, 1, 5, 0, 0, 0]);
+/// }
+/// ```
+/// A strided array occupies its stride times its length, so the next field in this example would
+/// be at offset 32. The stride must be at least the size of the array's element, which is checked
+/// at compile time. Interleaved arrays overlap each other, so all but one of them must be
+/// `#[aliased]`.
+///
+/// # Unsafe registers
+/// Some registers cannot be safely accessed in arbitrary ways. For example, writing a DMA
+/// peripheral's address register allows the hardware to write to arbitrary memory. These
+/// registers should use the [`UnsafeRead`](trait@crate::UnsafeRead) and
+/// [`UnsafeWrite`](trait@crate::UnsafeWrite) operations, whose methods are `unsafe`:
+/// ```
+/// # fn main() {}
+/// use tock_registers::{mmio32_register_map, Read, UnsafeRead, UnsafeWrite, Write};
+/// mmio32_register_map! {
+///     dma {
+///         0 => ctrl: u8 { Read, Write },
+///         1 => _,
+///         /// Safety: Writing this starts a DMA transfer to the written address.
+///         4 => address: usize { UnsafeRead, UnsafeWrite },
+///         8 => length: u32 { Read, Write },
+///     }
+/// }
+/// ```
+///
+/// # Registers with read side effects
+/// Registers whose reads change the hardware's state (such as read-to-clear status registers or
+/// FIFO data registers that pop on read) should use the
+/// [`ReadDestructive`](trait@crate::ReadDestructive) operation instead of [`Read`].
+/// `ReadDestructive` only provides `take`, which reads the register once and returns a
+/// [`LocalRegisterCopy`](crate::LocalRegisterCopy), so the register cannot accidentally be read
+/// multiple times by methods such as `is_set` and `matches_any`:
+/// ```
+/// # fn main() {}
+/// use tock_registers::{mmio32_register_map, ReadDestructive};
+/// mmio32_register_map![rx_fifo: u32 { ReadDestructive }];
+/// ```
+///
+/// # Write-1-to-clear and write-1-to-set registers
+/// Registers where writing a 1 clears (or sets) a bit and writing a 0 leaves it unchanged should
+/// use the [`Write1Clear`](trait@crate::Write1Clear) and [`Write1Set`](trait@crate::Write1Set)
+/// operations instead of [`Write`]. These provide `clear` and `set_bits` respectively, but not
+/// `modify`, which would clear every pending bit of a write-1-to-clear register:
+/// ```
+/// # fn main() {}
+/// use tock_registers::{mmio32_register_map, Read, Write1Clear, Write1Set};
+/// mmio32_register_map! {
+///     interrupts {
+///         0 => pending: u32 { Read, Write1Clear },
+///         4 => enable_set: u32 { Write1Set },
+///     }
+/// }
+/// ```
+///
+/// # Fake register blocks
+/// Adding `#[fake]` to a layout (or `#![fake]` at the top of the invocation, to apply it to every
+/// layout) generates a `Fake` type for unit tests, which is constructed by `Fake::new()` (or
+/// [`Default`]). `&Fake` implements `Interface`. Each register definition is stored in a generated
+/// fake register type that implements only the register's operations, and derefs to the
+/// [`FakeCell`](crate::FakeCell) holding its value (arrays are stored in a
+/// [`FakeArray`](crate::FakeArray)). The fields of a block's `Fake` are public so that tests can
+/// inspect register values and attach hooks to give registers side effects:
+/// ```
+/// # fn main() { transmit_test() }
+/// use core::cell::Cell;
+/// use tock_registers::{mmio32_register_map, Read, Write};
+/// mmio32_register_map! {
+///     #[fake]
+///     uart {
+///         0 => data: u8 { Write },
+///         1 => status: u8 { Read },
+///     }
+/// }
+///
+/// fn transmit<U: uart::Interface>(registers: U, byte: u8) {
+///     while registers.status().get() == 0 {}
+///     registers.data().set(byte);
+/// }
+///
+/// fn transmit_test() {
+///     let fake = uart::Fake::new();
+///     let status_reads = Cell::new(0);
+///     let status_hook = || {
+///         status_reads.set(status_reads.get() + 1);
+///         status_reads.get() / 2
+///     };
+///     fake.status.on_read(&status_hook);
+///     transmit(&fake, b'a');
+///     assert_eq!(fake.data.value(), b'a');
+///     assert_eq!(status_reads.get(), 2);
+/// }
+/// ```
+/// Layouts referenced by a `#[fake]` layout must also be `#[fake]`. A field that references
+/// another layout stores that layout's `Fake`, and register arrays are stored as (nested)
+/// `FakeArray`s:
+/// ```
+/// # fn main() { arrays_test() }
+/// use tock_registers::{mmio32_register_map, Read, RegisterArray, Write};
+/// mmio32_register_map! {
+///     #![fake]
+///     counter: u16 { Read, Write },
+///     uart {
+///         0 => counter: counter,
+///         2 => fifo: [[u8; 2]; 3] { Read, Write },
+///     }
+/// }
+///
+/// fn arrays_test() {
+///     use uart::Interface;
+///     let fake = uart::Fake::new();
+///     (&fake).counter().set(5);
+///     (&fake).fifo().get(2).unwrap().get(1).unwrap().set(7);
+///     assert!((&fake).fifo().get(3).is_none());
+///     assert_eq!(fake.counter.value(), 5);
+///     assert_eq!(fake.fifo[2][1].value(), 7);
+/// }
+/// ```
+/// Like the real registers, fake registers do not implement operations that their definition
+/// does not list:
+/// ```compile_fail
+/// # fn main() {}
+/// use tock_registers::{mmio32_register_map, Read, Write};
+/// mmio32_register_map! {
+///     #[fake]
+///     status: u8 { Read },
+/// }
+///
+/// fn write_test() {
+///     // Error: status is read-only.
+///     (&status::Fake::new()).set(1);
+/// }
+/// ```
+/// `FakeCell` and `FakeArray` require the `register_types` feature.
+///
+/// # Reset values
+/// A register definition (either a top-level layout or a block field) can specify the value it
+/// contains after a hardware reset using a `#[reset(...)]` attribute, which accepts a constant
+/// expression of the register's value type. Every element of a register array shares the reset
+/// value. The reset value is exposed as:
+/// 1. `RESET` constants on the layout's module, its `Interface` trait, and its real register
+///    struct, for top-level layouts.
+/// 2. A `<field>_reset` constant on the block's `Interface` trait, and a `RESET` constant on the
+///    field's real register struct, for block fields.
+///
+/// The `Fake::new()` constructor of a `#[fake]` layout initializes each register to its reset
+/// value (registers without a reset value contain their default value, and `Fake::new()` requires
+/// their value types to implement `Default`).
+/// ```
+/// # fn main() { restore_test() }
+/// use tock_registers::{mmio32_register_map, Read, Write};
+/// mmio32_register_map! {
+///     #![fake]
+///     #[reset(0x80)]
+///     status: u8 { Read },
+///     uart {
+///         #[reset(0x3)] 0 => ctrl: u8 { Read, Write },
+///         #[reset(0x1)] 1 => fifo: [u8; 2] { Read, Write },
+///         3 => status: status,
+///     }
+/// }
+///
+/// fn restore<U: uart::Interface>(registers: U) {
+///     registers.ctrl().set(U::ctrl_reset);
+/// }
+///
+/// fn restore_test() {
+///     assert_eq!(status::RESET, 0x80);
+///     let fake = uart::Fake::new();
+///     assert_eq!(fake.ctrl.value(), 0x3);
+///     assert_eq!(fake.fifo[1].value(), 0x1);
+///     assert_eq!(fake.status.value(), 0x80);
+///     fake.ctrl.set_value(0);
+///     restore(&fake);
+///     assert_eq!(fake.ctrl.value(), 0x3);
+/// }
+/// ```
+///
+/// # Conditional compilation
+/// Layouts and register block fields can have `#[cfg(...)]` attributes (`#![cfg(...)]` at the top
+/// of the invocation applies to every layout). A layout that is not compiled in does not generate
+/// its module, and a field that is not compiled in does not generate its register (including its
+/// `Interface` items). Padding fields cannot have `#[cfg]` attributes.
+///
+/// A field that is not compiled in is treated like padding without a specified size: the next
+/// field's offset is only checked against the end of the field before it, which it must not
+/// precede. If it is the last non-aliased field of the block, the block ends at its offset. This
+/// allows variants of a peripheral to have different registers at the same offset:
+/// ```
+/// # fn main() { timer_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::{mmio32_register_map, Mmio32, Read, RegisterArray, Span, Write};
+/// mmio32_register_map! {
+///     #![fake]
+///     timer {
+///         0 => count: u32 { Read, Write },
+///         #[cfg(target_endian = "little")] 4 => prescaler: u32 { Read, Write },
+///         #[cfg(not(target_endian = "little"))] 4 => prescaler: [u16; 2] { Read, Write },
+///         8 => compare: u32 { Read, Write },
+///         #[cfg(any())] 12 => capture: u32 { Read },
+///     }
+/// }
+///
+/// fn timer_test() {
+///     use timer::Interface;
+///     assert_eq!(<timer::Real<Mmio32> as Span>::SIZE, 12);
+///     let mut memory = [0u32; 3];
+///     let mmio = Mmio32::new(NonNull::from(&mut memory).cast());
+///     // Safety: `memory` has the layout of the `timer` register block and is only accessed
+///     // through `registers`.
+///     let registers = unsafe { timer::Real::new(mmio) };
+///     registers.compare().set(3);
+///     assert_eq!(memory, [0, 0, 3]);
+///     #[cfg(target_endian = "little")]
+///     assert_eq!(timer::Fake::new().prescaler.value(), 0);
+/// }
+/// ```
+/// The next field must still not overlap the field before the one that is not compiled in:
+/// ```compile_fail
+/// # fn main() {}
+/// use tock_registers::{mmio32_register_map, Read};
+/// mmio32_register_map! {
+///     timer {
+///         0 => count: u32 { Read },
+///         #[cfg(any())] 4 => prescaler: u32 { Read },
+///         // Error: offset overlaps the previous field for bus Mmio32.
+///         2 => compare: u16 { Read },
+///     }
+/// }
+/// ```
+///
+/// # Generic register blocks
+/// Register blocks can have const generic parameters, which must have the form `const NAME:
+/// usize`. Generic parameters can be used as array lengths, offsets, strides, and padding sizes,
+/// which is useful for peripherals whose variants differ only in the number of channels:
+/// ```
+/// # fn main() { arm_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::{mmio32_register_map, Mmio32, Read, RegisterArray, Span, Write};
+/// mmio32_register_map! {
+///     #![fake]
+///     pub timer<const CHANNELS: usize> {
+///         0 => ctrl: u32 { Read, Write },
+///         #[reset(0xffff_ffff)] 4 => compare: [u32; CHANNELS] { Read, Write },
+///     },
+///     pub soc<const CHANNELS: usize> {
+///         0 => timer0: timer<2>,
+///         12 => timer1: timer<CHANNELS>,
+///     },
+/// }
+///
+/// // Driver code can support every variant of the timer.
+/// fn arm<const CHANNELS: usize, T: timer::Interface<CHANNELS>>(registers: T, deadline: u32) {
+///     registers.ctrl().set(1);
+///     for compare in registers.compare().iter() {
+///         compare.set(deadline);
+///     }
+/// }
+///
+/// fn arm_test() {
+///     use soc::Interface;
+///     assert_eq!(<timer::Real<4> as Span>::SIZE, 20);
+///     assert_eq!(<soc::Real<1> as Span>::SIZE, 20);
+///     let mut memory = [0u32; 5];
+///     let mmio = Mmio32::new(NonNull::from(&mut memory).cast());
+///     // Safety: `memory` has the layout of the `soc<1>` register block and is only accessed
+///     // through `registers`.
+///     let registers: soc::Real<1> = unsafe { soc::Real::new(mmio) };
+///     arm(registers.timer1(), 7);
+///     assert_eq!(memory, [0, 0, 0, 1, 7]);
+///
+///     let fake: timer::Fake<3> = timer::Fake::new();
+///     assert_eq!(fake.compare[2].value(), 0xffff_ffff);
+///     arm(&fake, 5);
+///     assert_eq!(fake.compare[2].value(), 5);
+/// }
+/// ```
+/// The generated items take the generic parameters before their other parameters:
+/// `Interface<CHANNELS>`, `Real<CHANNELS, B>`, and `Fake<'h, CHANNELS>`. Because a block's offsets
+/// can depend on its generic parameters, they are checked when a `Real` is constructed rather than
+/// when the macro is expanded. Generic parameters can be used in [constant
+/// expressions](#constant-expressions), except that the array lengths of a block with a `Fake` must
+/// be literals, constants, or a single generic parameter (as Rust does not yet allow `Fake` to
+/// store arrays whose lengths are computed from generic parameters).
 ///
 /// # Visibility
 /// You can specify the visibility of the generated modules:
@@ -212,6 +657,46 @@
 /// mmio32_register_map![button_array: [button; 8]];   // mod button2 { ... }
 /// ```
 ///
+/// Register block fields can be marked `pub(crate)` to restrict access to the crate that defines
+/// the register block. `pub(crate)` fields are not part of the block's `Interface` trait; instead,
+/// they are accessed through a `pub(crate)` `RestrictedInterface` trait (which extends
+/// `Interface`). The fields of `Fake` and the generated register types have the same visibility as
+/// their field. Fields can also be marked `pub`, which is the default.
+/// ```
+/// # fn main() { unlock_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::{mmio32_register_map, Mmio32, Read, Write};
+/// mmio32_register_map! {
+///     #![fake]
+///     pub flash {
+///         0 => status: u32 { Read },
+///         #[reset(0xffff_ffff)] pub(crate) 4 => unlock: u32 { Write },
+///         #[aliased] pub(crate) 4 => locked: u32 { Read },
+///         pub 8 => data: u32 { Read, Write },
+///     }
+/// }
+///
+/// // Only code in this crate can unlock the flash.
+/// fn unlock<F: flash::RestrictedInterface>(registers: F) -> u32 {
+///     registers.unlock().set(0x4567_0123);
+///     registers.unlock().set(0xcdef_89ab);
+///     registers.locked().get()
+/// }
+///
+/// fn unlock_test() {
+///     let mut memory = [0u32; 3];
+///     let mmio = Mmio32::new(NonNull::from(&mut memory).cast());
+///     // Safety: `memory` has the layout of the `flash` register block and is only accessed
+///     // through `registers`.
+///     let registers = unsafe { flash::Real::new(mmio) };
+///     assert_eq!(unlock(registers), 0xcdef_89ab);
+///     let fake = flash::Fake::new();
+///     assert_eq!(fake.unlock.value(), 0xffff_ffff);
+///     unlock(&fake);
+///     assert_eq!(fake.unlock.value(), 0xcdef_89ab);
+/// }
+/// ```
+///
 /// # Specifying multiple buses
 /// There are some peripherals (such as LiteX peripherals) that support multiple bus types. For
 /// those peripherals, you can specify multiple buses. For example (using Mmio32 and Mmio64 because
@@ -266,6 +751,72 @@
 /// }
 /// ```
 ///
+/// # Layout metadata
+/// `register_map!` implements [`LayoutInfo`](crate::layout::LayoutInfo) for every generated
+/// `Real` type. The layout lists each field's name, offset, size, and operations, as well as the
+/// first paragraph of its doc comment. `pub(crate)` fields are not included, as they are private to
+/// the crate that defines the register block. See the [`layout`](crate::layout) module for an
+/// example.
+///
+/// # Debug dumps
+/// Each register block's module has a `debug_dump` function, which takes any implementation of the
+/// block's `Interface` and returns a value whose `Debug` implementation reads and prints every
+/// readable register in the block. Register arrays are printed as lists, nested register blocks
+/// are printed recursively, and bitfield registers are decoded using
+/// [`RegisterDebugInfo`](crate::debug::RegisterDebugInfo). Registers of other data types are
+/// printed as their raw value. Whether a register is read depends on the traits its type
+/// implements: registers that do not implement [`Read`] are skipped, as are registers that
+/// implement [`ReadDestructive`](trait@crate::ReadDestructive) (whose reads have side effects):
+/// ```
+/// # fn main() { dump_test() }
+/// use tock_registers::{register_bitfields, register_map, DataType, Mmio32};
+/// use tock_registers::{Read, ReadDestructive, Write};
+/// register_bitfields![u8,
+///     Mode [ENABLE OFFSET(0) NUMBITS(1) [], SPEED OFFSET(1) NUMBITS(2) [Slow = 0, Fast = 1]],
+/// ];
+///
+/// /// A data type without bitfield debug information.
+/// pub struct Count;
+/// impl DataType for Count {
+///     type Value = u16;
+///     type LongName = ();
+/// }
+///
+/// register_map! {
+///     #![bus(Mmio32)]
+///     #![fake]
+///     uart {
+///         0 => ctrl: u8 { Read, Write } [ENABLE OFFSET(0), PARITY OFFSET(1)],
+///         1 => rx: u8 { ReadDestructive },
+///         2 => baud: u16 { Read, Write },
+///         4 => mode: Mode::Register { Read, Write },
+///         5 => tx: u8 { Write },
+///         6 => count: Count { Read },
+///         8 => fifo: [u8; 2] { Read },
+///     },
+///     soc {
+///         0 => uarts: [uart; 2],
+///     },
+/// }
+///
+/// fn dump_test() {
+///     use soc::Interface as _;
+///     use uart::Interface as _;
+///     let fake = soc::Fake::new();
+///     let uart = (&fake).uarts().get(0).unwrap();
+///     uart.baud().set(0x1c2);
+///     uart.mode().set(0b011);
+///     assert_eq!(
+///         format!("{:x?}", uart::debug_dump(uart)),
+///         "uart { ctrl: ctrl { ENABLE: 0, PARITY: 0 }, baud: 1c2, \
+///          mode: Mode { ENABLE: 1, SPEED: Fast }, count: 0, fifo: [0, 0] }"
+///     );
+///     let dump = format!("{:?}", soc::debug_dump(&fake));
+///     assert!(dump.starts_with("soc { uarts: [uart { ctrl: ctrl { ENABLE: 0, PARITY: 0 }, "));
+///     assert_eq!(dump.matches("count: 0").count(), 2);
+/// }
+/// ```
+///
 /// # Multiple declarations
 ///
 /// Instead of writing multiple `register_map!` invocations, you can instead put multiple
@@ -122,10 +122,14 @@ macro_rules! bus_impls {
         /// Safety: All the bus_impls! invocations have the correct size.
         unsafe impl<$($generics)*> Bus<$value> for $nonnull {
             const PADDED_SIZE: usize = $size;
+            // MMIO registers are naturally aligned. This is at least the alignment that volatile
+            // accesses require on the targets that use this bus.
+            const ALIGNMENT: usize = $size;
         }
         /// Safety: All the bus_impls! invocations have the correct size.
         unsafe impl<$($generics)*> Bus<$value> for $nullable {
             const PADDED_SIZE: usize = $size;
+            const ALIGNMENT: usize = $size;
         }
         impl<$($generics)*> BusRead<$value> for $nonnull {
             unsafe fn read(self) -> $value {
@@ -0,0 +1,358 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+//! x86 port IO bus.
+//!
+//! [`PortIo`] is generic over the [`PortSpace`] it accesses. On x86, the default port space,
+//! [`X86Ports`], uses the `in` and `out` instructions. [`FakePortSpace`] stores port values in
+//! memory, so that register definitions can be shared between real hardware and host tests by
+//! listing both buses:
+//!
+//! ```
+//! # fn main() {}
+//! # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+//! # mod cfg {
+//! use tock_registers::{register_map, FakePortSpace, PortIo, Read, Write};
+//! register_map! {
+//!     #![buses(PortIo, PortIo<&FakePortSpace<8>>)]
+//!     uart16550 {
+//!         0 => data: u8 { Read, Write },
+//!         1 => interrupt_enable: u8 { Read, Write },
+//!         2 => _: 6,
+//!     }
+//! }
+//! # }
+//! ```
+
+use crate::{Address, Bus, BusRead, BusWrite};
+use core::cell::Cell;
+
+/// A port address space that [`PortIo`] can access.
+pub trait PortSpace: Copy {
+    /// Reads a byte from `port`.
+    /// # Safety
+    /// There must be a readable port at `port`, and the caller must uphold any safety invariants
+    /// of reading it.
+    unsafe fn in_u8(self, port: u16) -> u8;
+    /// Reads a 16-bit value from `port`.
+    /// # Safety
+    /// There must be a readable port at `port`, and the caller must uphold any safety invariants
+    /// of reading it.
+    unsafe fn in_u16(self, port: u16) -> u16;
+    /// Reads a 32-bit value from `port`.
+    /// # Safety
+    /// There must be a readable port at `port`, and the caller must uphold any safety invariants
+    /// of reading it.
+    unsafe fn in_u32(self, port: u16) -> u32;
+    /// Writes a byte to `port`.
+    /// # Safety
+    /// There must be a writable port at `port`, and the caller must uphold any safety invariants
+    /// of writing it.
+    unsafe fn out_u8(self, port: u16, value: u8);
+    /// Writes a 16-bit value to `port`.
+    /// # Safety
+    /// There must be a writable port at `port`, and the caller must uphold any safety invariants
+    /// of writing it.
+    unsafe fn out_u16(self, port: u16, value: u16);
+    /// Writes a 32-bit value to `port`.
+    /// # Safety
+    /// There must be a writable port at `port`, and the caller must uphold any safety invariants
+    /// of writing it.
+    unsafe fn out_u32(self, port: u16, value: u32);
+}
+
+/// The x86 IO port space, accessed using the `in` and `out` instructions. This only implements
+/// [`PortSpace`] when compiling for x86 or x86_64.
+#[derive(Clone, Copy)]
+pub struct X86Ports;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl PortSpace for X86Ports {
+    unsafe fn in_u8(self, port: u16) -> u8 {
+        let value;
+        // Safety: The caller guaranteed that there is a readable port at `port`.
+        unsafe {
+            core::arch::asm!("in al, dx", out("al") value, in("dx") port,
+                             options(nomem, nostack, preserves_flags));
+        }
+        value
+    }
+    unsafe fn in_u16(self, port: u16) -> u16 {
+        let value;
+        // Safety: The caller guaranteed that there is a readable port at `port`.
+        unsafe {
+            core::arch::asm!("in ax, dx", out("ax") value, in("dx") port,
+                             options(nomem, nostack, preserves_flags));
+        }
+        value
+    }
+    unsafe fn in_u32(self, port: u16) -> u32 {
+        let value;
+        // Safety: The caller guaranteed that there is a readable port at `port`.
+        unsafe {
+            core::arch::asm!("in eax, dx", out("eax") value, in("dx") port,
+                             options(nomem, nostack, preserves_flags));
+        }
+        value
+    }
+    unsafe fn out_u8(self, port: u16, value: u8) {
+        // Safety: The caller guaranteed that there is a writable port at `port`.
+        unsafe {
+            core::arch::asm!("out dx, al", in("dx") port, in("al") value,
+                             options(nomem, nostack, preserves_flags));
+        }
+    }
+    unsafe fn out_u16(self, port: u16, value: u16) {
+        // Safety: The caller guaranteed that there is a writable port at `port`.
+        unsafe {
+            core::arch::asm!("out dx, ax", in("dx") port, in("ax") value,
+                             options(nomem, nostack, preserves_flags));
+        }
+    }
+    unsafe fn out_u32(self, port: u16, value: u32) {
+        // Safety: The caller guaranteed that there is a writable port at `port`.
+        unsafe {
+            core::arch::asm!("out dx, eax", in("dx") port, in("eax") value,
+                             options(nomem, nostack, preserves_flags));
+        }
+    }
+}
+
+/// An in-memory port space for host tests, covering `N` ports starting at a base port.
+///
+/// Multi-byte accesses are little-endian (as on x86). Accesses outside the covered ports panic.
+/// `&FakePortSpace` implements [`PortSpace`].
+///
+/// ```
+/// # fn main() { pit_test() }
+/// use tock_registers::{register_map, FakePortSpace, PortIo, Read, Write};
+/// register_map! {
+///     #[buses(PortIo<&FakePortSpace<8>>)]
+///     pit {
+///         0 => counter0: u8 { Read, Write },
+///         1 => _: 2,
+///         3 => mode: u8 { Write },
+///         4 => wide: u32 { Read, Write },
+///     }
+/// }
+///
+/// fn pit_test() {
+///     use pit::Interface;
+///     let ports = FakePortSpace::<8>::new(0x40);
+///     // Safety: `ports` contains the PIT's registers and is only accessed through `registers`.
+///     let registers = unsafe { pit::Real::new(ports.port(0x40)) };
+///     registers.mode().set(0x34);
+///     registers.wide().set(0x1234_5678);
+///     assert_eq!(ports.get(0x43), 0x34);
+///     assert_eq!(ports.get(0x44), 0x78);
+///     assert_eq!(ports.get(0x47), 0x12);
+///     assert_eq!(registers.wide().get(), 0x1234_5678);
+/// }
+/// ```
+///
+/// Accesses that extend past the end of the port space panic as well:
+/// ```should_panic
+/// use tock_registers::{FakePortSpace, PortSpace};
+/// let ports = FakePortSpace::<1>::new(0xFFFF);
+/// // Safety: FakePortSpace has no safety invariants.
+/// unsafe { (&ports).out_u16(0xFFFF, 1) };
+/// ```
+pub struct FakePortSpace<const N: usize> {
+    base: u16,
+    bytes: [Cell<u8>; N],
+}
+
+impl<const N: usize> FakePortSpace<N> {
+    /// Constructs a new `FakePortSpace` covering ports `base..base + N`, all containing 0.
+    pub fn new(base: u16) -> Self {
+        Self {
+            base,
+            bytes: core::array::from_fn(|_| Cell::new(0)),
+        }
+    }
+
+    /// Returns a `PortIo` bus pointing at `port` in this port space.
+    pub fn port(&self, port: u16) -> PortIo<&Self> {
+        PortIo::with_space(self, port)
+    }
+
+    /// Returns the byte stored at `port`.
+    /// # Panics
+    /// Panics if `port` is not covered by this port space.
+    #[track_caller]
+    pub fn get(&self, port: u16) -> u8 {
+        self.cell(port).get()
+    }
+
+    /// Stores `value` at `port`.
+    /// # Panics
+    /// Panics if `port` is not covered by this port space.
+    #[track_caller]
+    pub fn set(&self, port: u16, value: u8) {
+        self.cell(port).set(value)
+    }
+
+    #[track_caller]
+    fn cell(&self, port: u16) -> &Cell<u8> {
+        let Some(cell) = port
+            .checked_sub(self.base)
+            .and_then(|index| self.bytes.get(usize::from(index)))
+        else {
+            panic!("port {port:#x} is outside the FakePortSpace");
+        };
+        cell
+    }
+
+    /// Returns the port `i` bytes after `port`, which is accessed by multi-byte accesses.
+    /// # Panics
+    /// Panics if that port is past the end of the 16-bit port space.
+    #[track_caller]
+    fn byte_port(port: u16, i: usize) -> u16 {
+        let Some(byte_port) = port.checked_add(i as u16) else {
+            panic!(
+                "port {:#x} is outside the FakePortSpace",
+                usize::from(port) + i
+            );
+        };
+        byte_port
+    }
+
+    #[track_caller]
+    fn read_le<const B: usize>(&self, port: u16) -> [u8; B] {
+        core::array::from_fn(|i| self.get(Self::byte_port(port, i)))
+    }
+
+    #[track_caller]
+    fn write_le(&self, port: u16, bytes: &[u8]) {
+        for (i, &byte) in bytes.iter().enumerate() {
+            self.set(Self::byte_port(port, i), byte);
+        }
+    }
+}
+
+impl<const N: usize> PortSpace for &FakePortSpace<N> {
+    unsafe fn in_u8(self, port: u16) -> u8 {
+        self.get(port)
+    }
+    unsafe fn in_u16(self, port: u16) -> u16 {
+        u16::from_le_bytes(self.read_le(port))
+    }
+    unsafe fn in_u32(self, port: u16) -> u32 {
+        u32::from_le_bytes(self.read_le(port))
+    }
+    unsafe fn out_u8(self, port: u16, value: u8) {
+        self.set(port, value)
+    }
+    unsafe fn out_u16(self, port: u16, value: u16) {
+        self.write_le(port, &value.to_le_bytes())
+    }
+    unsafe fn out_u32(self, port: u16, value: u32) {
+        self.write_le(port, &value.to_le_bytes())
+    }
+}
+
+/// Port IO register bus. Register offsets on this bus are port numbers, so a block's offsets are
+/// added to the base port it is constructed with.
+///
+/// `PortIo` can only access ports if `S` implements [`PortSpace`], so the default `X86Ports` space
+/// is only usable when compiling for x86 or x86_64.
+///
+/// Register offsets that extend past port 0xFFFF panic:
+/// ```should_panic
+/// # fn main() { overflow() }
+/// use tock_registers::{register_map, FakePortSpace, PortIo, Read};
+/// register_map! {
+///     #[buses(PortIo<&FakePortSpace<1>>)]
+///     status {
+///         0 => _: 1,
+///         1 => value: u8 { Read },
+///     }
+/// }
+///
+/// fn overflow() {
+///     use status::Interface;
+///     let ports = FakePortSpace::<1>::new(0xFFFF);
+///     // Safety: This panics before any port is accessed.
+///     let registers = unsafe { status::Real::new(ports.port(0xFFFF)) };
+///     registers.value();
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct PortIo<S = X86Ports> {
+    space: S,
+    port: u16,
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl PortIo {
+    /// Returns a new port IO address pointing at `port` in the x86 IO port space.
+    pub const fn new(port: u16) -> Self {
+        Self {
+            space: X86Ports,
+            port,
+        }
+    }
+}
+
+impl<S: PortSpace> PortIo<S> {
+    /// Returns a new port IO address pointing at `port` in the given port space.
+    pub const fn with_space(space: S, port: u16) -> Self {
+        Self { space, port }
+    }
+
+    /// Returns the port number this address points at.
+    pub const fn port(self) -> u16 {
+        self.port
+    }
+}
+
+impl<S: PortSpace> Address for PortIo<S> {
+    unsafe fn byte_add(self, offset: usize) -> Self {
+        // The safety requirements of Address::byte_add require self + offset to remain within
+        // this register span, so an address past the 16-bit port space means the register block
+        // was constructed with an incorrect base port.
+        let Some(port) = usize::from(self.port)
+            .checked_add(offset)
+            .and_then(|port| u16::try_from(port).ok())
+        else {
+            panic!("port {:#x} + {offset:#x} is past port 0xffff", self.port);
+        };
+        Self {
+            space: self.space,
+            port,
+        }
+    }
+}
+
+/// Macro to implement the Bus traits for PortIo.
+macro_rules! port_impls {
+    [$value:ty, $size:literal, $in:ident, $out:ident] => {
+        /// Safety: All the port_impls! invocations have the correct size.
+        unsafe impl<S: PortSpace> Bus<$value> for PortIo<S> {
+            const PADDED_SIZE: usize = $size;
+            // x86 port accesses do not need to be aligned.
+            const ALIGNMENT: usize = 1;
+        }
+        impl<S: PortSpace> BusRead<$value> for PortIo<S> {
+            unsafe fn read(self) -> $value {
+                // BusRead::read's preconditions guarantee that a readable register with value type
+                // $value exists at self.port, and the caller is responsible for avoiding data
+                // races and satisfying any other unsafe invariants of the register.
+                unsafe { self.space.$in(self.port) }
+            }
+        }
+        impl<S: PortSpace> BusWrite<$value> for PortIo<S> {
+            unsafe fn write(self, value: $value) {
+                // BusWrite::write's preconditions guarantee that a writable register with value
+                // type $value exists at self.port, and the caller is responsible for avoiding data
+                // races and satisfying any other unsafe invariants of the register.
+                unsafe { self.space.$out(self.port, value) }
+            }
+        }
+    }
+}
+
+port_impls!(u8, 1, in_u8, out_u8);
+port_impls!(u16, 2, in_u16, out_u16);
+port_impls!(u32, 4, in_u32, out_u32);
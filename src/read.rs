@@ -144,6 +144,143 @@ pub trait Read: Register {
             .any(|field| value & field.mask() == field.value)
     }
 
+    /// Poll the register until all specified parts of `field` match, reading the register at most
+    /// `max_iters` times (but at least once).
+    ///
+    /// Returns `Ok` with the matching value, or `Err` with the last value read if the register
+    /// did not match within `max_iters` reads.
+    ///
+    /// ```
+    /// # fn main() { wait_test() }
+    /// use core::cell::Cell;
+    /// use tock_registers::{mmio32_register_map, register_bitfields, Read};
+    /// register_bitfields![u8,
+    ///     Status [
+    ///         TXCOMPLETE OFFSET(0) NUMBITS(1) [],
+    ///         MODE OFFSET(4) NUMBITS(2) [Idle = 0, Busy = 1],
+    ///     ],
+    /// ];
+    /// mmio32_register_map! {
+    ///     #[fake]
+    ///     status: Status::Register { Read },
+    /// }
+    ///
+    /// fn wait_test() {
+    ///     let reads = Cell::new(0);
+    ///     // Transmission completes on the third read.
+    ///     let status_hook = || {
+    ///         reads.set(reads.get() + 1);
+    ///         match reads.get() {
+    ///             3.. => 0b1,
+    ///             _ => 0b10000,
+    ///         }
+    ///     };
+    ///     let status = status::Fake::new();
+    ///     status.on_read(&status_hook);
+    ///     let done = Status::TXCOMPLETE::SET + Status::MODE::Idle;
+    ///     assert!((&status).wait_until(done, 5).is_ok());
+    ///     assert_eq!(reads.get(), 3);
+    ///     // The register is read once even if max_iters is 0.
+    ///     reads.set(0);
+    ///     assert_eq!((&status).wait_until(done, 0).unwrap_err().get(), 0b10000);
+    ///     assert_eq!(reads.get(), 1);
+    /// }
+    /// ```
+    fn wait_until(
+        self,
+        field: FieldValue<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >,
+        max_iters: usize,
+    ) -> WaitResult<Self>
+    where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        let mut remaining = max_iters;
+        self.wait_until_with(
+            |value| field.matches_all(value.get()),
+            || {
+                remaining = remaining.saturating_sub(1);
+                remaining == 0
+            },
+        )
+    }
+
+    /// Poll the register until one or more bits in `field` are set, reading the register at most
+    /// `max_iters` times (but at least once).
+    ///
+    /// Returns `Ok` with the matching value, or `Err` with the last value read if no bits of
+    /// `field` were set within `max_iters` reads.
+    fn wait_until_set(
+        self,
+        field: Field<<Self::DataType as DataType>::Value, <Self::DataType as DataType>::LongName>,
+        max_iters: usize,
+    ) -> WaitResult<Self>
+    where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        let mut remaining = max_iters;
+        self.wait_until_with(
+            |value| field.is_set(value.get()),
+            || {
+                remaining = remaining.saturating_sub(1);
+                remaining == 0
+            },
+        )
+    }
+
+    /// Poll the register until `done` returns true for the value read.
+    ///
+    /// After each read for which `done` returns false, `timed_out` is called; if it returns true,
+    /// polling stops. This allows the caller to implement timeouts based on a timer, an iteration
+    /// count, or anything else. Returns `Ok` with the value for which `done` returned true, or
+    /// `Err` with the last value read if polling timed out.
+    ///
+    /// ```
+    /// # fn main() { poll_test() }
+    /// use tock_registers::{mmio32_register_map, Read};
+    /// mmio32_register_map! {
+    ///     #[fake]
+    ///     #[reset(3)]
+    ///     data: u8 { Read },
+    /// }
+    ///
+    /// fn poll_test() {
+    ///     let register = data::Fake::new();
+    ///     let mut polls = 0;
+    ///     let result = (&register).wait_until_with(
+    ///         |value| value.get() == 4,
+    ///         || {
+    ///             polls += 1;
+    ///             polls == 10
+    ///         },
+    ///     );
+    ///     assert_eq!(result.unwrap_err().get(), 3);
+    ///     assert_eq!(polls, 10);
+    /// }
+    /// ```
+    fn wait_until_with(
+        self,
+        mut done: impl FnMut(
+            &LocalRegisterCopy<
+                <Self::DataType as DataType>::Value,
+                <Self::DataType as DataType>::LongName,
+            >,
+        ) -> bool,
+        mut timed_out: impl FnMut() -> bool,
+    ) -> WaitResult<Self> {
+        loop {
+            let value = self.extract();
+            if done(&value) {
+                return Ok(value);
+            }
+            if timed_out() {
+                return Err(value);
+            }
+        }
+    }
+
     /// Returns a [`RegisterDebugValue`] that implements [`core::fmt::Debug`]. The debug
     /// information is extracted from `<Register>::DebugInfo`.
     fn debug(
@@ -164,6 +301,21 @@ pub trait Read: Register {
     }
 }
 
+/// The result of polling a register with [`Read::wait_until`] and similar methods.
+///
+/// `Ok` contains the value that satisfied the condition, and `Err`
+/// contains the last value read before polling timed out.
+pub type WaitResult<R> = Result<
+    LocalRegisterCopy<
+        <<R as Register>::DataType as DataType>::Value,
+        <<R as Register>::DataType as DataType>::LongName,
+    >,
+    LocalRegisterCopy<
+        <<R as Register>::DataType as DataType>::Value,
+        <<R as Register>::DataType as DataType>::LongName,
+    >,
+>;
+
 /// A Bus that implements `BusRead<T>` can support Read implementations with DataType T. Other
 /// crates (e.g. LiteX registers) can implement this on their own buses so that Read works with
 /// them as well.
@@ -205,6 +357,15 @@ macro_rules! Read {
             }
         }
     };
+    // Implements the trait for a fake register generated by register_map!'s #[fake] attribute.
+    // The fake register is a newtype around a FakeCell.
+    (fake_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl $crate::Read for &$name<'_> {
+            fn get(self) -> <$datatype as $crate::DataType>::Value {
+                $crate::internal::fake::read(&self.0)
+            }
+        }
+    };
     // Catch-all case that emits nothing if register_map! invokes it with an unknown first
     // argument. This is so that we can add new functionality into the operations macros without
     // breaking backwards compatibility (though registers! would need to be compatible with this
@@ -0,0 +1,90 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::{DataType, LocalRegisterCopy, Register};
+
+/// A register whose reads have side effects, such as a read-to-clear status register or a FIFO
+/// data register that pops on read.
+///
+/// Unlike [`Read`](trait@crate::Read), which offers many methods that each read the register,
+/// `ReadDestructive` only offers [`take`](ReadDestructive::take), which reads the register once
+/// and returns a [`LocalRegisterCopy`]. Fields can then be inspected on the copy without reading
+/// the register again.
+///
+/// ```
+/// # fn main() { rx_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::{mmio32_register_map, register_bitfields, Mmio32, ReadDestructive};
+/// register_bitfields![u32,
+///     RxFifo [
+///         DATA OFFSET(0) NUMBITS(8) [],
+///         VALID OFFSET(31) NUMBITS(1) [],
+///     ],
+/// ];
+/// mmio32_register_map![rx: RxFifo::Register { ReadDestructive }];
+///
+/// fn rx_test() {
+///     let mut memory = 0x8000_0041u32;
+///     // Safety: `memory` has the layout of an `rx` register and is only accessed through `rx`.
+///     let rx = unsafe { rx::Real::new(Mmio32::new(NonNull::from(&mut memory).cast())) };
+///     let entry = rx.take();
+///     assert!(entry.is_set(RxFifo::VALID));
+///     assert_eq!(entry.read(RxFifo::DATA), 0x41);
+/// }
+/// ```
+pub trait ReadDestructive: Register {
+    /// Read the register, returning a local copy of its value.
+    fn take(
+        self,
+    ) -> LocalRegisterCopy<
+        <Self::DataType as DataType>::Value,
+        <Self::DataType as DataType>::LongName,
+    >;
+}
+
+/// The macro that goes along with the ReadDestructive trait. We don't expect this macro to be used
+/// by tock_register's users, instead it is invoked by the generated code.
+#[cfg(feature = "register_types")]
+#[macro_export]
+macro_rules! ReadDestructive {
+    // Provides a real implementation of the trait. The trailing $rest argument is for future
+    // compatibility: it allows the procedural macro to pass additional arguments in the future
+    // without breaking compatibility with this implementation of ReadDestructive!.
+    (real_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl<B: Bus + $crate::BusRead<<$datatype as $crate::DataType>::Value>>
+            $crate::ReadDestructive for $name<B>
+        {
+            fn take(
+                self,
+            ) -> $crate::LocalRegisterCopy<
+                <$datatype as $crate::DataType>::Value,
+                <$datatype as $crate::DataType>::LongName,
+            > {
+                // Safety: The caller assured this register accessor points at a register on bus B
+                // with value type $datatype::Value that is safe to read. The code that constructed
+                // `self` guaranteed that they would avoid data races (precondition of Self::new).
+                $crate::LocalRegisterCopy::new(unsafe { self.address.read() })
+            }
+        }
+    };
+    // Implements the trait for a fake register generated by register_map!'s #[fake] attribute.
+    // The fake register is a newtype around a FakeCell.
+    (fake_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl $crate::ReadDestructive for &$name<'_> {
+            fn take(
+                self,
+            ) -> $crate::LocalRegisterCopy<
+                <$datatype as $crate::DataType>::Value,
+                <$datatype as $crate::DataType>::LongName,
+            > {
+                $crate::LocalRegisterCopy::new($crate::internal::fake::read(&self.0))
+            }
+        }
+    };
+    // Catch-all case that emits nothing if register_map! invokes it with an unknown first
+    // argument. This is so that we can add new functionality into the operations macros without
+    // breaking backwards compatibility (though registers! would need to be compatible with this
+    // do-nothing block).
+    ($($unknown:tt)*) => {};
+}
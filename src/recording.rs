@@ -0,0 +1,311 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+//! Buses for golden-trace tests of drivers.
+//!
+//! [`RecordingBus`] backs an address space with an in-memory sparse map and records every access
+//! in a [`Recording`]. [`ReplayBus`] checks that a driver performs exactly the accesses in a
+//! previous recording, returning the recorded values for reads. Both buses support `u8` through
+//! `u128` and `usize` registers, so any register block with one of these buses in its
+//! `#[buses(...)]` list can run against them.
+//!
+//! Multi-byte values are stored in the sparse map in little-endian order.
+//!
+//! The buses borrow their `Recording` or `Replay`, so they are listed as `RecordingBus<'_>` and
+//! `ReplayBus<'_>` in `#[buses(...)]`, which lets tests use local recordings. `#[bus(...)]` uses
+//! its bus as the default type of `Real`'s bus parameter, where `'_` is not allowed, so a single
+//! bus must be given a named lifetime (such as `'static`) instead.
+//!
+//! ```
+//! # fn main() { replay_test() }
+//! use tock_registers::{register_map, AccessKind, Read, RecordedAccess, Recording, RecordingBus};
+//! use tock_registers::{Replay, ReplayBus, Write};
+//! register_map! {
+//!     #[buses(RecordingBus<'_>, ReplayBus<'_>)]
+//!     timer {
+//!         0 => ctrl: u8 { Read, Write },
+//!         1 => _: 3,
+//!         4 => count: u32 { Read, Write },
+//!     }
+//! }
+//!
+//! fn start<T: timer::Interface>(registers: T, count: u32) {
+//!     registers.count().set(count);
+//!     registers.ctrl().set(registers.ctrl().get() | 1);
+//! }
+//!
+//! fn replay_test() {
+//!     let recording = Recording::new();
+//!     recording.set_byte(0x1000, 0x80);
+//!     // Safety: RecordingBus does not access real memory.
+//!     start(unsafe { timer::Real::new(recording.bus(0x1000)) }, 1000);
+//!     assert_eq!(recording.byte(0x1000), 0x81);
+//!     assert_eq!(recording.byte(0x1004), 1000u32.to_le_bytes()[0]);
+//!     let log = recording.take_log();
+//!     use AccessKind::{Read, Write};
+//!     assert_eq!(log, [
+//!         RecordedAccess { kind: Write, address: 0x1004, size: 4, value: 1000 },
+//!         RecordedAccess { kind: Read, address: 0x1000, size: 1, value: 0x80 },
+//!         RecordedAccess { kind: Write, address: 0x1000, size: 1, value: 0x81 },
+//!     ]);
+//!
+//!     // Replaying the log checks that the driver performs the same accesses.
+//!     let replay = Replay::new(log);
+//!     // Safety: ReplayBus does not access real memory.
+//!     start(unsafe { timer::Real::new(replay.bus(0x1000)) }, 1000);
+//!     replay.assert_finished();
+//! }
+//! ```
+
+use crate::{AccessKind, Address, Bus, BusRead, BusWrite};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+
+/// A register access recorded by [`RecordingBus`] or expected by [`ReplayBus`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecordedAccess {
+    /// Whether the register was read or written.
+    pub kind: AccessKind,
+    /// The accessed register's address on the bus.
+    pub address: usize,
+    /// The size of the accessed value, in bytes.
+    pub size: usize,
+    /// The value that was read or written.
+    pub value: u128,
+}
+
+/// The memory and access log used by [`RecordingBus`].
+#[derive(Default)]
+pub struct Recording {
+    memory: RefCell<BTreeMap<usize, u8>>,
+    log: RefCell<Vec<RecordedAccess>>,
+}
+
+impl Recording {
+    /// Constructs a new `Recording` with an empty log, in which every byte of memory is 0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a `RecordingBus` pointing at `address` in this recording's memory.
+    pub fn bus(&self, address: usize) -> RecordingBus<'_> {
+        RecordingBus {
+            recording: self,
+            address,
+        }
+    }
+
+    /// Returns the byte at `address`, without recording an access.
+    pub fn byte(&self, address: usize) -> u8 {
+        self.memory.borrow().get(&address).copied().unwrap_or(0)
+    }
+
+    /// Stores `value` at `address`, without recording an access. This can be used to set up the
+    /// values that the driver will read.
+    pub fn set_byte(&self, address: usize, value: u8) {
+        self.memory.borrow_mut().insert(address, value);
+    }
+
+    /// Returns the accesses recorded so far.
+    pub fn log(&self) -> Vec<RecordedAccess> {
+        self.log.borrow().clone()
+    }
+
+    /// Removes and returns the accesses recorded so far.
+    pub fn take_log(&self) -> Vec<RecordedAccess> {
+        self.log.take()
+    }
+
+    fn load(&self, address: usize, size: usize) -> u128 {
+        (0..size).rev().fold(0, |value, i| {
+            value << 8 | u128::from(self.byte(offset_address(address, i)))
+        })
+    }
+
+    fn store(&self, address: usize, size: usize, value: u128) {
+        for i in 0..size {
+            self.set_byte(offset_address(address, i), (value >> (8 * i)) as u8);
+        }
+    }
+}
+
+/// Returns `address + offset`.
+/// # Panics
+/// Panics if the result overflows `usize`.
+#[track_caller]
+fn offset_address(address: usize, offset: usize) -> usize {
+    let Some(result) = address.checked_add(offset) else {
+        panic!("address {address:#x} + {offset:#x} overflows usize");
+    };
+    result
+}
+
+/// A bus that backs registers with the memory of a [`Recording`] and records each access.
+/// # Panics
+/// Accessing a register whose bytes extend past `usize::MAX` panics:
+/// ```should_panic
+/// # fn main() { overflow() }
+/// use tock_registers::{register_map, Read, Recording, RecordingBus};
+/// register_map! {
+///     #[buses(RecordingBus<'_>)]
+///     status {
+///         0 => _: 2,
+///         2 => value: u8 { Read },
+///     }
+/// }
+///
+/// fn overflow() {
+///     use status::Interface;
+///     let recording = Recording::new();
+///     // Safety: RecordingBus does not access real memory.
+///     let registers = unsafe { status::Real::new(recording.bus(usize::MAX - 1)) };
+///     registers.value().get();
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct RecordingBus<'r> {
+    recording: &'r Recording,
+    address: usize,
+}
+
+impl Address for RecordingBus<'_> {
+    unsafe fn byte_add(self, offset: usize) -> Self {
+        Self {
+            recording: self.recording,
+            address: offset_address(self.address, offset),
+        }
+    }
+}
+
+/// The expected accesses used by [`ReplayBus`].
+pub struct Replay {
+    expected: Vec<RecordedAccess>,
+    next: Cell<usize>,
+}
+
+impl Replay {
+    /// Constructs a new `Replay` that expects exactly the accesses in `expected`, in order.
+    pub fn new(expected: Vec<RecordedAccess>) -> Self {
+        Self {
+            expected,
+            next: Cell::new(0),
+        }
+    }
+
+    /// Returns a `ReplayBus` pointing at `address`.
+    pub fn bus(&self, address: usize) -> ReplayBus<'_> {
+        ReplayBus {
+            replay: self,
+            address,
+        }
+    }
+
+    /// Asserts that every expected access has been performed.
+    /// # Panics
+    /// Panics if some of the expected accesses have not been performed.
+    #[track_caller]
+    pub fn assert_finished(&self) {
+        let remaining = &self.expected[self.next.get()..];
+        assert!(
+            remaining.is_empty(),
+            "accesses not performed: {remaining:?}"
+        );
+    }
+
+    /// Checks that the described access is the next expected access, and returns the expected
+    /// access.
+    #[track_caller]
+    fn check(&self, kind: AccessKind, address: usize, size: usize, value: u128) -> RecordedAccess {
+        let index = self.next.get();
+        let Some(&expected) = self.expected.get(index) else {
+            panic!("unexpected {kind:?} of {size} bytes at {address:#x} after the replay finished");
+        };
+        let matches = expected.kind == kind
+            && expected.address == address
+            && expected.size == size
+            && (kind == AccessKind::Read || expected.value == value);
+        assert!(
+            matches,
+            "access {index} mismatch: expected {expected:?}, got {kind:?} of {size} bytes at \
+             {address:#x} (value {value:#x})"
+        );
+        self.next.set(index + 1);
+        expected
+    }
+}
+
+/// A bus that checks accesses against a [`Replay`], returning the recorded values for reads.
+/// # Panics
+/// Accesses panic if they do not match the next access in the replay, and computing an address
+/// past `usize::MAX` panics.
+#[derive(Clone, Copy)]
+pub struct ReplayBus<'r> {
+    replay: &'r Replay,
+    address: usize,
+}
+
+impl Address for ReplayBus<'_> {
+    unsafe fn byte_add(self, offset: usize) -> Self {
+        Self {
+            replay: self.replay,
+            address: offset_address(self.address, offset),
+        }
+    }
+}
+
+/// Macro to implement the Bus traits for RecordingBus and ReplayBus.
+macro_rules! recording_impls {
+    [$($value:ty)*] => {$(
+        /// Safety: Values are stored in the recording's memory using their native size.
+        unsafe impl Bus<$value> for RecordingBus<'_> {
+            const PADDED_SIZE: usize = size_of::<$value>();
+        }
+        impl BusRead<$value> for RecordingBus<'_> {
+            unsafe fn read(self) -> $value {
+                let size = size_of::<$value>();
+                let value = self.recording.load(self.address, size);
+                self.recording.log.borrow_mut().push(RecordedAccess {
+                    kind: AccessKind::Read,
+                    address: self.address,
+                    size,
+                    value,
+                });
+                value as $value
+            }
+        }
+        impl BusWrite<$value> for RecordingBus<'_> {
+            unsafe fn write(self, value: $value) {
+                let size = size_of::<$value>();
+                self.recording.store(self.address, size, value as u128);
+                self.recording.log.borrow_mut().push(RecordedAccess {
+                    kind: AccessKind::Write,
+                    address: self.address,
+                    size,
+                    value: value as u128,
+                });
+            }
+        }
+        /// Safety: Replayed values use their native size.
+        unsafe impl Bus<$value> for ReplayBus<'_> {
+            const PADDED_SIZE: usize = size_of::<$value>();
+        }
+        impl BusRead<$value> for ReplayBus<'_> {
+            #[track_caller]
+            unsafe fn read(self) -> $value {
+                let size = size_of::<$value>();
+                self.replay.check(AccessKind::Read, self.address, size, 0).value as $value
+            }
+        }
+        impl BusWrite<$value> for ReplayBus<'_> {
+            #[track_caller]
+            unsafe fn write(self, value: $value) {
+                let size = size_of::<$value>();
+                self.replay.check(AccessKind::Write, self.address, size, value as u128);
+            }
+        }
+    )*}
+}
+
+recording_impls![u8 u16 u32 u64 u128 usize];
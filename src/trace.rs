@@ -0,0 +1,155 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::{Address, Bus, BusRead, BusWrite};
+
+/// Whether a traced register access was a read or a write.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessKind {
+    /// The register was read.
+    Read,
+    /// The register was written.
+    Write,
+}
+
+/// A register access, as reported by [`Traced`] to a [`TraceSink`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Access {
+    /// Whether the register was read or written.
+    pub kind: AccessKind,
+    /// The accessed register's offset (in bytes) from the base of the register block, which is
+    /// the address of the inner bus passed to [`Traced::new`]. This is not the register's absolute
+    /// address, which `Traced` does not know (as not every bus has one).
+    pub block_offset: usize,
+    /// The size of the accessed value, in bytes.
+    pub size: usize,
+    /// The value that was read or written.
+    pub value: u128,
+}
+
+/// Receives the register accesses performed through a [`Traced`] bus. Implementations can log
+/// the accesses (e.g. over a UART or with `defmt`) or record them for later inspection.
+pub trait TraceSink: Copy {
+    /// Called for each register access. Reads are reported after the read is performed, and
+    /// writes are reported before the write is performed.
+    fn record(self, access: Access);
+}
+
+/// A bus wrapper that reports every register access to a [`TraceSink`].
+///
+/// `Traced<B, S>` accesses registers through the bus `B`, so it can be added to the
+/// `#[buses(...)]` list of any register block whose buses include `B` without changing driver
+/// code.
+///
+/// ```
+/// # fn main() { trace_test() }
+/// use core::cell::RefCell;
+/// use core::ptr::NonNull;
+/// use tock_registers::{register_map, Access, AccessKind, Mmio32, Read, TraceSink, Traced, Write};
+///
+/// #[derive(Clone, Copy)]
+/// struct Recorder(&'static RefCell<Vec<Access>>);
+/// impl TraceSink for Recorder {
+///     fn record(self, access: Access) {
+///         self.0.borrow_mut().push(access);
+///     }
+/// }
+///
+/// register_map! {
+///     #[buses(Mmio32, Traced<Mmio32, Recorder>)]
+///     timer {
+///         0 => ctrl: u8 { Read, Write },
+///         1 => _: 3,
+///         4 => count: u32 { Read, Write },
+///     }
+/// }
+///
+/// fn trace_test() {
+///     use timer::Interface;
+///     let accesses = Box::leak(Box::new(RefCell::new(Vec::new())));
+///     let mut memory = [u32::from_ne_bytes([0x80, 0, 0, 0]), 0];
+///     let bus = Traced::new(Mmio32::new(NonNull::from(&mut memory).cast()), Recorder(accesses));
+///     // Safety: `memory` has the layout of the `timer` register block and is only accessed
+///     // through `registers`.
+///     let registers = unsafe { timer::Real::new(bus) };
+///     registers.count().set(1000);
+///     registers.ctrl().set(registers.ctrl().get() | 1);
+///     use AccessKind::{Read, Write};
+///     assert_eq!(*accesses.borrow(), [
+///         Access { kind: Write, block_offset: 4, size: 4, value: 1000 },
+///         Access { kind: Read, block_offset: 0, size: 1, value: 0x80 },
+///         Access { kind: Write, block_offset: 0, size: 1, value: 0x81 },
+///     ]);
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct Traced<B: Address, S: TraceSink> {
+    inner: B,
+    sink: S,
+    block_offset: usize,
+}
+
+impl<B: Address, S: TraceSink> Traced<B, S> {
+    /// Returns a new traced bus that accesses registers through `inner` and reports accesses to
+    /// `sink`. The reported accesses' `block_offset`s are relative to `inner`.
+    pub const fn new(inner: B, sink: S) -> Self {
+        Self {
+            inner,
+            sink,
+            block_offset: 0,
+        }
+    }
+}
+
+impl<B: Address, S: TraceSink> Address for Traced<B, S> {
+    unsafe fn byte_add(self, offset: usize) -> Self {
+        Self {
+            // Safety: Traced<B, S> addresses the same registers as B, so the caller has satisfied
+            // B::byte_add's requirements.
+            inner: unsafe { self.inner.byte_add(offset) },
+            sink: self.sink,
+            block_offset: self.block_offset + offset,
+        }
+    }
+}
+
+/// Macro to implement the Bus traits for Traced.
+macro_rules! traced_impls {
+    [$($value:ty)*] => {$(
+        /// Safety: Tracing does not change the register's size.
+        unsafe impl<B: Bus<$value>, S: TraceSink> Bus<$value> for Traced<B, S> {
+            const PADDED_SIZE: usize = B::PADDED_SIZE;
+            const ALIGNMENT: usize = B::ALIGNMENT;
+        }
+        impl<B: BusRead<$value>, S: TraceSink> BusRead<$value> for Traced<B, S> {
+            unsafe fn read(self) -> $value {
+                // Safety: Traced<B, S> addresses the same registers as B, so the caller has
+                // satisfied B::read's requirements.
+                let value = unsafe { self.inner.read() };
+                self.sink.record(Access {
+                    kind: AccessKind::Read,
+                    block_offset: self.block_offset,
+                    size: size_of::<$value>(),
+                    value: value as u128,
+                });
+                value
+            }
+        }
+        impl<B: BusWrite<$value>, S: TraceSink> BusWrite<$value> for Traced<B, S> {
+            unsafe fn write(self, value: $value) {
+                self.sink.record(Access {
+                    kind: AccessKind::Write,
+                    block_offset: self.block_offset,
+                    size: size_of::<$value>(),
+                    value: value as u128,
+                });
+                // Safety: Traced<B, S> addresses the same registers as B, so the caller has
+                // satisfied B::write's requirements.
+                unsafe { self.inner.write(value) }
+            }
+        }
+    )*}
+}
+
+traced_impls![u8 u16 u32 u64 u128 usize];
@@ -0,0 +1,220 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::debug::{RegisterDebugInfo, RegisterDebugValue};
+use crate::fields::{Field, FieldValue, TryFromValue};
+use crate::{DataType, LocalRegisterCopy, Register, UIntLike};
+use core::marker::PhantomData;
+
+/// A register that can be read, but for which reading has safety invariants.
+///
+/// For example, reading the register may stop a DMA operation. This provides the same methods as
+/// [`Read`](trait@crate::Read), but they are `unsafe`.
+///
+/// # Safety
+/// Each method of this trait performs a read of the register. The caller must uphold the safety
+/// invariants of reading the register, which are hardware-specific and should be documented
+/// alongside the register's definition.
+///
+/// ```
+/// # fn main() { dma_test() }
+/// use core::ptr::NonNull;
+/// use tock_registers::{mmio64_register_map, Mmio64, UnsafeRead, UnsafeWrite};
+/// mmio64_register_map! {
+///     dma {
+///         0 => address: u64 { UnsafeRead, UnsafeWrite },
+///         8 => length: u64 { UnsafeRead, UnsafeWrite },
+///     }
+/// }
+///
+/// fn dma_test() {
+///     use dma::Interface;
+///     let mut memory = [0u64; 2];
+///     // Safety: `memory` has the layout of a `dma` register block and is only accessed through
+///     // `registers`.
+///     let registers = unsafe { dma::Real::new(Mmio64::new(NonNull::from(&mut memory).cast())) };
+///     // Safety: There is no DMA hardware, so these accesses have no side effects.
+///     unsafe {
+///         registers.address().set(0x1000);
+///         registers.length().set(5);
+///         assert_eq!(registers.address().get(), 0x1000);
+///         assert_eq!(registers.length().get(), 5);
+///     }
+/// }
+/// ```
+pub trait UnsafeRead: Register {
+    /// Get the raw register value
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for reads.
+    unsafe fn get(self) -> <Self::DataType as DataType>::Value;
+
+    /// Read the value of the given field
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for reads.
+    unsafe fn read(
+        self,
+        field: Field<<Self::DataType as DataType>::Value, <Self::DataType as DataType>::LongName>,
+    ) -> <Self::DataType as DataType>::Value
+    where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        // Safety: The caller upheld the register's safety invariants for reads.
+        field.read(unsafe { self.get() })
+    }
+
+    /// Read value of the given field as an enum member
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for reads.
+    unsafe fn read_as_enum<E: TryFromValue<<Self::DataType as DataType>::Value, EnumType = E>>(
+        self,
+        field: Field<<Self::DataType as DataType>::Value, <Self::DataType as DataType>::LongName>,
+    ) -> Option<E>
+    where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        // Safety: The caller upheld the register's safety invariants for reads.
+        field.read_as_enum(unsafe { self.get() })
+    }
+
+    /// Make a local copy of the register
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for reads.
+    unsafe fn extract(
+        self,
+    ) -> LocalRegisterCopy<
+        <Self::DataType as DataType>::Value,
+        <Self::DataType as DataType>::LongName,
+    > {
+        // Safety: The caller upheld the register's safety invariants for reads.
+        LocalRegisterCopy::new(unsafe { self.get() })
+    }
+
+    /// Check if one or more bits in a field are set
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for reads.
+    unsafe fn is_set(
+        self,
+        field: Field<<Self::DataType as DataType>::Value, <Self::DataType as DataType>::LongName>,
+    ) -> bool
+    where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        // Safety: The caller upheld the register's safety invariants for reads.
+        field.is_set(unsafe { self.get() })
+    }
+
+    /// Check if any bits corresponding to the mask in the passed `FieldValue`
+    /// are set.
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for reads.
+    unsafe fn any_matching_bits_set(
+        self,
+        field: FieldValue<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >,
+    ) -> bool
+    where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        // Safety: The caller upheld the register's safety invariants for reads.
+        field.any_matching_bits_set(unsafe { self.get() })
+    }
+
+    /// Check if all specified parts of a field match
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for reads.
+    unsafe fn matches_all(
+        self,
+        field: FieldValue<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >,
+    ) -> bool
+    where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        // Safety: The caller upheld the register's safety invariants for reads.
+        field.matches_all(unsafe { self.get() })
+    }
+
+    /// Check if any of the passed parts of a field exactly match the contained
+    /// value.
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for reads.
+    unsafe fn matches_any(
+        self,
+        fields: &[FieldValue<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >],
+    ) -> bool
+    where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        // Safety: The caller upheld the register's safety invariants for reads.
+        let value = unsafe { self.get() };
+        fields
+            .iter()
+            .any(|field| value & field.mask() == field.value)
+    }
+
+    /// Returns a [`RegisterDebugValue`] that implements [`core::fmt::Debug`]. The debug
+    /// information is extracted from `<Register>::DebugInfo`.
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for reads.
+    unsafe fn debug(
+        self,
+    ) -> RegisterDebugValue<
+        <Self::DataType as DataType>::Value,
+        <Self::DataType as DataType>::LongName,
+    >
+    where
+        <Self::DataType as DataType>::Value: UIntLike,
+        <Self::DataType as DataType>::LongName:
+            RegisterDebugInfo<<Self::DataType as DataType>::Value>,
+    {
+        RegisterDebugValue {
+            // Safety: The caller upheld the register's safety invariants for reads.
+            data: unsafe { self.get() },
+            _reg: PhantomData,
+        }
+    }
+}
+
+/// The macro that goes along with the UnsafeRead trait. We don't expect this macro to be used by
+/// tock_register's users, instead it is invoked by the generated code.
+#[macro_export]
+macro_rules! UnsafeRead {
+    // Provides a real implementation of the trait. The trailing $rest argument is for future
+    // compatibility: it allows the procedural macro to pass additional arguments in the future
+    // without breaking compatibility with this implementation of UnsafeRead!.
+    (real_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl<B: Bus + $crate::BusRead<<$datatype as $crate::DataType>::Value>> $crate::UnsafeRead
+            for $name<B>
+        {
+            unsafe fn get(self) -> <$datatype as $crate::DataType>::Value {
+                // Safety: The caller assured this register accessor points at a register on bus B
+                // with value type $datatype::Value that is readable. The caller of this function
+                // upheld the register's safety invariants for reads, and the code that constructed
+                // `self` guaranteed that they would avoid data races (precondition of Self::new).
+                unsafe { self.address.read() }
+            }
+        }
+    };
+    // Implements the trait for a fake register generated by register_map!'s #[fake] attribute.
+    // The fake register is a newtype around a FakeCell.
+    (fake_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl $crate::UnsafeRead for &$name<'_> {
+            unsafe fn get(self) -> <$datatype as $crate::DataType>::Value {
+                $crate::internal::fake::read(&self.0)
+            }
+        }
+    };
+    // Catch-all case that emits nothing if register_map! invokes it with an unknown first
+    // argument. This is so that we can add new functionality into the operations macros without
+    // breaking backwards compatibility (though registers! would need to be compatible with this
+    // do-nothing block).
+    ($($unknown:tt)*) => {};
+}
@@ -0,0 +1,113 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::{fields::FieldValue, DataType, LocalRegisterCopy, Register, UIntLike, UnsafeRead};
+
+/// A register that can be written, but for which writing has safety invariants.
+///
+/// For example, writing a DMA address register allows the hardware to access memory. This provides
+/// the same methods as [`Write`](trait@crate::Write), but they are `unsafe`.
+///
+/// # Safety
+/// Each method of this trait performs a write of the register. The caller must uphold the safety
+/// invariants of writing the register, which are hardware-specific and should be documented
+/// alongside the register's definition.
+pub trait UnsafeWrite: Register {
+    /// Set the raw register value
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for writes.
+    unsafe fn set(self, value: <Self::DataType as DataType>::Value);
+
+    /// Write the value of one or more fields, overwriting the other fields with zero.
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for writes.
+    unsafe fn write(
+        self,
+        field: FieldValue<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >,
+    ) where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        // Safety: The caller upheld the register's safety invariants for writes.
+        unsafe { self.set(field.value) }
+    }
+
+    /// Write the value of one or more fields, leaving the other fields unchanged.
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for both reads and writes.
+    unsafe fn modify(
+        self,
+        field: FieldValue<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >,
+    ) where
+        Self: UnsafeRead,
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        // Safety: The caller upheld the register's safety invariants for reads and writes.
+        unsafe { self.set(field.modify(self.get())) }
+    }
+
+    /// Write the value of one or more fields, maintaining the value of
+    /// unchanged fields via a provided original value, rather than a register
+    /// read.
+    /// # Safety
+    /// The caller must uphold the register's safety invariants for writes.
+    unsafe fn modify_no_read(
+        self,
+        original: LocalRegisterCopy<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >,
+        field: FieldValue<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >,
+    ) where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        // Safety: The caller upheld the register's safety invariants for writes.
+        unsafe { self.set(field.modify(original.get())) }
+    }
+}
+
+/// The macro that goes along with the UnsafeWrite trait. We don't expect this macro to be used by
+/// tock_register's users, instead it is invoked by the generated code.
+#[macro_export]
+macro_rules! UnsafeWrite {
+    // Provides a real implementation of the trait. The trailing $rest argument is for future
+    // compatibility: it allows the procedural macro to pass additional arguments in the future
+    // without breaking compatibility with this implementation of UnsafeWrite!.
+    (real_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl<B: Bus + $crate::BusWrite<<$datatype as $crate::DataType>::Value>> $crate::UnsafeWrite
+            for $name<B>
+        {
+            unsafe fn set(self, value: <$datatype as $crate::DataType>::Value) {
+                // Safety: The caller assured this register accessor points at a register on bus B
+                // with value type $datatype::Value that is writable. The caller of this function
+                // upheld the register's safety invariants for writes, and the code that
+                // constructed `self` guaranteed that they would avoid data races (precondition of
+                // Self::new).
+                unsafe { self.address.write(value) }
+            }
+        }
+    };
+    // Implements the trait for a fake register generated by register_map!'s #[fake] attribute.
+    // The fake register is a newtype around a FakeCell.
+    (fake_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl $crate::UnsafeWrite for &$name<'_> {
+            unsafe fn set(self, value: <$datatype as $crate::DataType>::Value) {
+                $crate::internal::fake::write(&self.0, value)
+            }
+        }
+    };
+    // Catch-all case that emits nothing if register_map! invokes it with an unknown first
+    // argument. This is so that we can add new functionality into the operations traits without
+    // breaking backwards compatibility (though registers! would need to be compatible with this
+    // do-nothing block).
+    ($($unknown:tt)*) => {};
+}
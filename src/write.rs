@@ -101,6 +101,15 @@ macro_rules! Write {
             }
         }
     };
+    // Implements the trait for a fake register generated by register_map!'s #[fake] attribute.
+    // The fake register is a newtype around a FakeCell.
+    (fake_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl $crate::Write for &$name<'_> {
+            fn set(self, value: <$datatype as $crate::DataType>::Value) {
+                $crate::internal::fake::write(&self.0, value)
+            }
+        }
+    };
     // Catch-all case that emits nothing if register_map! invokes it with an unknown first
     // argument. This is so that we can add new functionality into the operations traits without
     // breaking backwards compatibility (though registers! would need to be compatible with this
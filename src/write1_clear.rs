@@ -0,0 +1,103 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::{fields::FieldValue, DataType, Register, UIntLike};
+
+/// A write-1-to-clear register.
+///
+/// Writing a 1 to a bit of the register clears that bit, and writing a 0 leaves the bit
+/// unchanged. Interrupt status registers commonly behave this way. `Write1Clear` deliberately does
+/// not provide `modify`: writing back the bits read from the register would clear every bit that
+/// was set.
+///
+/// [`Write1Set`](trait@crate::Write1Set) is the write-1-to-set counterpart. `Fake` register blocks
+/// emulate both behaviors:
+/// ```
+/// # fn main() { interrupts_test() }
+/// use tock_registers::{mmio32_register_map, register_bitfields, Read, Write1Clear, Write1Set};
+/// register_bitfields![u32,
+///     Interrupts [
+///         RX OFFSET(0) NUMBITS(1) [],
+///         TX OFFSET(1) NUMBITS(1) [],
+///     ],
+/// ];
+/// mmio32_register_map! {
+///     #[fake]
+///     interrupts {
+///         0 => pending: Interrupts::Register { Read, Write1Clear },
+///         4 => enable_set: Interrupts::Register { Write1Set },
+///     }
+/// }
+///
+/// fn handle_rx<I: interrupts::Interface>(registers: I) {
+///     if registers.pending().is_set(Interrupts::RX) {
+///         registers.pending().clear(Interrupts::RX::SET);
+///         registers.enable_set().set_bits(Interrupts::TX::SET);
+///     }
+/// }
+///
+/// fn interrupts_test() {
+///     let fake = interrupts::Fake::default();
+///     fake.pending.set_value(0b11);
+///     fake.enable_set.set_value(0b1);
+///     handle_rx(&fake);
+///     assert_eq!(fake.pending.value(), 0b10);
+///     assert_eq!(fake.enable_set.value(), 0b11);
+/// }
+/// ```
+pub trait Write1Clear: Register {
+    /// Write the raw `mask` to the register, clearing the bits that are set in `mask`.
+    fn clear_raw(self, mask: <Self::DataType as DataType>::Value);
+
+    /// Clear the bits that are set in the value of one or more fields, leaving all other bits
+    /// unchanged. For example, `clear(Status::RX::SET)` clears only the `RX` bit.
+    fn clear(
+        self,
+        field: FieldValue<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >,
+    ) where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        self.clear_raw(field.value);
+    }
+}
+
+/// The macro that goes along with the Write1Clear trait. We don't expect this macro to be used by
+/// tock_register's users, instead it is invoked by the generated code.
+#[cfg(feature = "register_types")]
+#[macro_export]
+macro_rules! Write1Clear {
+    // Provides a real implementation of the trait. The trailing $rest argument is for future
+    // compatibility: it allows the procedural macro to pass additional arguments in the future
+    // without breaking compatibility with this implementation of Write1Clear!.
+    (real_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl<B: Bus + $crate::BusWrite<<$datatype as $crate::DataType>::Value>> $crate::Write1Clear
+            for $name<B>
+        {
+            fn clear_raw(self, mask: <$datatype as $crate::DataType>::Value) {
+                // Safety: The caller assured this register accessor points at a register on bus B
+                // with value type $datatype::Value that is safe to write. The code that
+                // constructed `self` guaranteed that they would avoid data races (precondition of
+                // Self::new).
+                unsafe { self.address.write(mask) }
+            }
+        }
+    };
+    // Implements the trait for a fake register generated by register_map!'s #[fake] attribute.
+    // The fake register is a newtype around a FakeCell.
+    (fake_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl $crate::Write1Clear for &$name<'_> {
+            fn clear_raw(self, mask: <$datatype as $crate::DataType>::Value) {
+                $crate::internal::fake::write_1_clear(&self.0, mask)
+            }
+        }
+    };
+    // Catch-all case that emits nothing if register_map! invokes it with an unknown first
+    // argument. This is so that we can add new functionality into the operations traits without
+    // breaking backwards compatibility (though registers! would need to be compatible with this
+    // do-nothing block).
+    ($($unknown:tt)*) => {};
+}
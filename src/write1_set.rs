@@ -0,0 +1,66 @@
+// Licensed under the Apache License, Version 2.0 or the MIT License.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright Tock Contributors 2026.
+
+use crate::{fields::FieldValue, DataType, Register, UIntLike};
+
+/// A write-1-to-set register.
+///
+/// Writing a 1 to a bit of the register sets that bit, and writing a 0 leaves the bit unchanged.
+/// Set/clear alias registers commonly behave this way. `Write1Set` deliberately does not provide
+/// `modify`, as a read-modify-write of the register is unnecessary and may not be safe.
+pub trait Write1Set: Register {
+    /// Write the raw `mask` to the register, setting the bits that are set in `mask`.
+    fn set_bits_raw(self, mask: <Self::DataType as DataType>::Value);
+
+    /// Set the bits that are set in the value of one or more fields, leaving all other bits
+    /// unchanged. For example, `set_bits(Output::PIN3::SET)` sets only the `PIN3` bit.
+    fn set_bits(
+        self,
+        field: FieldValue<
+            <Self::DataType as DataType>::Value,
+            <Self::DataType as DataType>::LongName,
+        >,
+    ) where
+        <Self::DataType as DataType>::Value: UIntLike,
+    {
+        self.set_bits_raw(field.value);
+    }
+}
+
+/// The macro that goes along with the Write1Set trait. We don't expect this macro to be used by
+/// tock_register's users, instead it is invoked by the generated code.
+#[cfg(feature = "register_types")]
+#[macro_export]
+macro_rules! Write1Set {
+    // Provides a real implementation of the trait. The trailing $rest argument is for future
+    // compatibility: it allows the procedural macro to pass additional arguments in the future
+    // without breaking compatibility with this implementation of Write1Set!.
+    (real_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl<B: Bus + $crate::BusWrite<<$datatype as $crate::DataType>::Value>> $crate::Write1Set
+            for $name<B>
+        {
+            fn set_bits_raw(self, mask: <$datatype as $crate::DataType>::Value) {
+                // Safety: The caller assured this register accessor points at a register on bus B
+                // with value type $datatype::Value that is safe to write. The code that
+                // constructed `self` guaranteed that they would avoid data races (precondition of
+                // Self::new).
+                unsafe { self.address.write(mask) }
+            }
+        }
+    };
+    // Implements the trait for a fake register generated by register_map!'s #[fake] attribute.
+    // The fake register is a newtype around a FakeCell.
+    (fake_impl, $name:ident, $datatype:ty, $($rest:tt)*) => {
+        impl $crate::Write1Set for &$name<'_> {
+            fn set_bits_raw(self, mask: <$datatype as $crate::DataType>::Value) {
+                $crate::internal::fake::write_1_set(&self.0, mask)
+            }
+        }
+    };
+    // Catch-all case that emits nothing if register_map! invokes it with an unknown first
+    // argument. This is so that we can add new functionality into the operations traits without
+    // breaking backwards compatibility (though registers! would need to be compatible with this
+    // do-nothing block).
+    ($($unknown:tt)*) => {};
+}
@@ -3,13 +3,14 @@
 // Copyright Tock Contributors 2026.
 // Copyright Better Bytes 2026.
 
-use tock_registers::{mmio32_register_map, Read};
+use tock_registers::{mmio32_register_map, Read, Write};
 
 mmio32_register_map! {
     pub scalar_definition: u8 { Read },
     pub array_definition: [u8; 2] { Read },
     pub block_definition {
         0 => scalar_field: u8 { Read },
+        pub(crate) 1 => restricted_field: u8 { Write },
     }
 }
 